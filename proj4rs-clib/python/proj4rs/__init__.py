@@ -27,6 +27,16 @@ class Proj:
     def is_geocent(self) -> bool:
         return lib.proj4rs_proj_is_geocent(self._cdata)
 
+    @property
+    def is_projected(self) -> bool:
+        return lib.proj4rs_proj_is_projected(self._cdata)
+
+    @property
+    def proj_type(self) -> int:
+        """ 0: geocentric, 1: geographic, 2: projected
+        """
+        return lib.proj4rs_proj_type(self._cdata)
+
     @property
     def axis(self) -> bytes:
         _rv = lib.proj4rs_proj_axis(self._cdata)
@@ -40,11 +50,20 @@ class Proj:
     def to_meter(self) -> float:
         return lib.proj4rs_proj_to_meter(self._cdata)
 
+    @property
+    def vto_meter(self) -> float:
+        return lib.proj4rs_proj_vto_meter(self._cdata)
+
     @property
     def units(self) -> str:
         _rv = lib.proj4rs_proj_units(self._cdata)
         return ffi.string(_rv).decode()
 
+    @property
+    def vunits(self) -> str:
+        _rv = lib.proj4rs_proj_vunits(self._cdata)
+        return ffi.string(_rv).decode()
+
 
 def _scalar_to_buffer(x):
     return array("d", (float(x),))
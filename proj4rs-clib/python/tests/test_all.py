@@ -115,3 +115,20 @@ def test_transform_buffer_2d():
 
 
 
+
+
+def test_proj_vunits():
+    p = proj4rs.Proj("+proj=tmerc +ellps=GRS80 +units=m +vunits=ft")
+    assert p.units == "m"
+    assert p.vunits == "ft"
+    assert p.vto_meter == pytest.approx(0.3048)
+
+
+def test_proj_type():
+
+    assert proj4rs.Proj("WGS84").proj_type == 1
+    assert proj4rs.Proj("+proj=cart +ellps=GRS80").proj_type == 0
+
+    p = proj4rs.Proj("+proj=utm +zone=31 +ellps=GRS80")
+    assert p.proj_type == 2
+    assert p.is_projected
@@ -3,18 +3,32 @@ use std::cell::RefCell;
 use std::ffi::{CStr, CString};
 use std::ptr;
 
+#[cfg(feature = "global-error")]
+use libc::size_t;
+#[cfg(feature = "global-error")]
+use std::sync::Mutex;
+
 use proj4rs::{
     errors, proj,
     transform::{transform, Transform, TransformClosure},
 };
 
+// Last error of the calling thread
 thread_local!(static LAST_ERROR: RefCell<CString> = RefCell::new(CString::new("").unwrap()));
 
+// Last error of the process, with the "global-error" feature:
+// only read through copies, see `proj4rs_last_error_global`
+#[cfg(feature = "global-error")]
+static GLOBAL_LAST_ERROR: Mutex<Option<CString>> = Mutex::new(None);
+
 fn set_last_error(err: errors::Error) {
     let error = err.to_string();
-    LAST_ERROR.with(|c| {
-        c.replace(CString::new(error.as_str()).unwrap_or(CString::new("Unknown").unwrap()))
-    });
+    let error = CString::new(error.as_str()).unwrap_or(CString::new("Unknown").unwrap());
+    #[cfg(feature = "global-error")]
+    if let Ok(mut last) = GLOBAL_LAST_ERROR.lock() {
+        last.replace(error.clone());
+    }
+    LAST_ERROR.with(|c| c.replace(error));
 }
 
 fn to_c_unit(name: &str) -> &'static str {
@@ -41,16 +55,57 @@ fn to_c_unit(name: &str) -> &'static str {
         "ind-ft" => "ind-ft\0",
         "ind-ch" => "ind-ch\0",
         "degrees" => "degrees\0",
+        "unknown" => "unknown\0",
         _ => "\0",
     }
 }
 
-/// Return the last error message
+/// Return the last error message raised in the calling thread
+///
+/// The returned pointer is valid until the next error in
+/// the calling thread.
 #[no_mangle]
 pub extern "C" fn proj4rs_last_error() -> *const c_char {
+    proj4rs_last_error_thread()
+}
+
+/// Return the last error message raised in the calling thread
+///
+/// Same as [`proj4rs_last_error`].
+#[no_mangle]
+pub extern "C" fn proj4rs_last_error_thread() -> *const c_char {
     LAST_ERROR.with_borrow(|c| c.as_ptr() as *const c_char)
 }
 
+/// Copy the last error message raised in any thread into `buf`
+///
+/// At most `len - 1` bytes of the message are copied and `buf` is always
+/// nul terminated when `len > 0`. Return the length of the message,
+/// without the terminating nul: the message has been truncated if
+/// the returned value is not less than `len`.
+///
+/// Requires the `global-error` feature. This allows retrieving
+/// errors from another thread than the one calling the failing
+/// function, at the cost of a process wide lock taken on every error,
+/// which adds contention when many threads are failing at the same time.
+#[cfg(feature = "global-error")]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[no_mangle]
+pub extern "C" fn proj4rs_last_error_global(buf: *mut c_char, len: size_t) -> size_t {
+    let last = GLOBAL_LAST_ERROR
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let msg = last.as_ref().map(|c| c.as_bytes()).unwrap_or_default();
+    if !buf.is_null() && len > 0 {
+        let n = msg.len().min(len - 1);
+        unsafe {
+            ptr::copy_nonoverlapping(msg.as_ptr() as *const c_char, buf, n);
+            *buf.add(n) = 0;
+        }
+    }
+    msg.len()
+}
+
 /// Opaque structure holding the internal representation
 /// of projection.
 pub struct Proj4rs {
@@ -66,6 +121,7 @@ pub struct Proj4rs {
 /// * A "WGS84" string - equivalent to the projstring "+proj=longlat +ellps=WGS84"
 /// * An EPSG code
 ///
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 #[no_mangle]
 pub extern "C" fn proj4rs_proj_new(c_defn: *const c_char) -> *mut Proj4rs {
     let cstr_defn = unsafe { CStr::from_ptr(c_defn) };
@@ -86,6 +142,7 @@ pub extern "C" fn proj4rs_proj_new(c_defn: *const c_char) -> *mut Proj4rs {
 }
 
 /// Delete projection object
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 #[no_mangle]
 pub extern "C" fn proj4rs_proj_delete(c_ptr: *mut Proj4rs) {
     if !c_ptr.is_null() {
@@ -96,6 +153,7 @@ pub extern "C" fn proj4rs_proj_delete(c_ptr: *mut Proj4rs) {
 }
 
 /// Returns the projection name
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 #[no_mangle]
 pub extern "C" fn proj4rs_proj_projname(c_ptr: *const Proj4rs) -> *const c_char {
     assert!(!c_ptr.is_null(), "Null proj pointer");
@@ -104,6 +162,7 @@ pub extern "C" fn proj4rs_proj_projname(c_ptr: *const Proj4rs) -> *const c_char
 }
 
 /// Returns true if the projection is geographic
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 #[no_mangle]
 pub extern "C" fn proj4rs_proj_is_latlong(c_ptr: *const Proj4rs) -> bool {
     assert!(!c_ptr.is_null(), "Null proj pointer");
@@ -112,6 +171,7 @@ pub extern "C" fn proj4rs_proj_is_latlong(c_ptr: *const Proj4rs) -> bool {
 }
 
 /// Returns true if the projection is geocentric
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 #[no_mangle]
 pub extern "C" fn proj4rs_proj_is_geocent(c_ptr: *const Proj4rs) -> bool {
     assert!(!c_ptr.is_null(), "Null proj pointer");
@@ -119,6 +179,30 @@ pub extern "C" fn proj4rs_proj_is_geocent(c_ptr: *const Proj4rs) -> bool {
     proj.inner.is_geocent()
 }
 
+/// Returns true if the projection is neither geographic nor geocentric
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[no_mangle]
+pub extern "C" fn proj4rs_proj_is_projected(c_ptr: *const Proj4rs) -> bool {
+    assert!(!c_ptr.is_null(), "Null proj pointer");
+    let proj: &Proj4rs = unsafe { &*c_ptr };
+    proj.inner.is_projected()
+}
+
+/// Returns the projection type
+///
+/// 0: geocentric, 1: geographic, 2: projected
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[no_mangle]
+pub extern "C" fn proj4rs_proj_type(c_ptr: *const Proj4rs) -> c_int {
+    assert!(!c_ptr.is_null(), "Null proj pointer");
+    let proj: &Proj4rs = unsafe { &*c_ptr };
+    match proj.inner.projection_type() {
+        proj::ProjType::Geocentric => 0,
+        proj::ProjType::Latlong => 1,
+        proj::ProjType::Other => 2,
+    }
+}
+
 /// Return the projection axes
 ///
 /// The value returned is a pointer to 3-value byte array
@@ -135,6 +219,7 @@ pub extern "C" fn proj4rs_proj_is_geocent(c_ptr: *const Proj4rs) -> bool {
 /// }
 /// ```
 ///
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 #[no_mangle]
 pub extern "C" fn proj4rs_proj_axis(c_ptr: *const Proj4rs) -> *const u8 {
     assert!(!c_ptr.is_null(), "Null proj pointer");
@@ -143,6 +228,7 @@ pub extern "C" fn proj4rs_proj_axis(c_ptr: *const Proj4rs) -> *const u8 {
 }
 
 /// Return true if the axis are noramilized
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 #[no_mangle]
 pub extern "C" fn proj4rs_proj_is_normalized_axis(c_ptr: *const Proj4rs) -> bool {
     assert!(!c_ptr.is_null(), "Null proj pointer");
@@ -150,6 +236,7 @@ pub extern "C" fn proj4rs_proj_is_normalized_axis(c_ptr: *const Proj4rs) -> bool
     proj.inner.is_normalized_axis()
 }
 
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 #[no_mangle]
 pub extern "C" fn proj4rs_proj_to_meter(c_ptr: *const Proj4rs) -> f64 {
     assert!(!c_ptr.is_null(), "Null proj pointer");
@@ -157,7 +244,17 @@ pub extern "C" fn proj4rs_proj_to_meter(c_ptr: *const Proj4rs) -> f64 {
     proj.inner.to_meter()
 }
 
+/// Return the vertical units to meter factor
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[no_mangle]
+pub extern "C" fn proj4rs_proj_vto_meter(c_ptr: *const Proj4rs) -> f64 {
+    assert!(!c_ptr.is_null(), "Null proj pointer");
+    let proj: &Proj4rs = unsafe { &*c_ptr };
+    proj.inner.vto_meter()
+}
+
 /// Return units of the projection (i.e "degrees", "m", "km", ...)
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 #[no_mangle]
 pub extern "C" fn proj4rs_proj_units(c_ptr: *const Proj4rs) -> *const c_char {
     assert!(!c_ptr.is_null(), "Null proj pointer");
@@ -165,6 +262,15 @@ pub extern "C" fn proj4rs_proj_units(c_ptr: *const Proj4rs) -> *const c_char {
     to_c_unit(proj.inner.units()).as_ptr() as *const c_char
 }
 
+/// Return vertical units of the projection (i.e "m", "ft", ...)
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[no_mangle]
+pub extern "C" fn proj4rs_proj_vunits(c_ptr: *const Proj4rs) -> *const c_char {
+    assert!(!c_ptr.is_null(), "Null proj pointer");
+    let proj: &Proj4rs = unsafe { &*c_ptr };
+    to_c_unit(proj.inner.vunits()).as_ptr() as *const c_char
+}
+
 // ----------------------------
 // Wrapper for Transform
 // ---------------------------
@@ -184,6 +290,7 @@ pub const ERR: c_int = 0;
 ///
 /// If `convert` is `true` then latlong coordinates are assumed te be in degrees.
 ///
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 #[no_mangle]
 pub extern "C" fn proj4rs_transform(
     src_ptr: *const Proj4rs,
@@ -213,7 +320,7 @@ pub extern "C" fn proj4rs_transform(
             to_radians(x, y, len, stride);
         }
     }
-    
+
     if let Err(err) = transform(&src.inner, &dst.inner, &mut Coords(x, y, z, len, stride)) {
         set_last_error(err);
         ERR
@@ -227,13 +334,43 @@ pub extern "C" fn proj4rs_transform(
     }
 }
 
+/// Transform array of interleaved `[x0, y0, x1, y1, ...]` coordinates
+///
+/// Projected coordinates will be set to  NAN in case of projection failure
+///
+/// * `coords` is a pointer to `2 * len` doubles
+/// * `len` in the number of points
+///
+/// If `convert` is `true` then latlong coordinates are assumed te be in degrees.
+///
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[no_mangle]
+pub extern "C" fn proj4rs_transform_interleaved_2d(
+    src_ptr: *const Proj4rs,
+    dst_ptr: *const Proj4rs,
+    coords: *mut f64,
+    len: ssize_t,
+    convert: bool,
+) -> c_int {
+    if coords.is_null() {
+        return ERR;
+    }
+    proj4rs_transform(
+        src_ptr,
+        dst_ptr,
+        coords,
+        unsafe { coords.add(1) },
+        ptr::null_mut(),
+        len,
+        2 * std::mem::size_of::<f64>() as ssize_t,
+        convert,
+    )
+}
+
 struct Coords(*mut f64, *mut f64, *mut f64, isize, isize);
 
 impl Transform for Coords {
-    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> errors::Result<()>
-    where
-        F: FnMut(f64, f64, f64) -> errors::Result<(f64, f64, f64)>,
-    {
+    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> errors::Result<()> {
         let (mut xx, mut yy, mut zz, mut len, stride) = (self.0, self.1, self.2, self.3, self.4);
 
         if zz.is_null() {
@@ -0,0 +1,138 @@
+//!
+//! Derive macro for the proj4rs [`Transform`] trait
+//!
+//! Fields holding coordinates are marked with the `#[transform(x)]`,
+//! `#[transform(y)]` and `#[transform(z)]` attributes. The `x` and `y`
+//! fields are mandatory, when no `z` field is given, the transformation
+//! is done with `z = 0` and the output `z` is dropped.
+//!
+//! ```rust
+//! use proj4rs::transform::transform;
+//! use proj4rs::Proj;
+//! use proj4rs_derive::Transform;
+//!
+//! #[derive(Transform)]
+//! struct MyPoint {
+//!     #[transform(y)]
+//!     lat: f64,
+//!     #[transform(x)]
+//!     lon: f64,
+//!     #[transform(z)]
+//!     elevation: f64,
+//!     name: String,
+//! }
+//!
+//! let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+//! let dst = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+//!
+//! let mut points = vec![MyPoint {
+//!     lat: 45f64.to_radians(),
+//!     lon: 3f64.to_radians(),
+//!     elevation: 0.,
+//!     name: "origin".into(),
+//! }];
+//!
+//! // Slices and vectors of points are transformed through the
+//! // `Transform` implementations for `[T]` and `Vec<T>` in proj4rs.
+//! transform(&src, &dst, &mut points).unwrap();
+//! assert!((points[0].lon - 500000.).abs() < 1.0e-6);
+//! ```
+//!
+//! [`Transform`]: https://docs.rs/proj4rs/latest/proj4rs/transform/trait.Transform.html
+//!
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, Index, Result};
+
+#[proc_macro_derive(Transform, attributes(transform))]
+pub fn derive_transform(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Coordinates members
+#[derive(Default)]
+struct Members {
+    x: Option<TokenStream2>,
+    y: Option<TokenStream2>,
+    z: Option<TokenStream2>,
+}
+
+impl Members {
+    fn from_fields(fields: &Fields) -> Result<Self> {
+        let mut members = Self::default();
+        for (i, field) in fields.iter().enumerate() {
+            let member = match &field.ident {
+                Some(ident) => ident.to_token_stream(),
+                None => Index::from(i).to_token_stream(),
+            };
+            for attr in field
+                .attrs
+                .iter()
+                .filter(|a| a.path().is_ident("transform"))
+            {
+                let axis: Ident = attr.parse_args()?;
+                let slot = match axis.to_string().as_str() {
+                    "x" => &mut members.x,
+                    "y" => &mut members.y,
+                    "z" => &mut members.z,
+                    _ => return Err(Error::new_spanned(axis, "expected `x`, `y` or `z`")),
+                };
+                if slot.replace(member.clone()).is_some() {
+                    return Err(Error::new_spanned(axis, "duplicate coordinate attribute"));
+                }
+            }
+        }
+        Ok(members)
+    }
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Transform can only be derived for structs",
+            ))
+        }
+    };
+
+    let members = Members::from_fields(fields)?;
+    let (x, y) = match (members.x, members.y) {
+        (Some(x), Some(y)) => (x, y),
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "missing `#[transform(x)]` or `#[transform(y)]` field",
+            ))
+        }
+    };
+
+    let body = match members.z {
+        Some(z) => quote! {
+            (self.#x, self.#y, self.#z) = f(self.#x, self.#y, self.#z)?;
+        },
+        None => quote! {
+            (self.#x, self.#y) = f(self.#x, self.#y, 0.).map(|(x, y, _)| (x, y))?;
+        },
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::proj4rs::transform::Transform for #name #ty_generics #where_clause {
+            fn transform_coordinates<F: ::proj4rs::transform::TransformClosure>(
+                &mut self,
+                f: &mut F,
+            ) -> ::proj4rs::errors::Result<()> {
+                #body
+                Ok(())
+            }
+        }
+    })
+}
@@ -0,0 +1,106 @@
+//!
+//! Test the Transform derive macro
+//!
+use approx::assert_abs_diff_eq;
+use proj4rs::transform::transform;
+use proj4rs::Proj;
+use proj4rs_derive::Transform;
+
+#[derive(Debug, Clone, Transform)]
+struct Point3d {
+    name: &'static str,
+    #[transform(y)]
+    lat: f64,
+    #[transform(x)]
+    lon: f64,
+    #[transform(z)]
+    elevation: f64,
+}
+
+#[derive(Debug, Clone, Transform)]
+struct Point2d {
+    #[transform(x)]
+    x: f64,
+    #[transform(y)]
+    y: f64,
+}
+
+#[derive(Debug, Clone, Transform)]
+struct TuplePoint(#[transform(x)] f64, #[transform(y)] f64, u32);
+
+fn projs() -> (Proj, Proj) {
+    (
+        Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap(),
+        Proj::from_proj_string("+proj=utm +zone=30 +ellps=GRS80").unwrap(),
+    )
+}
+
+// Reference values from proj
+const EXPECTED: [(f64, f64, f64); 2] = [
+    (1057002.4054912976, 110955.14117594929, 0.),
+    (611263.812278906, 110547.10569680421, 0.),
+];
+
+#[test]
+fn derive_named_fields() {
+    let (src, dst) = projs();
+
+    let mut points = vec![
+        Point3d {
+            name: "a",
+            lat: 1f64.to_radians(),
+            lon: 2f64.to_radians(),
+            elevation: 0.,
+        },
+        Point3d {
+            name: "b",
+            lat: 1f64.to_radians(),
+            lon: -2f64.to_radians(),
+            elevation: 0.,
+        },
+    ];
+
+    transform(&src, &dst, &mut points).unwrap();
+
+    for (pt, expected) in points.iter().zip(EXPECTED) {
+        assert_abs_diff_eq!(pt.lon, expected.0, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.lat, expected.1, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.elevation, expected.2, epsilon = 1.0e-6);
+    }
+    assert_eq!(points[0].name, "a");
+}
+
+#[test]
+fn derive_2d_slice() {
+    let (src, dst) = projs();
+
+    let mut points = [
+        Point2d {
+            x: 2f64.to_radians(),
+            y: 1f64.to_radians(),
+        },
+        Point2d {
+            x: -2f64.to_radians(),
+            y: 1f64.to_radians(),
+        },
+    ];
+
+    transform(&src, &dst, &mut points[..]).unwrap();
+
+    for (pt, expected) in points.iter().zip(EXPECTED) {
+        assert_abs_diff_eq!(pt.x, expected.0, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.y, expected.1, epsilon = 1.0e-6);
+    }
+}
+
+#[test]
+fn derive_tuple_struct() {
+    let (src, dst) = projs();
+
+    let mut pt = TuplePoint(2f64.to_radians(), 1f64.to_radians(), 42);
+    transform(&src, &dst, &mut pt).unwrap();
+
+    assert_abs_diff_eq!(pt.0, EXPECTED[0].0, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.1, EXPECTED[0].1, epsilon = 1.0e-6);
+    assert_eq!(pt.2, 42);
+}
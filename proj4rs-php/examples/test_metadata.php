@@ -0,0 +1,8 @@
+<?php
+
+var_dump(proj4rs_list_projections());
+var_dump(Projection::getSupportedEllipsoids());
+
+$p = new Projection("+proj=laea +lat_0=52 +lon_0=10 +x_0=4321000 +y_0=3210000 +ellps=GRS80");
+
+var_dump($p->toArray());
@@ -0,0 +1,8 @@
+<?php
+
+$p = new Projection("+proj=tmerc +ellps=GRS80 +units=m +vunits=ft");
+
+assert($p->units == "m");
+assert($p->vUnits == "ft");
+
+var_dump($p->vUnits);
@@ -4,8 +4,10 @@
 //! See https://davidcole1340.github.io/ext-php-rs
 
 
-use proj4rs::{errors, proj, transform};
+use proj4rs::{errors, proj, projections, transform};
 use ext_php_rs::prelude::*;
+use ext_php_rs::boxed::ZBox;
+use ext_php_rs::types::ZendHashTable;
 
 
 #[cfg(feature = "logging")]
@@ -61,6 +63,22 @@ impl Projection {
         self.inner.is_geocent()
     }
 
+    #[getter(rename = "isProjected")]
+    pub fn is_projected(&self) -> bool {
+        self.inner.is_projected()
+    }
+
+    /// One of "geocentric", "latlong" or "projected"
+    #[getter(rename = "projType")]
+    pub fn proj_type(&self) -> String {
+        match self.inner.projection_type() {
+            proj::ProjType::Geocentric => "geocentric",
+            proj::ProjType::Latlong => "latlong",
+            proj::ProjType::Other => "projected",
+        }
+        .into()
+    }
+
     #[getter]
     pub fn axis(&self) -> String {
         String::from_utf8_lossy(self.inner.axis()).into_owned()
@@ -84,6 +102,41 @@ impl Projection {
     pub fn units(&self) -> String {
         self.inner.units().into()
     }
+
+    #[getter(rename = "vUnits")]
+    pub fn vunits(&self) -> String {
+        self.inner.vunits().into()
+    }
+
+    /// Return the names of the known ellipsoids
+    pub fn get_supported_ellipsoids() -> Vec<String> {
+        proj4rs::ellipsoid_names().map(String::from).collect()
+    }
+
+    /// Return the projection parameters as an associative array
+    ///
+    /// Angles (`lam0`, `phi0` and `fromGreenwich`) are in radians.
+    pub fn to_array(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let p = &self.inner;
+        let d = p.data();
+        let mut ht = ZendHashTable::new();
+        let mut insert = |k: &str, v: f64| ht.insert(k, v).map_err(|e| PhpException::from(e.to_string()));
+        insert("a", d.a())?;
+        insert("e", d.e())?;
+        insert("es", d.es())?;
+        insert("k0", d.k0())?;
+        insert("lam0", d.lam0())?;
+        insert("phi0", d.phi0())?;
+        insert("x0", d.x0())?;
+        insert("y0", d.y0())?;
+        insert("fromGreenwich", p.from_greenwich())?;
+        insert("toMeter", p.to_meter())?;
+        insert("vToMeter", p.vto_meter())?;
+        ht.insert("projName", p.projname())
+            .and_then(|_| ht.insert("axis", self.axis()))
+            .map_err(|e| PhpException::from(e.to_string()))?;
+        Ok(ht)
+    }
 }
 
 
@@ -152,6 +205,12 @@ pub fn transform_point(
 }
 
 
+/// Return the names of the available projections
+#[php_function]
+pub fn proj4rs_list_projections() -> Vec<String> {
+    projections::projection_names().map(String::from).collect()
+}
+
 #[php_module]
 pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
     module
@@ -0,0 +1,177 @@
+//!
+//! Transform benchmarks
+//!
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use proj4rs::nadgrids::{catalog, files::read_from_file};
+use proj4rs::{transform::transform, Proj};
+use std::io::Write;
+use std::path::PathBuf;
+
+const NUM_POINTS: usize = 100_000;
+
+fn points_n(n: usize) -> Vec<(f64, f64, f64)> {
+    (0..n)
+        .map(|i| {
+            let t = i as f64 / n as f64;
+            (400_000. + 200_000. * t, 4_000_000. + 1_000_000. * t, 0.)
+        })
+        .collect()
+}
+
+fn points() -> Vec<(f64, f64, f64)> {
+    points_n(NUM_POINTS)
+}
+
+fn same_crs(c: &mut Criterion) {
+    let src = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+    let dst = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+    // Numerically the same CRS, but not equivalent because
+    // of the explicit datum parameters: run the full pipeline
+    let full =
+        Proj::from_proj_string("+proj=utm +zone=31 +ellps=WGS84 +towgs84=0,0,0,0,0,0,0").unwrap();
+
+    let mut group = c.benchmark_group("same_crs");
+    group.bench_function("short_circuit", |b| {
+        let mut pts = points();
+        b.iter(|| transform(&src, &dst, black_box(pts.as_mut_slice())))
+    });
+    group.bench_function("full_pipeline", |b| {
+        let mut pts = points();
+        b.iter(|| transform(&src, &full, black_box(pts.as_mut_slice())))
+    });
+    group.finish();
+}
+
+fn same_datum(c: &mut Criterion) {
+    let src = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+    let dst = Proj::from_proj_string("+proj=utm +zone=32 +datum=WGS84").unwrap();
+    // Null shift, but the datums are not identical
+    let shifted =
+        Proj::from_proj_string("+proj=utm +zone=32 +ellps=WGS84 +towgs84=0,0,0,0,0,0,0").unwrap();
+
+    let mut group = c.benchmark_group("same_datum");
+    group.sample_size(10);
+    group.bench_function("utm_to_utm_1M", |b| {
+        let mut pts = points_n(1_000_000);
+        b.iter(|| transform(&src, &dst, black_box(pts.as_mut_slice())))
+    });
+    group.bench_function("utm_to_utm_shifted_1M", |b| {
+        let mut pts = points_n(1_000_000);
+        b.iter(|| transform(&src, &shifted, black_box(pts.as_mut_slice())))
+    });
+    group.finish();
+}
+
+// Write a NTv2 file with a 8x8 degrees root grid, optionally
+// tiled by 64 subgrids of 1x1 degree
+fn write_ntv2(name: &str, tiled: bool) -> PathBuf {
+    fn rec(buf: &mut Vec<u8>, key: &str, value: [u8; 8]) {
+        buf.extend_from_slice(format!("{key:<8}").as_bytes());
+        buf.extend_from_slice(&value);
+    }
+    fn int(v: u32) -> [u8; 8] {
+        let mut b = [0u8; 8];
+        b[..4].copy_from_slice(&v.to_ne_bytes());
+        b
+    }
+    fn text(s: &str) -> [u8; 8] {
+        format!("{s:<8}").as_bytes().try_into().unwrap()
+    }
+    fn subgrid(buf: &mut Vec<u8>, name: &str, parent: &str, ll: (f64, f64), size: f64) {
+        // Bounds in seconds, longitudes are positive west
+        let (lon, lat, del) = (ll.0 * 3600., ll.1 * 3600., size * 900.);
+        rec(buf, "SUB_NAME", text(name));
+        rec(buf, "PARENT", text(parent));
+        rec(buf, "CREATED", text(""));
+        rec(buf, "UPDATED", text(""));
+        rec(buf, "S_LAT", lat.to_ne_bytes());
+        rec(buf, "N_LAT", (lat + size * 3600.).to_ne_bytes());
+        rec(buf, "E_LONG", (-lon - size * 3600.).to_ne_bytes());
+        rec(buf, "W_LONG", (-lon).to_ne_bytes());
+        rec(buf, "LAT_INC", del.to_ne_bytes());
+        rec(buf, "LONG_INC", del.to_ne_bytes());
+        rec(buf, "GS_COUNT", int(25));
+        for _ in 0..25 {
+            [0.1f32, -0.1, 0.01, 0.01]
+                .iter()
+                .for_each(|v| buf.extend_from_slice(&v.to_ne_bytes()));
+        }
+    }
+
+    let mut buf = vec![];
+    rec(&mut buf, "NUM_OREC", int(11));
+    rec(&mut buf, "NUM_SREC", int(11));
+    rec(&mut buf, "NUM_FILE", int(if tiled { 65 } else { 1 }));
+    rec(&mut buf, "GS_TYPE", text("SECONDS"));
+    rec(&mut buf, "VERSION", text("NTv2.0"));
+    rec(&mut buf, "SYSTEM_F", text("GRS80"));
+    rec(&mut buf, "SYSTEM_T", text("GRS80"));
+    for key in ["MAJOR_F", "MINOR_F", "MAJOR_T", "MINOR_T"] {
+        rec(&mut buf, key, 6_378_137f64.to_ne_bytes());
+    }
+    // Grid ids must be unique among loaded grids
+    let root = if tiled { "TILED" } else { "SINGLE" };
+    subgrid(&mut buf, root, "NONE", (0., 44.), 8.);
+    if tiled {
+        for k in 0..64 {
+            let ll = ((k % 8) as f64, 44. + (k / 8) as f64);
+            subgrid(&mut buf, &format!("TILE{k:04}"), root, ll, 1.);
+        }
+    }
+
+    let path = std::env::temp_dir().join(name);
+    std::fs::File::create(&path)
+        .and_then(|mut f| f.write_all(&buf))
+        .unwrap();
+    path
+}
+
+fn nadgrids(c: &mut Criterion) {
+    catalog::set_builder(read_from_file);
+
+    let dst = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+    let proj = |path: PathBuf| {
+        Proj::from_proj_string(&format!(
+            "+proj=latlong +ellps=GRS80 +nadgrids={}",
+            path.display()
+        ))
+        .unwrap()
+    };
+    let single = proj(write_ntv2("proj4rs_bench_single.gsb", false));
+    let tiled = proj(write_ntv2("proj4rs_bench_tiled.gsb", true));
+
+    // Spread the points over all subgrids, away from their borders
+    let points = || -> Vec<(f64, f64, f64)> {
+        (0..NUM_POINTS)
+            .map(|i| {
+                let (k, t) = (i % 64, (i / 64) as f64 / (NUM_POINTS / 64) as f64);
+                let lam = (k % 8) as f64 + 0.05 + 0.9 * t;
+                let phi = 44. + (k / 8) as f64 + 0.95 - 0.9 * t;
+                (lam.to_radians(), phi.to_radians(), 0.)
+            })
+            .collect()
+    };
+
+    // Both grids define the same shift
+    let (mut a, mut b) = (points(), points());
+    transform(&single, &dst, a.as_mut_slice()).unwrap();
+    transform(&tiled, &dst, b.as_mut_slice()).unwrap();
+    assert!(a
+        .iter()
+        .zip(b.iter())
+        .all(|(a, b)| (a.0 - b.0).abs() < 1.0e-12 && (a.1 - b.1).abs() < 1.0e-12));
+
+    let mut group = c.benchmark_group("nadgrids");
+    group.bench_function("single_grid", |b| {
+        let mut pts = points();
+        b.iter(|| transform(&single, &dst, black_box(pts.as_mut_slice())))
+    });
+    group.bench_function("64_subgrids", |b| {
+        let mut pts = points();
+        b.iter(|| transform(&tiled, &dst, black_box(pts.as_mut_slice())))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, same_crs, same_datum, nadgrids);
+criterion_main!(benches);
@@ -1,12 +1,18 @@
 //!
 //! Transform adaptors
 //!
+mod coordinates;
 #[cfg(feature = "geo-types")]
 pub mod geo_types;
 
-use crate::errors::Result;
+#[cfg(feature = "geojson")]
+pub mod geojson;
+
+pub use coordinates::{GeographicCoordinate, ProjectedCoordinate};
+
+use crate::errors::{Error, Result};
 use crate::proj::Proj;
-use crate::transform::{transform, Transform, TransformClosure};
+use crate::transform::{transform, Transform, TransformClosure, Transformation};
 
 //
 // Transform a 3-tuple
@@ -93,22 +99,216 @@ pub fn transform_xy(src: &Proj, dst: &Proj, x: f64, y: f64) -> Result<(f64, f64)
     transform_xyz(src, dst, x, y, 0.).map(|(x, y, _)| (x, y))
 }
 
+/// Step name with the coordinates of the point after the step
+#[cfg(feature = "debug-trace")]
+pub type TracedStep = (String, (f64, f64, f64));
+
+/// Transform a point and record its coordinates after each step
+///
+/// The trace is returned along with the result, so that the steps
+/// before a failure can be inspected. See
+/// [`transform_debug`](crate::transform::transform_debug) for the step names.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::adaptors::transform_traced;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+/// let dst = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+///
+/// let mut pt = (3f64.to_radians(), 45f64.to_radians(), 0.);
+/// let (result, trace) = transform_traced(&src, &dst, &mut pt);
+/// assert!(result.is_ok());
+/// assert_eq!(trace[0].0, "input");
+/// ```
+#[cfg(feature = "debug-trace")]
+pub fn transform_traced(
+    src: &Proj,
+    dst: &Proj,
+    pt: &mut (f64, f64, f64),
+) -> (Result<()>, Vec<TracedStep>) {
+    let mut trace = crate::transform::TransformTrace::default();
+    let result = crate::transform::transform_trace_into(src, dst, pt, &mut trace);
+    let steps = trace
+        .steps
+        .into_iter()
+        .map(|(name, pts)| (name.to_string(), pts[0]))
+        .collect();
+    (result, steps)
+}
+
+/// Transform points and convert the output to degrees
+///
+/// When `dst` is geographic, output coordinates are converted from
+/// radians to degrees in place; otherwise this is the same as
+/// [`transform`]. Input coordinates are in `src` units, i.e. radians
+/// for geographic coordinates.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::adaptors::transform_array_to_degrees;
+///
+/// let src = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+/// let dst = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+///
+/// let mut points = [(500000., 0., 0.)];
+/// transform_array_to_degrees(&src, &dst, points.as_mut_slice()).unwrap();
+/// assert!((points[0].0 - 3.).abs() < 1.0e-9);
+/// ```
+pub fn transform_array_to_degrees<P>(src: &Proj, dst: &Proj, points: &mut P) -> Result<()>
+where
+    P: Transform + ?Sized,
+{
+    transform(src, dst, points)?;
+    if dst.is_latlong() {
+        points.transform_coordinates(&mut |x, y, z| Ok((x.to_degrees(), y.to_degrees(), z)))?;
+    }
+    Ok(())
+}
+
+/// Transform each point independently and return a status per point
+///
+/// The batch is never aborted: points that fail to transform are left
+/// unchanged and their error is reported at the same index.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::adaptors::transform_with_status;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+/// let dst = Proj::from_proj_string("+proj=geos +h=35785831 +ellps=GRS80").unwrap();
+///
+/// let mut points = [
+///     (0f64.to_radians(), 0f64.to_radians(), 0.),
+///     (180f64.to_radians(), 0f64.to_radians(), 0.),
+/// ];
+///
+/// let status = transform_with_status(&src, &dst, &mut points);
+/// assert!(status[0].is_ok());
+/// assert!(status[1].is_err());
+/// ```
+pub fn transform_with_status(
+    src: &Proj,
+    dst: &Proj,
+    points: &mut [(f64, f64, f64)],
+) -> Vec<Result<(), Error>> {
+    let transformation = Transformation::new(src, dst);
+    points
+        .iter_mut()
+        .map(|pt| {
+            let mut out = *pt;
+            transformation.apply(&mut out).map(|_| *pt = out)
+        })
+        .collect()
+}
+
+/// Transform columnar x and y buffers in place
+///
+/// This is the layout used by GeoArrow for separated coordinates.
+/// `validity` is an Arrow validity bitmap (bit `i` set, least significant
+/// bit first, means that point `i` is valid): null points are skipped and
+/// points that fail to transform are set to null. Without a bitmap, failed
+/// points are set to NaN.
+///
+/// Return an error if the buffers sizes do not match.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::adaptors::transform_buffers;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+/// let dst = Proj::from_proj_string("+proj=geos +h=35785831 +ellps=GRS80").unwrap();
+///
+/// let mut x = [0f64.to_radians(), 180f64.to_radians(), 10f64.to_radians()];
+/// let mut y = [0f64.to_radians(), 0f64.to_radians(), 10f64.to_radians()];
+/// let mut validity = [0b111u8];
+///
+/// transform_buffers(&src, &dst, &mut x, &mut y, Some(&mut validity)).unwrap();
+/// assert_eq!(x[0], 0.);
+/// // Failed point is set to null
+/// assert_eq!(validity, [0b101]);
+/// ```
+pub fn transform_buffers(
+    src: &Proj,
+    dst: &Proj,
+    x: &mut [f64],
+    y: &mut [f64],
+    mut validity: Option<&mut [u8]>,
+) -> Result<()> {
+    let len = x.len();
+    if y.len() != len || validity.as_ref().is_some_and(|v| v.len() < len.div_ceil(8)) {
+        return Err(Error::InvalidParameterValue("Buffers sizes do not match"));
+    }
+
+    let transformation = Transformation::new(src, dst);
+    for (i, (x, y)) in x.iter_mut().zip(y.iter_mut()).enumerate() {
+        let (byte, mask) = (i / 8, 1u8 << (i % 8));
+        if let Some(v) = validity.as_deref() {
+            if v[byte] & mask == 0 {
+                continue;
+            }
+        }
+        let mut pt = (*x, *y);
+        if transformation.apply(&mut pt).is_ok() {
+            (*x, *y) = pt;
+        } else if let Some(v) = validity.as_deref_mut() {
+            v[byte] &= !mask;
+        } else {
+            (*x, *y) = (f64::NAN, f64::NAN);
+        }
+    }
+    Ok(())
+}
+
+/// Transform columnar x and y raw buffers in place
+///
+/// Same as [`transform_buffers`] for buffers shared through FFI
+/// (i.e. the Arrow C data interface). `null_bitmap` may be null.
+///
+/// # Safety
+///
+/// `x_ptr` and `y_ptr` must be valid for reads and writes of `len`
+/// values, and `null_bitmap`, if not null, of `len.div_ceil(8)` bytes.
+/// The buffers must not overlap.
+pub unsafe fn transform_raw_buffers(
+    src: &Proj,
+    dst: &Proj,
+    x_ptr: *mut f64,
+    y_ptr: *mut f64,
+    null_bitmap: *mut u8,
+    len: usize,
+) -> Result<()> {
+    if len == 0 {
+        return Ok(());
+    }
+    if x_ptr.is_null() || y_ptr.is_null() {
+        return Err(Error::InvalidParameterValue("Null coordinates buffer"));
+    }
+    let x = std::slice::from_raw_parts_mut(x_ptr, len);
+    let y = std::slice::from_raw_parts_mut(y_ptr, len);
+    let validity = if null_bitmap.is_null() {
+        None
+    } else {
+        Some(std::slice::from_raw_parts_mut(null_bitmap, len.div_ceil(8)))
+    };
+    transform_buffers(src, dst, x, y, validity)
+}
+
 //
-// Transform an array of 3-tuple:
+// Transform a slice of transformable items
 //
-impl Transform for [(f64, f64, f64)] {
+impl<T: Transform> Transform for [T] {
     fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
         self.iter_mut()
-            .try_for_each(|xyz| xyz.transform_coordinates(f))
+            .try_for_each(|item| item.transform_coordinates(f))
     }
 }
 
 //
-// Transform an array of 2-tuple:
+// Transform a vector of transformable items
 //
-impl Transform for [(f64, f64)] {
+impl<T: Transform> Transform for Vec<T> {
     fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
-        self.iter_mut()
-            .try_for_each(|xy| xy.transform_coordinates(f))
+        self.as_mut_slice().transform_coordinates(f)
     }
 }
@@ -0,0 +1,177 @@
+//!
+//! Coordinate types with explicit units
+//!
+//! Transformations work on radians for geographic coordinates: these
+//! wrappers make the unit explicit at construction, so that degrees
+//! are not passed by mistake.
+//!
+use crate::{
+    errors::Result,
+    transform::{Transform, TransformClosure},
+};
+
+/// Geographic coordinate, stored in radians
+///
+/// Longitude and latitude are given in degrees with
+/// [`from_degrees`](Self::from_degrees) and read back with
+/// [`to_degrees`](Self::to_degrees); the conversion to the radians
+/// expected by transformations is done on the way in and out.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::adaptors::GeographicCoordinate;
+/// use proj4rs::transform::transform;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+/// let dst = Proj::from_proj_string("+proj=latlong +ellps=GRS80 +pm=paris").unwrap();
+///
+/// let mut pt = GeographicCoordinate::from_degrees(2.337229, 48.836389);
+/// transform(&src, &dst, &mut pt).unwrap();
+///
+/// let (lon, lat) = pt.to_degrees();
+/// assert!(lon.abs() < 1.0e-6);
+/// assert!((lat - 48.836389).abs() < 1.0e-12);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GeographicCoordinate {
+    lam: f64,
+    phi: f64,
+    z: f64,
+}
+
+impl GeographicCoordinate {
+    /// Create a coordinate from longitude and latitude in degrees
+    pub fn from_degrees(lon: f64, lat: f64) -> Self {
+        Self::from_radians(lon.to_radians(), lat.to_radians())
+    }
+    /// Create a coordinate from longitude and latitude in radians
+    pub fn from_radians(lam: f64, phi: f64) -> Self {
+        Self { lam, phi, z: 0. }
+    }
+    /// Set the height
+    pub fn with_z(self, z: f64) -> Self {
+        Self { z, ..self }
+    }
+    /// Return longitude and latitude in degrees
+    pub fn to_degrees(&self) -> (f64, f64) {
+        (self.lam.to_degrees(), self.phi.to_degrees())
+    }
+    /// Return longitude and latitude in radians
+    pub fn to_radians(&self) -> (f64, f64) {
+        (self.lam, self.phi)
+    }
+    /// Return the height
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+    /// Return the coordinate as expected by
+    /// [`transform`](crate::transform::transform), i.e. in radians
+    pub fn into_transform_input(self) -> (f64, f64, f64) {
+        (self.lam, self.phi, self.z)
+    }
+}
+
+/// Transform between geographic CRS
+///
+/// Both the source and the destination CRS must be geographic: for
+/// projecting coordinates, transform the
+/// [`into_transform_input`](GeographicCoordinate::into_transform_input)
+/// tuple and convert it with [`ProjectedCoordinate::from`].
+impl Transform for GeographicCoordinate {
+    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
+        (self.lam, self.phi, self.z) = f(self.lam, self.phi, self.z)?;
+        Ok(())
+    }
+}
+
+/// Projected or geocentric coordinate
+///
+/// The [`Transform`] implementation is meant for transformations
+/// between projected or geocentric CRS.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::adaptors::{GeographicCoordinate, ProjectedCoordinate};
+/// use proj4rs::transform::transform;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+/// let dst = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+///
+/// let mut pt = GeographicCoordinate::from_degrees(3., 0.).into_transform_input();
+/// transform(&src, &dst, &mut pt).unwrap();
+///
+/// let pt = ProjectedCoordinate::from(pt);
+/// assert!((pt.x - 500000.).abs() < 1.0e-6);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ProjectedCoordinate {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl ProjectedCoordinate {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+    /// Return the coordinate as expected by
+    /// [`transform`](crate::transform::transform)
+    pub fn into_transform_input(self) -> (f64, f64, f64) {
+        (self.x, self.y, self.z)
+    }
+}
+
+impl From<(f64, f64, f64)> for ProjectedCoordinate {
+    fn from((x, y, z): (f64, f64, f64)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Transform for ProjectedCoordinate {
+    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
+        (self.x, self.y, self.z) = f(self.x, self.y, self.z)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+    use crate::{transform::transform, Proj};
+
+    #[test]
+    fn geographic_coordinate_units() {
+        let pt = GeographicCoordinate::from_degrees(2., 1.).with_z(10.);
+        assert_eq!(pt.to_radians(), (2f64.to_radians(), 1f64.to_radians()));
+        assert_eq!(
+            pt,
+            GeographicCoordinate::from_radians(2f64.to_radians(), 1f64.to_radians()).with_z(10.)
+        );
+        assert_eq!(pt.into_transform_input().2, 10.);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn transforms_coordinates() {
+        let etmerc = Proj::from_proj_string("+proj=etmerc +ellps=GRS80").unwrap();
+        let utm = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+
+        // (2°, 1°) from etmerc to utm zone 31
+        let mut pt = ProjectedCoordinate::new(222650.79679758527, 110642.22941193319, 0.);
+        transform(&etmerc, &utm, &mut pt).unwrap();
+        assert_abs_diff_eq!(pt.x, 388736.187721094, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.y, 110547.10569680421, epsilon = 1.0e-6);
+
+        let latlong = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+        let paris = Proj::from_proj_string("+proj=latlong +ellps=GRS80 +pm=paris").unwrap();
+
+        let mut pt = GeographicCoordinate::from_degrees(2., 1.).with_z(10.);
+        transform(&latlong, &paris, &mut pt).unwrap();
+        let (lon, lat) = pt.to_degrees();
+        assert_abs_diff_eq!(lon, 2. - 2.337229166667, epsilon = 1.0e-10);
+        assert_abs_diff_eq!(lat, 1., epsilon = 1.0e-10);
+        assert_eq!(pt.z(), 10.);
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
 impl Transform for Coord {
     fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
         let mut xy = (self.x, self.y);
-        (&mut xy).transform_coordinates(f)?;
+        xy.transform_coordinates(f)?;
         *self = Coord::from(xy);
         Ok(())
     }
@@ -81,6 +81,9 @@ impl Transform for Rect {
     }
 }
 
+// Tuple fields are deprecated in recent geo-types but there is
+// no mutable accessor for vertices
+#[allow(deprecated)]
 impl Transform for Triangle {
     fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
         self.0.transform_coordinates(f)?;
@@ -208,7 +211,50 @@ mod tests {
         assert_cord_eq(COORD_1, rect.max());
     }
 
-    fn transform_helper<T: Transform>(geometry: &mut T) {
+    #[test]
+    fn transforms_coord_slice() {
+        let mut coords = vec![COORD_0; 10];
+        transform_helper(coords.as_mut_slice());
+        coords
+            .into_iter()
+            .for_each(|coord| assert_cord_eq(COORD_1, coord));
+    }
+
+    #[test]
+    fn transforms_polygon_to_webmercator() {
+        let deg = |x: f64, y: f64| Coord {
+            x: x.to_radians(),
+            y: y.to_radians(),
+        };
+        let exterior = LineString::new(vec![
+            deg(-5., 48.),
+            deg(10., 48.),
+            deg(10., 60.),
+            deg(-5., 48.),
+        ]);
+        let mut polygons = MultiPolygon::new(vec![
+            Polygon::new(exterior.clone(), vec![]),
+            Polygon::new(exterior, vec![LineString::new(vec![deg(2., 1.)])]),
+        ]);
+
+        let from = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+        let to = Proj::from_proj_string("+proj=webmerc +datum=WGS84").unwrap();
+        transform(&from, &to, &mut polygons).unwrap();
+
+        polygons.iter().for_each(|polygon| {
+            let exterior = polygon.exterior();
+            assert_abs_diff_eq!(exterior.0[0].x, -556597.4539663679, epsilon = 1.0e-6);
+            assert_abs_diff_eq!(exterior.0[0].y, 6106854.834885075, epsilon = 1.0e-6);
+            assert_abs_diff_eq!(exterior.0[2].x, 1113194.9079327357, epsilon = 1.0e-6);
+            assert_abs_diff_eq!(exterior.0[2].y, 8399737.889818355, epsilon = 1.0e-6);
+        });
+
+        let interior = &polygons.0[1].interiors()[0];
+        assert_abs_diff_eq!(interior.0[0].x, 222638.98158654713, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(interior.0[0].y, 111325.14286638486, epsilon = 1.0e-6);
+    }
+
+    fn transform_helper<T: Transform + ?Sized>(geometry: &mut T) {
         let from = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
         let to = Proj::from_proj_string("+proj=etmerc +ellps=GRS80").unwrap();
         transform(&from, &to, geometry).unwrap();
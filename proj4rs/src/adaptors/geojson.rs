@@ -0,0 +1,281 @@
+//!
+//! Transform GeoJSON objects
+//!
+//! Positions of geographic coordinates are in degrees, as
+//! specified by GeoJSON.
+//!
+use serde_json::{Map, Value};
+
+use crate::errors::{Error, Result};
+use crate::proj::Proj;
+use crate::transform::Transformation;
+
+/// Transform in place all the positions of a GeoJSON object
+///
+/// `geojson` may be a geometry, a `Feature` or a `FeatureCollection`.
+/// Positions are transformed from `src` to `dst`; the deprecated
+/// `crs` member is ignored.
+///
+/// When `src` or `dst` is geographic, the corresponding
+/// coordinates are in degrees.
+///
+/// The altitude is only transformed when present in the position,
+/// additional position elements are left unchanged. `bbox` members
+/// are removed, since they no longer match the positions.
+///
+/// On error, `geojson` is left unchanged.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::adaptors::geojson::transform_geojson;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+/// let dst = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+///
+/// let mut geojson = serde_json::json!({
+///     "type": "Point",
+///     "coordinates": [3.0, 0.0]
+/// });
+/// transform_geojson(&src, &dst, &mut geojson).unwrap();
+/// let x = geojson["coordinates"][0].as_f64().unwrap();
+/// assert!((x - 500000.).abs() < 1.0e-6);
+/// ```
+pub fn transform_geojson(src: &Proj, dst: &Proj, geojson: &mut Value) -> Result<()> {
+    let transformation = Transformation::new(src, dst);
+    let mut result = geojson.clone();
+    transform_object(&mut result, &mut |position| {
+        let coord = |i: usize| position.get(i).and_then(Value::as_f64);
+        let (x, y, z) = match (coord(0), coord(1)) {
+            (Some(x), Some(y)) => (x, y, coord(2)),
+            _ => return Err(Error::InvalidGeoJson("invalid position")),
+        };
+
+        let mut point = (x, y, z.unwrap_or(0.));
+        if src.is_latlong() {
+            point.0 = point.0.to_radians();
+            point.1 = point.1.to_radians();
+        }
+        transformation.apply(&mut point)?;
+        if dst.is_latlong() {
+            point.0 = point.0.to_degrees();
+            point.1 = point.1.to_degrees();
+        }
+
+        position[0] = point.0.into();
+        position[1] = point.1.into();
+        if z.is_some() {
+            position[2] = point.2.into();
+        }
+        Ok(())
+    })?;
+    *geojson = result;
+    Ok(())
+}
+
+fn transform_object<F>(object: &mut Value, f: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Vec<Value>) -> Result<()>,
+{
+    let object = object
+        .as_object_mut()
+        .ok_or(Error::InvalidGeoJson("expecting an object"))?;
+
+    object.remove("bbox");
+
+    let depth = match object.get("type").and_then(Value::as_str) {
+        Some("FeatureCollection") => {
+            return member(object, "features")?
+                .iter_mut()
+                .try_for_each(|feature| transform_object(feature, f));
+        }
+        Some("GeometryCollection") => {
+            return member(object, "geometries")?
+                .iter_mut()
+                .try_for_each(|geometry| transform_object(geometry, f));
+        }
+        Some("Feature") => {
+            return match object.get_mut("geometry") {
+                None | Some(Value::Null) => Ok(()),
+                Some(geometry) => transform_object(geometry, f),
+            };
+        }
+        Some("Point") => 0,
+        Some("MultiPoint" | "LineString") => 1,
+        Some("MultiLineString" | "Polygon") => 2,
+        Some("MultiPolygon") => 3,
+        _ => return Err(Error::InvalidGeoJson("unknown type")),
+    };
+
+    transform_positions(
+        object
+            .get_mut("coordinates")
+            .ok_or(Error::InvalidGeoJson("missing coordinates"))?,
+        depth,
+        f,
+    )
+}
+
+// Return the array member `name`
+fn member<'a>(object: &'a mut Map<String, Value>, name: &str) -> Result<&'a mut Vec<Value>> {
+    object
+        .get_mut(name)
+        .and_then(Value::as_array_mut)
+        .ok_or(Error::InvalidGeoJson("expecting an array"))
+}
+
+// Transform positions nested at `depth` in arrays
+fn transform_positions<F>(value: &mut Value, depth: usize, f: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Vec<Value>) -> Result<()>,
+{
+    let array = value
+        .as_array_mut()
+        .ok_or(Error::InvalidGeoJson("expecting an array"))?;
+    if depth == 0 {
+        f(array)
+    } else {
+        array
+            .iter_mut()
+            .try_for_each(|v| transform_positions(v, depth - 1, f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    // (2°, 1°) in utm zone 31
+    const X: f64 = 388736.187721094;
+    const Y: f64 = 110547.10569680421;
+
+    const EPS: f64 = 1.0e-6;
+
+    fn projs() -> (Proj, Proj) {
+        (
+            Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap(),
+            Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap(),
+        )
+    }
+
+    fn assert_position(position: &Value, x: f64, y: f64) {
+        assert_abs_diff_eq!(position[0].as_f64().unwrap(), x, epsilon = EPS);
+        assert_abs_diff_eq!(position[1].as_f64().unwrap(), y, epsilon = EPS);
+    }
+
+    #[test]
+    fn transforms_geometries() {
+        let (src, dst) = projs();
+
+        let mut geojson = json!({
+            "type": "GeometryCollection",
+            "geometries": [
+                { "type": "Point", "coordinates": [2., 1.] },
+                { "type": "MultiPoint", "coordinates": [[2., 1.], [2., 1.]] },
+                { "type": "LineString", "coordinates": [[2., 1.], [2., 1.]] },
+                { "type": "MultiLineString", "coordinates": [[[2., 1.], [2., 1.]]] },
+                { "type": "Polygon", "coordinates": [[[2., 1.], [2., 1.], [2., 1.]]] },
+                { "type": "MultiPolygon", "coordinates": [[[[2., 1.], [2., 1.], [2., 1.]]]] },
+            ]
+        });
+        transform_geojson(&src, &dst, &mut geojson).unwrap();
+
+        let geometries = geojson["geometries"].as_array().unwrap();
+        assert_position(&geometries[0]["coordinates"], X, Y);
+        assert_position(&geometries[1]["coordinates"][1], X, Y);
+        assert_position(&geometries[2]["coordinates"][1], X, Y);
+        assert_position(&geometries[3]["coordinates"][0][1], X, Y);
+        assert_position(&geometries[4]["coordinates"][0][2], X, Y);
+        assert_position(&geometries[5]["coordinates"][0][0][2], X, Y);
+    }
+
+    #[test]
+    fn transforms_feature_collection() {
+        let (src, dst) = projs();
+
+        let mut geojson = json!({
+            "type": "FeatureCollection",
+            "crs": { "type": "name", "properties": { "name": "EPSG:2154" } },
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": { "coordinates": [2., 1.] },
+                    "geometry": { "type": "Point", "coordinates": [2., 1., 10., 5.] }
+                },
+                { "type": "Feature", "properties": null, "geometry": null },
+            ]
+        });
+        transform_geojson(&src, &dst, &mut geojson).unwrap();
+
+        let feature = &geojson["features"][0];
+        assert_position(&feature["geometry"]["coordinates"], X, Y);
+        assert_eq!(feature["geometry"]["coordinates"][2], json!(10.));
+        assert_eq!(feature["geometry"]["coordinates"][3], json!(5.));
+        assert_eq!(feature["properties"]["coordinates"], json!([2., 1.]));
+
+        // Back to degrees
+        transform_geojson(&dst, &src, &mut geojson).unwrap();
+        assert_position(&geojson["features"][0]["geometry"]["coordinates"], 2., 1.);
+    }
+
+    #[test]
+    fn removes_bbox() {
+        let (src, dst) = projs();
+
+        let mut geojson = json!({
+            "type": "FeatureCollection",
+            "bbox": [2., 1., 2., 1.],
+            "features": [{
+                "type": "Feature",
+                "bbox": [2., 1., 2., 1.],
+                "properties": { "bbox": [2., 1., 2., 1.] },
+                "geometry": { "type": "Point", "bbox": [2., 1., 2., 1.], "coordinates": [2., 1.] }
+            }]
+        });
+        transform_geojson(&src, &dst, &mut geojson).unwrap();
+
+        let feature = &geojson["features"][0];
+        assert!(geojson.get("bbox").is_none());
+        assert!(feature.get("bbox").is_none());
+        assert!(feature["geometry"].get("bbox").is_none());
+        assert_eq!(feature["properties"]["bbox"], json!([2., 1., 2., 1.]));
+    }
+
+    #[test]
+    fn unchanged_on_error() {
+        let (src, dst) = projs();
+
+        let mut geojson = json!({
+            "type": "GeometryCollection",
+            "bbox": [2., 1., 2., 1.],
+            "geometries": [
+                { "type": "Point", "coordinates": [2., 1.] },
+                { "type": "Point", "coordinates": [2.] },
+            ]
+        });
+        let orig = geojson.clone();
+        assert!(transform_geojson(&src, &dst, &mut geojson).is_err());
+        assert_eq!(geojson, orig);
+    }
+
+    #[test]
+    fn invalid_geojson() {
+        let (src, dst) = projs();
+
+        for mut geojson in [
+            json!([2., 1.]),
+            json!({ "type": "Circle", "coordinates": [2., 1.] }),
+            json!({ "type": "Point" }),
+            json!({ "type": "Point", "coordinates": [2.] }),
+            json!({ "type": "LineString", "coordinates": [2., 1.] }),
+            json!({ "type": "FeatureCollection", "features": {} }),
+        ] {
+            assert!(matches!(
+                transform_geojson(&src, &dst, &mut geojson),
+                Err(Error::InvalidGeoJson(_))
+            ));
+        }
+    }
+}
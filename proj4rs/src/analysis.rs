@@ -0,0 +1,300 @@
+//!
+//! Projection distortion analysis
+//!
+//! Compute the finite difference Jacobian of the forward projection,
+//! and Tissot's indicatrix and grid factors from it.
+//!
+//! See Snyder, "Map Projections: A Working Manual", pp. 20-26
+//!
+use crate::errors::{Error, Result};
+use crate::math::adjlon;
+use crate::math::consts::{EPS_10, FRAC_PI_2};
+use crate::proj::{Proj, ProjType};
+
+// Step for finite differences in radians
+const DELTA: f64 = 1.0e-7;
+
+/// Tissot's indicatrix at a given location
+#[derive(Debug, Copy, Clone)]
+pub struct TissotIndicatrix {
+    /// Scale factor along the meridian
+    pub scale_h: f64,
+    /// Scale factor along the parallel
+    pub scale_k: f64,
+    /// Maximum angular distortion in degrees
+    pub max_angular_distortion_deg: f64,
+    /// Areal scale factor
+    pub areal_distortion: f64,
+}
+
+/// Point scale factor and meridian convergence at a given location
+#[derive(Debug, Copy, Clone)]
+pub struct GridFactors {
+    /// Point scale factor
+    pub scale_factor: f64,
+    /// Angle from true north to grid north, in degrees
+    pub meridian_convergence_deg: f64,
+}
+
+/// Compute the Jacobian of the forward projection at `lam`, `phi`
+///
+/// Input coordinates are in radians, and the partial derivatives
+/// `[[dx/dlam, dx/dphi], [dy/dlam, dy/dphi]]` are in meters per radian.
+/// They are computed by central finite differences.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::analysis::forward_gradient;
+///
+/// let p = Proj::from_proj_string("+proj=merc +R=6378137").unwrap();
+/// let j = forward_gradient(&p, 0., 0.).unwrap();
+/// assert!((j[0][0] - 6378137.).abs() < 1.0e-3);
+/// assert!(j[0][1].abs() < 1.0e-3 && j[1][0].abs() < 1.0e-3);
+/// ```
+pub fn forward_gradient(proj: &Proj, lam: f64, phi: f64) -> Result<[[f64; 2]; 2]> {
+    let (x_l, y_l, x_p, y_p) = jacobian(proj, lam, phi)?;
+    Ok([[x_l, x_p], [y_l, y_p]])
+}
+
+// Partial derivatives (x_l, y_l, x_p, y_p) of the forward projection,
+// in meters, by central differences
+fn jacobian(proj: &Proj, lam: f64, phi: f64) -> Result<(f64, f64, f64, f64)> {
+    if proj.projection_type() != ProjType::Other {
+        return Err(Error::InvalidParameterValue(
+            "Distortion analysis requires a projected CRS",
+        ));
+    }
+
+    if phi.abs() + DELTA >= FRAC_PI_2 {
+        return Err(Error::LatitudeOutOfRange);
+    }
+
+    let d = proj.data();
+    let a = d.ellps.a;
+    let projection = proj.projection();
+
+    let fwd = |lam: f64, phi: f64| {
+        projection
+            .forward(adjlon(lam - d.lam0), phi, 0.)
+            .map(|(x, y, _)| (a * x, a * y))
+    };
+
+    let (x1, y1) = fwd(lam + DELTA, phi)?;
+    let (x0, y0) = fwd(lam - DELTA, phi)?;
+    let (x_l, y_l) = ((x1 - x0) / (2. * DELTA), (y1 - y0) / (2. * DELTA));
+
+    let (x1, y1) = fwd(lam, phi + DELTA)?;
+    let (x0, y0) = fwd(lam, phi - DELTA)?;
+    let (x_p, y_p) = ((x1 - x0) / (2. * DELTA), (y1 - y0) / (2. * DELTA));
+
+    Ok((x_l, y_l, x_p, y_p))
+}
+
+// Radius of curvature in the prime vertical
+fn prime_vertical_radius(proj: &Proj, phi: f64) -> f64 {
+    let d = proj.data();
+    d.ellps.a / (1. - d.ellps.es * phi.sin().powi(2)).sqrt()
+}
+
+/// Compute the point scale factor and the meridian convergence
+/// at `lam_deg`, `phi_deg`
+///
+/// Analytic values are used when the projection provides them
+/// (`etmerc`, `utm`); otherwise they are computed from finite
+/// differences and the scale factor is the scale along the parallel,
+/// which is the point scale factor for conformal projections.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::analysis::grid_factors;
+///
+/// let p = Proj::from_proj_string("+proj=utm +zone=31 +ellps=WGS84").unwrap();
+/// let f = grid_factors(&p, 3.0, 45.0).unwrap();
+/// assert!((f.scale_factor - 0.9996).abs() < 1.0e-12);
+/// assert!(f.meridian_convergence_deg.abs() < 1.0e-12);
+/// ```
+pub fn grid_factors(proj: &Proj, lam_deg: f64, phi_deg: f64) -> Result<GridFactors> {
+    let (lam, phi) = (lam_deg.to_radians(), phi_deg.to_radians());
+
+    let analytic = match proj.projection_type() {
+        ProjType::Other if phi.abs() + DELTA < FRAC_PI_2 => proj
+            .projection()
+            .factors(adjlon(lam - proj.data().lam0), phi),
+        _ => None,
+    };
+
+    let (k, gamma) = match analytic {
+        Some(factors) => factors,
+        None => {
+            let (x_l, y_l, x_p, y_p) = jacobian(proj, lam, phi)?;
+            let n = prime_vertical_radius(proj, phi);
+            (x_l.hypot(y_l) / (n * phi.cos()), -x_p.atan2(y_p))
+        }
+    };
+
+    Ok(GridFactors {
+        scale_factor: k,
+        meridian_convergence_deg: gamma.to_degrees(),
+    })
+}
+
+/// Compute Tissot's indicatrix at `lam_deg`, `phi_deg`
+///
+/// When meridians and parallels are orthogonal the maximum
+/// angular distortion is `2 * asin((k - h) / (k + h))`; in the general
+/// case the semi-axes of the indicatrix are used instead of `h` and `k`.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::analysis::tissot_at;
+///
+/// let p = Proj::from_proj_string("+proj=merc +ellps=WGS84").unwrap();
+/// let t = tissot_at(&p, 2.0, 45.0).unwrap();
+/// assert!(t.max_angular_distortion_deg.abs() < 1.0e-4);
+/// ```
+pub fn tissot_at(proj: &Proj, lam_deg: f64, phi_deg: f64) -> Result<TissotIndicatrix> {
+    let (lam, phi) = (lam_deg.to_radians(), phi_deg.to_radians());
+    let (x_l, y_l, x_p, y_p) = jacobian(proj, lam, phi)?;
+
+    // Radii of curvature in the meridian and the prime vertical
+    let es = proj.data().ellps.es;
+    let (sinphi, cosphi) = phi.sin_cos();
+    let w = 1. - es * sinphi * sinphi;
+    let n = prime_vertical_radius(proj, phi);
+    let m = n * (1. - es) / w;
+
+    let h = x_p.hypot(y_p) / m;
+    let k = x_l.hypot(y_l) / (n * cosphi);
+    let s = (y_p * x_l - x_p * y_l) / (m * n * cosphi);
+
+    // Sum and difference of the indicatrix semi axes
+    let t = h * h + k * k;
+    let sum = (t + 2. * s).max(0.).sqrt();
+    let diff = (t - 2. * s).max(0.).sqrt();
+
+    let omega = if sum > EPS_10 {
+        2. * (diff / sum).min(1.).asin()
+    } else {
+        0.
+    };
+
+    Ok(TissotIndicatrix {
+        scale_h: h,
+        scale_k: k,
+        max_angular_distortion_deg: omega.to_degrees(),
+        areal_distortion: s,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn tissot_conformal() {
+        let p = Proj::from_proj_string(
+            "+proj=lcc +lat_1=49 +lat_2=44 +lat_0=46.5 +lon_0=3 +x_0=700000 +y_0=6600000 +ellps=GRS80",
+        )
+        .unwrap();
+
+        let t = tissot_at(&p, 5.0, 47.0).unwrap();
+        assert_abs_diff_eq!(t.max_angular_distortion_deg, 0., epsilon = 1.0e-4);
+        assert_abs_diff_eq!(t.scale_h, t.scale_k, epsilon = 1.0e-8);
+        assert_abs_diff_eq!(t.areal_distortion, t.scale_h * t.scale_k, epsilon = 1.0e-8);
+    }
+
+    #[test]
+    fn tissot_plate_carree() {
+        let p = Proj::from_proj_string("+proj=eqc +R=6378137").unwrap();
+
+        // h = 1, k = 1/cos(phi)
+        let t = tissot_at(&p, 10.0, 60.0).unwrap();
+        assert_abs_diff_eq!(t.scale_h, 1., epsilon = 1.0e-8);
+        assert_abs_diff_eq!(t.scale_k, 2., epsilon = 1.0e-8);
+        assert_abs_diff_eq!(t.areal_distortion, 2., epsilon = 1.0e-8);
+        assert_abs_diff_eq!(
+            t.max_angular_distortion_deg,
+            2. * (1.0f64 / 3.).asin().to_degrees(),
+            epsilon = 1.0e-6
+        );
+    }
+
+    #[test]
+    fn grid_factors_numerical() {
+        // Analytic and finite differences factors agree
+        let p = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+        let f = grid_factors(&p, 5.0, 45.0).unwrap();
+
+        let (lam, phi) = (5f64.to_radians(), 45f64.to_radians());
+        let (x_l, y_l, x_p, y_p) = jacobian(&p, lam, phi).unwrap();
+        let n = prime_vertical_radius(&p, phi);
+        assert_abs_diff_eq!(
+            f.scale_factor,
+            x_l.hypot(y_l) / (n * phi.cos()),
+            epsilon = 1.0e-8
+        );
+        assert_abs_diff_eq!(
+            f.meridian_convergence_deg,
+            (-x_p.atan2(y_p)).to_degrees(),
+            epsilon = 1.0e-6
+        );
+
+        // Mercator: no convergence
+        let p = Proj::from_proj_string("+proj=merc +ellps=WGS84").unwrap();
+        let f = grid_factors(&p, 10.0, 60.0).unwrap();
+        assert_abs_diff_eq!(f.meridian_convergence_deg, 0., epsilon = 1.0e-8);
+    }
+
+    #[test]
+    fn forward_gradient_merc() {
+        // Spherical mercator: x = R * lam, y = R * ln(tan(pi/4 + phi/2))
+        let p = Proj::from_proj_string("+proj=merc +R=6378137").unwrap();
+        let phi = 50f64.to_radians();
+        let j = forward_gradient(&p, 0.2, phi).unwrap();
+        assert_abs_diff_eq!(j[0][0], 6378137., epsilon = 1.0e-2);
+        assert_abs_diff_eq!(j[1][1], 6378137. / phi.cos(), epsilon = 1.0e-2);
+        assert_abs_diff_eq!(j[0][1], 0., epsilon = 1.0e-6);
+        assert_abs_diff_eq!(j[1][0], 0., epsilon = 1.0e-6);
+
+        // Ellipsoidal: dy/dphi = a * (1 - es) / ((1 - es * sin(phi)^2) * cos(phi))
+        let p = Proj::from_proj_string("+proj=merc +ellps=WGS84 +lon_0=10").unwrap();
+        let es = p.data().ellps.es;
+        let j = forward_gradient(&p, 0., phi).unwrap();
+        assert_abs_diff_eq!(j[0][0], 6378137., epsilon = 1.0e-2);
+        assert_abs_diff_eq!(
+            j[1][1],
+            6378137. * (1. - es) / ((1. - es * phi.sin().powi(2)) * phi.cos()),
+            epsilon = 1.0e-2
+        );
+        assert_abs_diff_eq!(j[0][1], 0., epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn forward_gradient_utm() {
+        // Conformal: the Jacobian is a rotation by the meridian
+        // convergence scaled by the point scale factor
+        let p = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+        let (lam, phi) = (5f64.to_radians(), 45f64.to_radians());
+        let j = forward_gradient(&p, lam, phi).unwrap();
+        let f = grid_factors(&p, 5., 45.).unwrap();
+
+        let (sin_g, cos_g) = f.meridian_convergence_deg.to_radians().sin_cos();
+        let es = p.data().ellps.es;
+        let n = prime_vertical_radius(&p, phi);
+        let m = n * (1. - es) / (1. - es * phi.sin().powi(2));
+        let (kn, km) = (f.scale_factor * n * phi.cos(), f.scale_factor * m);
+        assert_abs_diff_eq!(j[0][0], kn * cos_g, epsilon = 1.0e-2);
+        assert_abs_diff_eq!(j[1][0], kn * sin_g, epsilon = 1.0e-2);
+        assert_abs_diff_eq!(j[0][1], -km * sin_g, epsilon = 1.0e-2);
+        assert_abs_diff_eq!(j[1][1], km * cos_g, epsilon = 1.0e-2);
+    }
+
+    #[test]
+    fn tissot_invalid() {
+        let p = Proj::from_proj_string("+proj=longlat +ellps=WGS84").unwrap();
+        assert!(tissot_at(&p, 0., 0.).is_err());
+        assert!(grid_factors(&p, 0., 0.).is_err());
+        assert!(forward_gradient(&p, 0., 0.).is_err());
+    }
+}
@@ -4,7 +4,7 @@
 use crate::datums::DatumParamDefn;
 use crate::errors::{Error, Result};
 use crate::math::consts::SEC_TO_RAD;
-use crate::nadgrids::NadGrids;
+use crate::nadgrids::{AccuracyMode, InterpolationMethod, NadGrids};
 use crate::parse::FromStr;
 
 /// Datum parameters
@@ -49,8 +49,13 @@ impl DatumParams {
         }
     }
 
-    pub fn from_nadgrid_str(nadgrids: &str) -> Result<Self> {
-        NadGrids::new_grid_transform(nadgrids).map(Self::NadGrids)
+    pub fn from_nadgrid_str(
+        nadgrids: &str,
+        accuracy: AccuracyMode,
+        interpolation: InterpolationMethod,
+    ) -> Result<Self> {
+        NadGrids::new_grid_transform(nadgrids)
+            .map(|g| Self::NadGrids(g.with_accuracy(accuracy).with_interpolation(interpolation)))
     }
 
     pub fn use_nadgrids(&self) -> bool {
@@ -79,7 +84,9 @@ impl TryFrom<&DatumParamDefn> for DatumParams {
                 *rz * SEC_TO_RAD,
                 *s / 1_000_000.0 + 1.,
             )),
-            DatumParamDefn::NadGrids(s) => Self::from_nadgrid_str(s),
+            DatumParamDefn::NadGrids(s) => {
+                Self::from_nadgrid_str(s, AccuracyMode::default(), InterpolationMethod::default())
+            }
         }
     }
 }
@@ -114,6 +114,11 @@ impl Datum {
         }
     }
 
+    #[inline]
+    pub fn params(&self) -> &DatumParams {
+        &self.params
+    }
+
     #[inline]
     pub fn no_datum(&self) -> bool {
         self.params.no_datum()
@@ -130,6 +130,15 @@ pub mod constants {
         BESSEL,
         "swiss",
     );
+    // Swisstopo parameters from CH1903+ to ETRS89,
+    // rotations and scale are null
+    datum!(
+        CH1903_PLUS,
+        "ch1903+",
+        towgs84!(674.374, 15.056, 405.346, 0., 0., 0., 0.),
+        BESSEL,
+        "CH1903+",
+    );
     datum!(
         OSNI52,
         "osni52",
@@ -172,9 +181,39 @@ pub mod constants {
         INTL,
         "Reseau National Belge 1972",
     );
+    datum!(
+        GDA94,
+        "GDA94",
+        towgs84!(0., 0., 0., 0., 0., 0., 0.),
+        GRS80,
+        "Geocentric Datum of Australia 1994",
+    );
+    datum!(
+        GDA2020,
+        "GDA2020",
+        // As in PROJ: GDA2020 is aligned with WGS84 at the
+        // accuracy of the WGS84 realizations
+        towgs84!(0., 0., 0.),
+        GRS80,
+        "Geocentric Datum of Australia 2020",
+    );
+    datum!(
+        AGD66,
+        "AGD66",
+        towgs84!(-117.808, -51.536, 137.784, 0.303, 0.446, 0.234, -0.29),
+        AUST_SA,
+        "Australian Geodetic Datum 1966",
+    );
+    datum!(
+        AGD84,
+        "AGD84",
+        towgs84!(-134., -48., 149.),
+        AUST_SA,
+        "Australian Geodetic Datum 1984",
+    );
 
     /// Static datums table
-    pub(super) const DATUMS: [&DatumDefn; 17] = [
+    pub(super) const DATUMS: [&DatumDefn; 22] = [
         &WGS84,
         &GGRS87,
         &NAD83,
@@ -186,17 +225,34 @@ pub mod constants {
         &NZGD49,
         &OSGB36,
         &CH1903,
+        &CH1903_PLUS,
         &OSNI52,
         &RASSADIRAN,
         &S_JTSK,
         &BEDUARAM,
         &GUNUNG_SEGARA,
         &RNB72,
+        &GDA94,
+        &GDA2020,
+        &AGD66,
+        &AGD84,
+    ];
+
+    /// Alternative names of datums
+    pub(super) const DATUM_ALIASES: [(&str, &str); 1] = [
+        // CH1903+ is the datum of the LV95 reference frame
+        ("LV95", "ch1903+"),
     ];
 }
 
 /// Return the datum definition
+///
+/// `name` may be an alias of the datum.
 pub fn find_datum(name: &str) -> Option<&DatumDefn> {
+    let name = constants::DATUM_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map_or(name, |(_, id)| id);
     constants::DATUMS
         .iter()
         .find(|d| d.id.eq_ignore_ascii_case(name))
@@ -0,0 +1,163 @@
+//!
+//! Degrees, minutes, seconds parsing and formatting
+//!
+//! Accepted input follows the proj `dmstor` syntax: an optional sign,
+//! degrees, minutes and seconds values followed by their unit
+//! (`d` or `°`, `'` and `"`) and an optional hemisphere (`N`, `S`, `E`, `W`).
+//! A plain decimal number is read as degrees.
+//!
+//! Examples: `38d30'09"N`, `38°30'9.5"`, `-12d30`, `12.5W`.
+//!
+use crate::errors::{Error, Result};
+use crate::parse::FromStr;
+use std::fmt::Write;
+
+fn hemisphere_sign(c: char) -> Option<f64> {
+    match c {
+        'N' | 'n' | 'E' | 'e' => Some(1.),
+        'S' | 's' | 'W' | 'w' => Some(-1.),
+        _ => None,
+    }
+}
+
+fn parse_number(s: &str) -> Result<f64> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return Err(Error::ParameterValueError);
+    }
+    f64::from_str(s).map_err(|_| Error::ParameterValueError)
+}
+
+/// Parse a DMS string and return the value in degrees
+pub fn parse_dms(s: &str) -> Result<f64> {
+    let mut s = s.trim();
+
+    // Hemisphere
+    let mut sign = match s.chars().last().and_then(hemisphere_sign) {
+        Some(sign) => {
+            s = s[..s.len() - 1].trim_end();
+            sign
+        }
+        None => 1.,
+    };
+
+    if let Some(rest) = s.strip_prefix('-') {
+        sign = -sign;
+        s = rest;
+    } else if let Some(rest) = s.strip_prefix('+') {
+        s = rest;
+    }
+
+    // Plain decimal degrees
+    if s.chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+    {
+        return f64::from_str(s)
+            .map(|v| sign * v)
+            .map_err(|_| Error::ParameterValueError);
+    }
+
+    // Sequence of value/unit pairs: degrees, then minutes, then seconds
+    let mut value = 0.;
+    let mut level = 0;
+    let mut rest = s;
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let v = parse_number(&rest[..end])?;
+        let mut chars = rest[end..].chars();
+        let (next, scale) = match chars.next() {
+            Some('d' | 'D' | '°') if level < 1 => (1, 1.),
+            Some('\'' | '′') if level < 2 => (2, 60.),
+            Some('"' | '″') if level < 3 => (3, 3600.),
+            // A last value without unit is in the next unit
+            None if level < 3 => (level + 1, 60f64.powi(level)),
+            _ => return Err(Error::ParameterValueError),
+        };
+        value += v / scale;
+        level = next;
+        rest = chars.as_str().trim_start();
+    }
+
+    if level == 0 {
+        Err(Error::ParameterValueError)
+    } else {
+        Ok(sign * value)
+    }
+}
+
+/// Format an angle in degrees as a DMS string
+///
+/// Seconds are rounded to the millisecond of arc and the
+/// hemisphere is set from `is_lat`, e.g. `38°30'09"N`.
+pub fn format_dms(deg: f64, is_lat: bool) -> String {
+    let hemisphere = match (is_lat, deg < 0.) {
+        (true, false) => 'N',
+        (true, true) => 'S',
+        (false, false) => 'E',
+        (false, true) => 'W',
+    };
+
+    let total = (deg.abs() * 3_600_000.).round() as u64;
+    let (d, m, ms) = (total / 3_600_000, (total / 60_000) % 60, total % 60_000);
+
+    let mut out = format!("{d}°{m:02}'{:02}", ms / 1000);
+    if ms % 1000 != 0 {
+        let frac = format!("{:03}", ms % 1000);
+        let _ = write!(out, ".{}", frac.trim_end_matches('0'));
+    }
+    let _ = write!(out, "\"{hemisphere}");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn dms_parse() {
+        assert_abs_diff_eq!(
+            parse_dms("38d30'09\"N").unwrap(),
+            38.5025,
+            epsilon = 1.0e-12
+        );
+        assert_abs_diff_eq!(
+            parse_dms("38°30'09\"S").unwrap(),
+            -38.5025,
+            epsilon = 1.0e-12
+        );
+        assert_abs_diff_eq!(parse_dms("-12d30").unwrap(), -12.5, epsilon = 1.0e-12);
+        assert_abs_diff_eq!(parse_dms("12d30'").unwrap(), 12.5, epsilon = 1.0e-12);
+        assert_abs_diff_eq!(parse_dms("12.5W").unwrap(), -12.5, epsilon = 1.0e-12);
+        assert_abs_diff_eq!(parse_dms("+2.25").unwrap(), 2.25, epsilon = 1.0e-12);
+        assert_abs_diff_eq!(parse_dms("-1e-3").unwrap(), -0.001, epsilon = 1.0e-12);
+
+        assert!(parse_dms("").is_err());
+        assert!(parse_dms("12'30d").is_err());
+        assert!(parse_dms("12dfoo").is_err());
+    }
+
+    #[test]
+    fn dms_format() {
+        assert_eq!(format_dms(38.5025, true), "38°30'09\"N");
+        assert_eq!(format_dms(-77.0365, false), "77°02'11.4\"W");
+        assert_eq!(format_dms(0., false), "0°00'00\"E");
+        // Rounding carries over to minutes
+        assert_eq!(format_dms(-10.999_999_99, true), "11°00'00\"S");
+    }
+
+    #[test]
+    fn dms_round_trip() {
+        for (deg, is_lat) in [
+            (38.5025, true),
+            (-33.856_784, true),
+            (151.215_297, false),
+            (-0.127_758, false),
+            (90., true),
+        ] {
+            let s = format_dms(deg, is_lat);
+            assert_abs_diff_eq!(parse_dms(&s).unwrap(), deg, epsilon = 0.5e-3 / 3600.);
+        }
+    }
+}
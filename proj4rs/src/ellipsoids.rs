@@ -135,6 +135,39 @@ pub mod constants {
 
 }
 
+impl EllipsoidDefn {
+    /// Return the eccentricity squared
+    pub fn es(&self) -> f64 {
+        match self.rf_or_b {
+            InvFlat(rf) => {
+                let f = 1. / rf;
+                2. * f - f * f
+            }
+            MinorAxis(b) => (self.a * self.a - b * b) / (self.a * self.a),
+        }
+    }
+
+    /// Check if the definition matches the semi-major axis
+    /// and eccentricity squared
+    pub fn matches(&self, a: f64, es: f64) -> bool {
+        self.a == a && (self.es() - es).abs() < 1.0e-12
+    }
+}
+
+/// Return the ellipsoid definition matching the semi-major axis
+/// and eccentricity squared
+pub fn find_ellipsoid_by_params(a: f64, es: f64) -> Option<&'static EllipsoidDefn> {
+    constants::ELLIPSOIDS
+        .iter()
+        .find(|e| e.matches(a, es))
+        .copied()
+}
+
+/// Return the names of the known ellipsoids
+pub fn ellipsoid_names() -> impl Iterator<Item = &'static str> {
+    constants::ELLIPSOIDS.iter().map(|e| e.id)
+}
+
 /// Return the ellipse definition
 pub fn find_ellipsoid(name: &str) -> Option<&EllipsoidDefn> {
     constants::ELLIPSOIDS
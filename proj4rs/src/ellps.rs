@@ -36,11 +36,9 @@
 
 use crate::ellipsoids::{EllipsoidDefn, FlatteningParam};
 use crate::errors::{Error, Result};
-use crate::math::consts::EPS_10;
+use crate::math::consts::{EPS_10, FRAC_PI_2};
 use crate::parameters::ParamList;
 
-use std::ops::ControlFlow;
-
 // series coefficients for calculating ellipsoid-equivalent spheres
 const SIXTH: f64 = 1. / 6.;
 const RA4: f64 = 17. / 360.;
@@ -59,6 +57,8 @@ const TOK_R_V: &str = "R_V";
 const TOK_R_a: &str = "R_a";
 const TOK_R_g: &str = "R_g";
 const TOK_R_h: &str = "R_h";
+const TOK_R_lat_a: &str = "R_lat_a";
+const TOK_R_lat_g: &str = "R_lat_g";
 
 /// A shape parameter
 #[allow(non_camel_case_types)]
@@ -70,7 +70,7 @@ enum Shape {
     SP_b(f64),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Ellipsoid {
     // The linear parameters
     pub a: f64, // semimajor axis (radius if eccentricity==0)
@@ -124,6 +124,19 @@ impl Ellipsoid {
         self.es != 0.
     }
 
+    /// Return the radius of the sphere having the same surface area
+    /// as the ellipsoid
+    ///
+    /// Use the closed form `R^2 = a^2/2 (1 + (1-e^2)/e atanh(e))`, while `+R_A`
+    /// uses the series expansion in `es` (proj compatible).
+    pub fn authalic_sphere_radius_exact(&self) -> f64 {
+        if self.e < EPS_10 {
+            self.a
+        } else {
+            self.a * (0.5 * (1. + self.one_es * self.e.atanh() / self.e)).sqrt()
+        }
+    }
+
     /// Create sphere
     pub fn sphere(radius: f64) -> Result<Self> {
         if !(radius.is_normal() && radius > 0.) {
@@ -143,7 +156,7 @@ impl Ellipsoid {
         })
     }
 
-    #[cfg(test)]
+    /// Create ellipsoid from definition
     pub fn try_from_ellipsoid(defn: &EllipsoidDefn) -> Result<Self> {
         Self::calc_ellipsoid_params(
             defn.a,
@@ -293,32 +306,57 @@ impl Ellipsoid {
 
     fn spherification(self, params: &ParamList) -> Result<Self> {
         // Spherification parameter
-        const SPHERE_TOKENS: &[&str] = &[TOK_R_A, TOK_R_V, TOK_R_a, TOK_R_g, TOK_R_h];
-        match SPHERE_TOKENS.iter().try_for_each(|tok| {
-            if params.get(tok).is_some() {
-                let es = self.es;
-                let a = match *tok {
-                    // a sphere with same area as ellipsoid
-                    TOK_R_A => 1. - es * (SIXTH + es * (RA4 + es * RA6)),
-                    // a sphere with same volume as ellipsoid
-                    TOK_R_V => 1. - es * (SIXTH + es * (RV4 + es * RV6)),
-                    // a sphere with R = the arithmetic mean of the ellipsoid
-                    TOK_R_a => (self.a + self.b) / 2.,
-                    // a sphere with R = the geometric mean of the ellipsoid
-                    TOK_R_g => (self.a + self.b).sqrt(),
-                    // a sphere with R = the harmonic mean of the ellipsoid
-                    TOK_R_h => (2. * self.a * self.b) / (self.a + self.b),
-                    _ => unreachable!(),
-                };
-                // Update ellipsoid parameters
-                ControlFlow::Break(Self::calc_ellipsoid_params(a, SP_es(0.)))
-            } else {
-                ControlFlow::Continue(())
+        const SPHERE_TOKENS: &[&str] = &[
+            TOK_R_A,
+            TOK_R_V,
+            TOK_R_a,
+            TOK_R_g,
+            TOK_R_h,
+            TOK_R_lat_a,
+            TOK_R_lat_g,
+        ];
+        let Some((tok, p)) = SPHERE_TOKENS
+            .iter()
+            .find_map(|tok| params.get(tok).map(|p| (*tok, p)))
+        else {
+            return Ok(self);
+        };
+
+        let es = self.es;
+        let a = match tok {
+            // a sphere with same area as ellipsoid
+            TOK_R_A => self.a * (1. - es * (SIXTH + es * (RA4 + es * RA6))),
+            // a sphere with same volume as ellipsoid
+            TOK_R_V => self.a * (1. - es * (SIXTH + es * (RV4 + es * RV6))),
+            // a sphere with R = the arithmetic mean of the ellipsoid
+            TOK_R_a => (self.a + self.b) / 2.,
+            // a sphere with R = the geometric mean of the ellipsoid
+            TOK_R_g => (self.a * self.b).sqrt(),
+            // a sphere with R = the harmonic mean of the ellipsoid
+            TOK_R_h => (2. * self.a * self.b) / (self.a + self.b),
+            // a sphere with R = the arithmetic or geometric mean of the
+            // meridian (M) and normal (N) radii of curvature at latitude phi
+            // (Snyder, 1987, eq. 4-18 and 4-20)
+            TOK_R_lat_a | TOK_R_lat_g => {
+                let phi = p.try_angular_value()?;
+                if phi.abs() > FRAC_PI_2 {
+                    return Err(Error::InvalidParameterValue(
+                        "Invalid value for R_lat_a/R_lat_g: |phi| > 90",
+                    ));
+                }
+                let t = 1. - es * phi.sin().powi(2);
+                if tok == TOK_R_lat_a {
+                    // (M + N) / 2
+                    self.a * (1. - es + t) / (2. * t * t.sqrt())
+                } else {
+                    // sqrt(M * N)
+                    self.a * (1. - es).sqrt() / t
+                }
             }
-        }) {
-            ControlFlow::Break(rv) => rv,
-            _ => Ok(self),
-        }
+            _ => unreachable!(),
+        };
+        // Update ellipsoid parameters
+        Self::calc_ellipsoid_params(a, SP_es(0.))
     }
 }
 
@@ -327,6 +365,7 @@ mod tests {
     use super::*;
     use crate::ellipsoids::constants::*;
     use crate::projstring;
+    use approx::assert_abs_diff_eq;
 
     #[test]
     fn ellps_from_defn() {
@@ -384,6 +423,86 @@ mod tests {
         assert_sphere(ellps);
     }
 
+    #[test]
+    fn ellps_spherification_radius() {
+        fn radius(s: &str) -> f64 {
+            let ellps =
+                Ellipsoid::try_from_ellipsoid_with_params(&WGS84, &projstring::parse(s).unwrap())
+                    .unwrap();
+            assert_sphere(ellps.clone());
+            ellps.a
+        }
+
+        let el = Ellipsoid::try_from_ellipsoid(&WGS84).unwrap();
+
+        assert_abs_diff_eq!(radius("+R_a"), (el.a + el.b) / 2., epsilon = 1.0e-6);
+        assert_abs_diff_eq!(
+            radius("+R_h"),
+            2. * el.a * el.b / (el.a + el.b),
+            epsilon = 1.0e-6
+        );
+
+        // At the equator M = a(1-es) and N = a
+        assert_abs_diff_eq!(
+            radius("+R_lat_a=0"),
+            el.a * (1. - el.es / 2.),
+            epsilon = 1.0e-6
+        );
+        assert_abs_diff_eq!(radius("+R_lat_g=0"), el.b, epsilon = 1.0e-6);
+        // At the poles M = N = a^2/b
+        assert_abs_diff_eq!(radius("+R_lat_a=90"), el.a * el.a / el.b, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(radius("+R_lat_g=-90"), el.a * el.a / el.b, epsilon = 1.0e-6);
+        // Reference values at 45°
+        assert_abs_diff_eq!(radius("+R_lat_a=45"), 6_378_110.052_9, epsilon = 1.0e-3);
+        assert_abs_diff_eq!(radius("+R_lat_g=45"), 6_378_101.030_2, epsilon = 1.0e-3);
+    }
+
+    #[test]
+    fn ellps_mean_sphere_radius() {
+        let from_projstring = |s| {
+            Ellipsoid::try_from_ellipsoid_with_params(&WGS84, &projstring::parse(s).unwrap())
+                .unwrap()
+        };
+        let el = Ellipsoid::try_from_ellipsoid(&WGS84).unwrap();
+
+        // Series expansions, scaled by the semi-major axis
+        assert_abs_diff_eq!(from_projstring("+R_A").a, 6_371_007.181_1, epsilon = 1.0e-3);
+        assert_abs_diff_eq!(from_projstring("+R_V").a, 6_371_000.790_4, epsilon = 1.0e-3);
+        // Geometric mean
+        assert_abs_diff_eq!(
+            from_projstring("+R_g").a,
+            (el.a * el.b).sqrt(),
+            epsilon = 1.0e-6
+        );
+    }
+
+    #[test]
+    fn ellps_authalic_radius_exact() {
+        // Reference values computed with mpmath
+        let el = Ellipsoid::try_from_ellipsoid(&WGS84).unwrap();
+        assert_abs_diff_eq!(
+            el.authalic_sphere_radius_exact(),
+            6_371_007.180_918_474,
+            epsilon = 1.0e-8
+        );
+
+        let el = Ellipsoid::try_from_semi_major_axis(1., &projstring::parse("+es=0.99").unwrap())
+            .unwrap();
+        let exact = el.authalic_sphere_radius_exact();
+        assert!((exact - 0.717_663_926_047_055_9).abs() / exact < 1.0e-15);
+
+        // The series expansion used by R_A diverges for extreme ellipsoids
+        let series =
+            Ellipsoid::try_from_semi_major_axis(1., &projstring::parse("+es=0.99 +R_A").unwrap())
+                .unwrap()
+                .a;
+        assert_abs_diff_eq!(series, 0.767_219_473_214_285_7, epsilon = 1.0e-15);
+        assert!((series - exact).abs() > 0.04);
+
+        let sphere = Ellipsoid::sphere(6_400_000.).unwrap();
+        assert_eq!(sphere.authalic_sphere_radius_exact(), 6_400_000.);
+    }
+
     #[test]
     fn ellps_invalid_params() {
         fn from_projstring(s: &str) -> Result<Ellipsoid> {
@@ -395,4 +514,18 @@ mod tests {
         assert!(from_projstring("+es=-1.").is_err());
         assert!(from_projstring("+f=20.").is_err());
     }
+
+    #[test]
+    fn ellps_invalid_lat_radius() {
+        let from_projstring =
+            |s| Ellipsoid::try_from_ellipsoid_with_params(&WGS84, &projstring::parse(s).unwrap());
+        assert!(matches!(
+            from_projstring("+R_lat_a"),
+            Err(Error::NoValueParameter)
+        ));
+        assert!(matches!(
+            from_projstring("+R_lat_g=91"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+    }
 }
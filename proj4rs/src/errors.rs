@@ -18,6 +18,8 @@ pub enum Error {
     InvalidEllipsoid,
     #[error("{0}")]
     InvalidParameterValue(&'static str),
+    #[error("Unknown parameter: {0}")]
+    UnknownParameter(String),
     #[error("Invalid coordinate dimension")]
     InvalidCoordinateDimension,
     #[error("Latitude out of range")]
@@ -42,6 +44,8 @@ pub enum Error {
     NanCoordinateValue,
     #[error("Coordinate out of range")]
     CoordinateOutOfRange,
+    #[error("Coordinate out of range, likely in degrees instead of radians")]
+    CoordinateLikelyInDegrees,
     #[error("Invalid number of coordinates")]
     InvalidNumberOfCoordinates,
     #[error("Projection not found")]
@@ -82,6 +86,12 @@ pub enum Error {
     UnknownGridFormat,
     #[error("Numerical argument too  large")]
     ArgumentTooLarge,
+    #[error("Invalid PROJJSON: {0}")]
+    InvalidProjJson(&'static str),
+    #[error("Invalid GeoJSON: {0}")]
+    InvalidGeoJson(&'static str),
+    #[error("Invalid MGRS reference: {0}")]
+    InvalidMgrsReference(&'static str),
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -0,0 +1,199 @@
+//!
+//! Local cartesian frames
+//!
+//! Conversion between geocentric (ECEF) coordinates and local
+//! East-North-Up frames defined on an ellipsoid.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::Result;
+use crate::geocent::{geocentric_to_geodetic, geodetic_to_geocentric};
+
+/// Local East-North-Up frame
+///
+/// The frame is centered at a reference point given by its
+/// geodetic coordinates on the ellipsoid.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::frames::EnuFrame;
+///
+/// let wgs84 = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+///
+/// let frame = EnuFrame::new(wgs84.ellipsoid(), 45.0, 3.0, 100.0).unwrap();
+/// let (x, y, z) = frame.to_ecef(10.0, 20.0, 5.0);
+/// let (e, n, u) = frame.from_ecef(x, y, z);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnuFrame {
+    /// Semi-major axis, eccentricity squared and semi-minor axis
+    /// of the ellipsoid
+    a: f64,
+    es: f64,
+    b: f64,
+    /// Reference latitude in radians
+    ref_lat: f64,
+    /// Reference longitude in radians
+    ref_lon: f64,
+    /// Reference height above the ellipsoid in meters
+    ref_alt: f64,
+    /// Reference point in geocentric coordinates
+    origin: (f64, f64, f64),
+    sin_lat: f64,
+    cos_lat: f64,
+    sin_lon: f64,
+    cos_lon: f64,
+}
+
+impl EnuFrame {
+    /// Create a frame from reference latitude and longitude in degrees
+    /// and height above the ellipsoid in meters
+    pub fn new(ellps: &Ellipsoid, lat_deg: f64, lon_deg: f64, alt_m: f64) -> Result<Self> {
+        Self::from_geodetic_origin(ellps, lat_deg.to_radians(), lon_deg.to_radians(), alt_m)
+    }
+
+    /// Create a frame from a reference point given in geocentric coordinates
+    pub fn from_ecef_origin(ellps: &Ellipsoid, x: f64, y: f64, z: f64) -> Result<Self> {
+        let (lon, lat, alt) = geocentric_to_geodetic(x, y, z, ellps.a, ellps.es, ellps.b)?;
+        // Ensure that the origin is consistent with the
+        // geodetic reference
+        Self::from_geodetic_origin(ellps, lat, lon, alt)
+    }
+
+    /// Create a frame from reference latitude and longitude in radians
+    pub(crate) fn from_geodetic_origin(
+        ellps: &Ellipsoid,
+        lat: f64,
+        lon: f64,
+        alt: f64,
+    ) -> Result<Self> {
+        let (a, es, b) = (ellps.a, ellps.es, ellps.b);
+        let origin = geodetic_to_geocentric(lon, lat, alt, a, es)?;
+        let (sin_lat, cos_lat) = lat.sin_cos();
+        let (sin_lon, cos_lon) = lon.sin_cos();
+        Ok(Self {
+            a,
+            es,
+            b,
+            ref_lat: lat,
+            ref_lon: lon,
+            ref_alt: alt,
+            origin,
+            sin_lat,
+            cos_lat,
+            sin_lon,
+            cos_lon,
+        })
+    }
+
+    /// Reference latitude in degrees
+    pub fn ref_lat(&self) -> f64 {
+        self.ref_lat.to_degrees()
+    }
+
+    /// Reference longitude in degrees
+    pub fn ref_lon(&self) -> f64 {
+        self.ref_lon.to_degrees()
+    }
+
+    /// Reference height in meters
+    pub fn ref_alt(&self) -> f64 {
+        self.ref_alt
+    }
+
+    /// Convert geocentric coordinates to local east, north, up
+    pub fn from_ecef(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let (dx, dy, dz) = (x - self.origin.0, y - self.origin.1, z - self.origin.2);
+        let t = self.cos_lon * dx + self.sin_lon * dy;
+        (
+            -self.sin_lon * dx + self.cos_lon * dy,
+            -self.sin_lat * t + self.cos_lat * dz,
+            self.cos_lat * t + self.sin_lat * dz,
+        )
+    }
+
+    /// Convert local east, north, up coordinates to geocentric
+    pub fn to_ecef(&self, e: f64, n: f64, u: f64) -> (f64, f64, f64) {
+        let t = -self.sin_lat * n + self.cos_lat * u;
+        (
+            self.origin.0 - self.sin_lon * e + self.cos_lon * t,
+            self.origin.1 + self.cos_lon * e + self.sin_lon * t,
+            self.origin.2 + self.cos_lat * n + self.sin_lat * u,
+        )
+    }
+
+    /// Convert geodetic coordinates in radians to local east, north, up
+    pub(crate) fn geodetic_to_enu(&self, lon: f64, lat: f64, h: f64) -> Result<(f64, f64, f64)> {
+        geodetic_to_geocentric(lon, lat, h, self.a, self.es)
+            .map(|(x, y, z)| self.from_ecef(x, y, z))
+    }
+
+    /// Convert local east, north, up coordinates to geodetic
+    /// coordinates in radians
+    pub(crate) fn enu_to_geodetic(&self, e: f64, n: f64, u: f64) -> Result<(f64, f64, f64)> {
+        let (x, y, z) = self.to_ecef(e, n, u);
+        geocentric_to_geodetic(x, y, z, self.a, self.es, self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proj::Proj;
+    use approx::assert_abs_diff_eq;
+
+    fn wgs84() -> Ellipsoid {
+        Proj::from_proj_string("+proj=latlong +ellps=WGS84")
+            .unwrap()
+            .ellipsoid()
+            .clone()
+    }
+
+    #[test]
+    fn enu_one_meter_north() {
+        let (lat, lon) = (45.0f64, 3.0f64);
+        let ellps = wgs84();
+        let frame = EnuFrame::new(&ellps, lat, lon, 0.).unwrap();
+
+        // Meridional radius of curvature
+        let (a, es) = (ellps.a, ellps.es);
+        let s = lat.to_radians().sin();
+        let m = a * (1. - es) / (1. - es * s * s).powf(1.5);
+
+        let (x, y, z) =
+            geodetic_to_geocentric(lon.to_radians(), lat.to_radians() + 1. / m, 0., a, es).unwrap();
+
+        let (e, n, u) = frame.from_ecef(x, y, z);
+        assert_abs_diff_eq!(e, 0., epsilon = 1.0e-9);
+        assert_abs_diff_eq!(n, 1., epsilon = 1.0e-6);
+        assert_abs_diff_eq!(u, 0., epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn enu_roundtrip() {
+        let ellps = wgs84();
+        let frame = EnuFrame::new(&ellps, -33.5, 151.2, 42.).unwrap();
+        let (x, y, z) = frame.to_ecef(120.5, -35.25, 7.);
+        let (e, n, u) = frame.from_ecef(x, y, z);
+        assert_abs_diff_eq!(e, 120.5, epsilon = 1.0e-8);
+        assert_abs_diff_eq!(n, -35.25, epsilon = 1.0e-8);
+        assert_abs_diff_eq!(u, 7., epsilon = 1.0e-8);
+
+        let o = EnuFrame::from_ecef_origin(&ellps, x, y, z).unwrap();
+        assert_abs_diff_eq!(o.from_ecef(x, y, z).2, 0., epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn enu_ellipsoid() {
+        // On a sphere, the up axis goes through the center
+        let sphere = Ellipsoid::sphere(6370997.).unwrap();
+        let frame = EnuFrame::new(&sphere, 30., 60., 0.).unwrap();
+        let (e, n, u) = frame.from_ecef(0., 0., 0.);
+        assert_abs_diff_eq!(e, 0., epsilon = 1.0e-8);
+        assert_abs_diff_eq!(n, 0., epsilon = 1.0e-8);
+        assert_abs_diff_eq!(u, -6370997., epsilon = 1.0e-8);
+
+        // but not on the ellipsoid
+        let frame = EnuFrame::new(&wgs84(), 30., 60., 0.).unwrap();
+        assert!(frame.from_ecef(0., 0., 0.).1.abs() > 1000.);
+    }
+}
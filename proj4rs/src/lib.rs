@@ -58,6 +58,8 @@
 //! ## Optional features
 //!
 //! * **geo-types**: [geo-types](<https://docs.rs/geo-types/latest/geo_types/>) support
+//! * **geojson**: Transform GeoJSON objects parsed with [serde_json](https://docs.rs/serde_json/latest/serde_json/)
+//!   using [`transform_geojson`](adaptors::geojson::transform_geojson).
 //! * **logging**: support for logging with [log](https://docs.rs/log/latest/log/) crate.
 //!   If activated for WASM, it will use the [console-log](https://docs.rs/console_log/latest/console_log/)
 //!   adaptor.
@@ -69,6 +71,11 @@
 //!   default and disabled when compiling for WASM.
 //! * **crs-definitions**: Support for initializing projections from EPSG codes with the
 //!   [crs_definitions](https://docs.rs/crs-definitions/latest/crs_definitions/) crate.
+//! * **debug-trace**: Add [`transform_debug`](transform::transform_debug) and
+//!   [`transform_traced`](adaptors::transform_traced) for recording the
+//!   coordinates after each step of a transformation.
+//! * **gzip**: Read gzip compressed grid files with
+//!   [`read_from_file`](nadgrids::files::read_from_file).
 //!
 //! ## WKT Support
 //!
@@ -90,24 +97,34 @@
 mod datum_params;
 mod datum_transform;
 mod datums;
+mod dms;
 mod ellipsoids;
 mod ellps;
 mod geocent;
-mod math;
+pub mod math;
 mod parameters;
 mod parse;
 mod prime_meridians;
 mod projstring;
+
+#[cfg(feature = "projjson")]
+mod projjson;
 mod units;
 
 pub mod adaptors;
+pub mod analysis;
 pub mod errors;
+pub mod frames;
+pub mod mgrs;
 pub mod nadgrids;
 pub mod proj;
 pub mod projections;
 pub mod transform;
 
 // Reexport
+pub use dms::{format_dms, parse_dms};
+pub use ellipsoids::ellipsoid_names;
+pub use ellps::Ellipsoid;
 pub use proj::Proj;
 
 // Include wasm entry point for wasm32-unknown-unknown
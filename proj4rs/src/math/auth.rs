@@ -2,7 +2,10 @@
 //! Determine latitude from authalic latitude
 //!
 
-pub(crate) fn authset(es: f64) -> (f64, f64, f64) {
+/// Precompute the coefficients of the series used by [`authlat`]
+///
+/// `es` is the squared eccentricity of the ellipsoid.
+pub fn authset(es: f64) -> (f64, f64, f64) {
     const P00: f64 = 1. / 3.;
     const P01: f64 = 31. / 180.;
     const P02: f64 = 517. / 5040.;
@@ -17,7 +20,93 @@ pub(crate) fn authset(es: f64) -> (f64, f64, f64) {
     )
 }
 
-pub(crate) fn authlat(beta: f64, apa: (f64, f64, f64)) -> f64 {
+/// Return the geodetic latitude from the authalic latitude `beta`
+///
+/// `apa` are the coefficients returned by [`authset`]. The series is
+/// truncated at `es^3`.
+///
+/// ```rust
+/// use proj4rs::math::{authlat, authset, qsfn};
+///
+/// // GRS80
+/// let es = 0.006_694_380_022_900_787_6;
+/// let (e, one_es) = (f64::sqrt(es), 1. - es);
+///
+/// let phi = 45f64.to_radians();
+/// let beta = (qsfn(phi.sin(), e, one_es) / qsfn(1., e, one_es)).asin();
+/// assert!((authlat(beta, authset(es)) - phi).abs() < 1.0e-9);
+/// ```
+pub fn authlat(beta: f64, apa: (f64, f64, f64)) -> f64 {
     let t = beta + beta;
     beta + apa.0 * t.sin() + apa.1 * (t + t).sin() + apa.2 * (t + t + t).sin()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::consts::FRAC_PI_2;
+    use crate::math::qsfn;
+    use approx::assert_abs_diff_eq;
+
+    // GRS80
+    #[allow(clippy::excessive_precision)]
+    const ES: f64 = 0.006_694_380_022_900_787_6;
+
+    fn authalic(phi: f64) -> f64 {
+        let e = ES.sqrt();
+        (qsfn(phi.sin(), e, 1. - ES) / qsfn(1., e, 1. - ES)).asin()
+    }
+
+    #[test]
+    fn auth_coefficients() {
+        let apa = authset(ES);
+        assert_abs_diff_eq!(apa.0, 2.239_208_873_476_66e-3, epsilon = 1.0e-17);
+        assert_abs_diff_eq!(apa.1, 2.883_084_001_206_079e-6, epsilon = 1.0e-19);
+        assert_abs_diff_eq!(apa.2, 5.033_182_737_581_256e-9, epsilon = 1.0e-21);
+        assert_eq!(authset(0.), (0., 0., 0.));
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn auth_round_trip() {
+        // Reference authalic latitudes computed with mpmath
+        let inputs = [
+            (30., 0.521_661_408_360_635_9),
+            (45., 0.783_158_956_107_026_9),
+            (60., 1.045_256_493_205_823_9),
+            (89., 1.553_264_738_068_876_7),
+            (-45., -0.783_158_956_107_026_9),
+        ];
+
+        let apa = authset(ES);
+        for (lat, expected) in inputs {
+            let phi = f64::to_radians(lat);
+            let beta = authalic(phi);
+            assert_abs_diff_eq!(beta, expected, epsilon = 1.0e-13);
+            // The series is truncated at es^3 (~1mm on the ground)
+            assert_abs_diff_eq!(authlat(beta, apa), phi, epsilon = 1.0e-9);
+        }
+
+        // Poles and equator are preserved
+        assert_eq!(authlat(0., apa), 0.);
+        assert_abs_diff_eq!(authlat(FRAC_PI_2, apa), FRAC_PI_2, epsilon = 1.0e-15);
+    }
+
+    #[test]
+    fn auth_round_trip_ellipsoids() {
+        // WGS84, Clarke 1866, International 1924
+        for es in [
+            0.006_694_379_990_141_3f64,
+            0.006_768_657_997_291,
+            0.006_722_670_022_333,
+        ] {
+            let (e, one_es) = (es.sqrt(), 1. - es);
+            let apa = authset(es);
+            for lat in (-85..=85).step_by(5) {
+                let phi = f64::from(lat).to_radians();
+                let beta = (qsfn(phi.sin(), e, one_es) / qsfn(1., e, one_es)).asin();
+                assert_abs_diff_eq!(authlat(beta, apa), phi, epsilon = 1.0e-9);
+            }
+        }
+    }
+}
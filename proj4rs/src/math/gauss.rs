@@ -1,5 +1,9 @@
 //!
-//! Gauss
+//! Gauss conformal sphere
+//!
+//! Conformal mapping of the ellipsoid onto the sphere osculating
+//! at the latitude `phi0`, as used by the oblique stereographic
+//! projection (`sterea`).
 //!
 
 // Original copyright
@@ -27,6 +31,7 @@
 // SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 //
 use super::consts::{FRAC_PI_2, FRAC_PI_4};
+use crate::ellps::Ellipsoid;
 use crate::errors::{Error, Result};
 
 #[inline]
@@ -34,15 +39,40 @@ fn srat(esinp: f64, ratexp: f64) -> f64 {
     ((1. - esinp) / (1. + esinp)).powf(ratexp)
 }
 
-#[derive(Debug, Clone)]
-pub(crate) struct Gauss {
+/// Return the radius of the Gauss conformal sphere at `phi0`
+///
+/// This is the geometric mean `sqrt(M * N)` of the radii of curvature in
+/// the meridian and in the prime vertical, i.e. the value returned by
+/// [`gauss_ini`] scaled by the semi-major axis.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::math::gauss_sphere_radius;
+///
+/// let p = Proj::from_proj_string("+proj=longlat +ellps=GRS80").unwrap();
+/// let r = gauss_sphere_radius(52f64.to_radians(), p.ellipsoid());
+/// assert!((r - 6383287.311).abs() < 1.0e-3);
+/// ```
+pub fn gauss_sphere_radius(phi0: f64, ellps: &Ellipsoid) -> f64 {
+    let sphi = phi0.sin();
+    ellps.a * ellps.one_es.sqrt() / (1. - ellps.es * sphi * sphi)
+}
+
+/// Parameters of the conformal mapping onto the Gauss sphere
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gauss {
     c: f64,
     k: f64,
     e: f64,
     ratexp: f64,
 }
 
-pub(crate) fn gauss_ini(e: f64, phi0: f64) -> Result<(Gauss, f64, f64)> {
+/// Initialize the mapping for the eccentricity `e` at latitude `phi0`
+///
+/// Return the mapping parameters, the conformal latitude of `phi0`
+/// on the sphere, and the radius of the sphere relative to the
+/// semi-major axis.
+pub fn gauss_ini(e: f64, phi0: f64) -> Result<(Gauss, f64, f64)> {
     let es = e * e;
     let (sphi, mut cphi) = phi0.sin_cos();
 
@@ -62,7 +92,8 @@ pub(crate) fn gauss_ini(e: f64, phi0: f64) -> Result<(Gauss, f64, f64)> {
     Ok((Gauss { c, k, e, ratexp }, chi, rc))
 }
 
-pub(crate) fn gauss(lam: f64, phi: f64, en: &Gauss) -> (f64, f64) {
+/// Map geodetic coordinates to the Gauss sphere
+pub fn gauss(lam: f64, phi: f64, en: &Gauss) -> (f64, f64) {
     (
         // lam
         en.c * lam,
@@ -73,7 +104,8 @@ pub(crate) fn gauss(lam: f64, phi: f64, en: &Gauss) -> (f64, f64) {
     )
 }
 
-pub(crate) fn inv_gauss(lam: f64, mut phi: f64, en: &Gauss) -> Result<(f64, f64)> {
+/// Map coordinates on the Gauss sphere back to geodetic coordinates
+pub fn inv_gauss(lam: f64, mut phi: f64, en: &Gauss) -> Result<(f64, f64)> {
     const DEL_TOL: f64 = 1.0e-14;
     const MAX_ITER: usize = 20;
     let mut i = MAX_ITER;
@@ -106,3 +138,42 @@ pub(crate) fn inv_gauss(lam: f64, mut phi: f64, en: &Gauss) -> Result<(f64, f64)
         Err(Error::InvMeridDistConvError)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ellipsoids::constants::GRS80;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn gauss_radius() {
+        let ellps = Ellipsoid::try_from_ellipsoid(&GRS80).unwrap();
+        let (a, es) = (ellps.a, ellps.es);
+
+        for lat in [0f64, 30., 46.95240555555556, 52.15616055555555, -70., 90.] {
+            let phi0 = lat.to_radians();
+            let w = 1. - es * phi0.sin().powi(2);
+            // Radii of curvature in the meridian and the prime vertical
+            let m = a * (1. - es) / w.powf(1.5);
+            let n = a / w.sqrt();
+
+            let r = gauss_sphere_radius(phi0, &ellps);
+            assert_abs_diff_eq!(r, (m * n).sqrt(), epsilon = 1.0e-6);
+
+            let (_, _, rc) = gauss_ini(ellps.e, phi0).unwrap();
+            assert_abs_diff_eq!(r, a * rc, epsilon = 1.0e-6);
+        }
+    }
+
+    #[test]
+    fn gauss_round_trip() {
+        let ellps = Ellipsoid::try_from_ellipsoid(&GRS80).unwrap();
+        let (en, _, _) = gauss_ini(ellps.e, 52f64.to_radians()).unwrap();
+
+        let (lam, phi) = (0.1, 0.9);
+        let (l, p) = gauss(lam, phi, &en);
+        let (l, p) = inv_gauss(l, p, &en).unwrap();
+        assert_abs_diff_eq!(l, lam, epsilon = 1.0e-14);
+        assert_abs_diff_eq!(p, phi, epsilon = 1.0e-14);
+    }
+}
@@ -0,0 +1,176 @@
+//!
+//! Isometric and conformal latitudes
+//!
+//! The isometric latitude is defined by
+//! psi = asinh(tan(phi)) - e * atanh(e * sin(phi))
+//!
+//! and the conformal latitude by chi = atan(sinh(psi)).
+//!
+//! Note that `tsfn` computes `exp(-psi)` and `phi2` inverts it.
+//!
+//! ref: C.F.F. Karney, "Transverse Mercator with an accuracy of a few nanometers",
+//! J. Geodesy 85(8), 475-485 (2011)
+//!
+use super::asinh;
+
+const ISOLAT_NITER: i32 = 5;
+const TOL: f64 = 1.0e-14;
+// 2 / sqrt(f64::EPSILON)
+const TAU_MAX: f64 = 2. / 1.490_116_119_384_765_6e-8;
+
+/// Return the isometric latitude of the geographic latitude `phi`
+///
+/// `e` is the eccentricity of the ellipsoid.
+///
+/// ```rust
+/// use proj4rs::math::isometric_latitude;
+///
+/// let psi = isometric_latitude(45f64.to_radians(), 0.);
+/// assert!((psi - 0.881373587019543).abs() < 1.0e-14);
+/// ```
+pub fn isometric_latitude(phi: f64, e: f64) -> f64 {
+    asinh(phi.tan()) - e * (e * phi.sin()).atanh()
+}
+
+/// Return the geographic latitude from the isometric latitude `psi`
+///
+/// Solve for `tan(phi)` with Newton's iterations, starting
+/// from the spherical solution scaled by `1/(1-e^2)`: this converges
+/// in at most 2 or 3 iterations even for high eccentricities.
+///
+/// Large values of `psi`, infinities included, return `±pi/2`.
+pub fn inverse_isometric_latitude(psi: f64, e: f64) -> f64 {
+    let e2m = 1. - e * e;
+    let taup = psi.sinh();
+    let stol = TOL * taup.abs().max(1.);
+
+    // Near the poles, start from the large argument limit:
+    // 70 corresponds to a conformal latitude of 89.18°
+    let mut tau = if taup.abs() > 70. {
+        taup * (e * e.atanh()).exp()
+    } else {
+        taup / e2m
+    };
+    // The limit is exact beyond TAU_MAX (also catch nan)
+    if tau.is_nan() || tau.abs() >= TAU_MAX {
+        return tau.atan();
+    }
+    for _ in 0..ISOLAT_NITER {
+        let tau1 = tau.hypot(1.);
+        let sig = (e * (e * tau / tau1).atanh()).sinh();
+        let taupa = sig.hypot(1.) * tau - sig * tau1;
+        let dtau = (taup - taupa) * (1. + e2m * tau * tau) / (e2m * tau1 * taupa.hypot(1.));
+        tau += dtau;
+        if dtau.abs() < stol {
+            break;
+        }
+    }
+    tau.atan()
+}
+
+/// Return the conformal latitude of the geographic latitude `phi`
+///
+/// Use a trigonometric series in the third flattening `n` up to order 6,
+/// evaluated with Clenshaw summation. The error is below 1e-18 rad for
+/// the earth ellipsoids but grows with the eccentricity (about 1e-10 rad
+/// for `e^2 = 0.1`): for high eccentricities prefer the exact
+/// `atan(sinh(isometric_latitude(phi, e)))`.
+pub fn conformal_latitude_series(phi: f64, e: f64) -> f64 {
+    let f = 1. - (1. - e * e).sqrt();
+    let n = f / (2. - f);
+    let n2 = n * n;
+
+    #[rustfmt::skip]
+    let c = [
+        n * (-2. + n * (2. / 3. + n * (4. / 3. + n * (-82. / 45. + n * (32. / 45. + n * 4642. / 4725.))))),
+        n2 * (5. / 3. + n * (-16. / 15. + n * (-13. / 9. + n * (904. / 315. + n * -1522. / 945.)))),
+        n2 * n * (-26. / 15. + n * (34. / 21. + n * (8. / 5. + n * -12686. / 2835.))),
+        n2 * n2 * (1237. / 630. + n * (-12. / 5. + n * -24832. / 14175.)),
+        n2 * n2 * n * (-734. / 315. + n * 109598. / 31185.),
+        n2 * n2 * n2 * 444337. / 155925.,
+    ];
+
+    phi + clenshaw_sin(2. * phi, &c)
+}
+
+// Evaluate sum(c[k] * sin((k+1) * x)) with Clenshaw summation
+fn clenshaw_sin(x: f64, c: &[f64]) -> f64 {
+    let (s, co) = x.sin_cos();
+    let r = 2. * co;
+    let (mut b1, mut b2) = (0., 0.);
+    for ck in c.iter().rev() {
+        (b1, b2) = (r * b1 - b2 + ck, b1);
+    }
+    b1 * s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{phi2, tsfn};
+    use approx::assert_abs_diff_eq;
+
+    // GRS80
+    const E: f64 = 0.081_819_191_042_811;
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn isolat_values() {
+        // Reference values computed with mpmath
+        let phi = 45f64.to_radians();
+        assert_abs_diff_eq!(
+            isometric_latitude(phi, E),
+            0.8766346534113832,
+            epsilon = 1.0e-14
+        );
+        assert_abs_diff_eq!(
+            isometric_latitude(-30f64.to_radians(), E),
+            -0.5459570851651559,
+            epsilon = 1.0e-14
+        );
+        assert_abs_diff_eq!(
+            isometric_latitude(89f64.to_radians(), E),
+            4.734640408267675,
+            epsilon = 1.0e-13
+        );
+
+        assert_abs_diff_eq!(
+            conformal_latitude_series(phi, E),
+            0.7820416169556464,
+            epsilon = 1.0e-15
+        );
+        assert_abs_diff_eq!(
+            conformal_latitude_series(89f64.to_radians(), E),
+            1.553225563871898,
+            epsilon = 1.0e-15
+        );
+    }
+
+    #[test]
+    fn isolat_tsfn() {
+        let phi = 52f64.to_radians();
+        let ts = tsfn(phi, phi.sin(), E);
+        assert_abs_diff_eq!(isometric_latitude(phi, E), -ts.ln(), epsilon = 1.0e-14);
+        assert_abs_diff_eq!(
+            inverse_isometric_latitude(-ts.ln(), E),
+            phi2(ts, E).unwrap(),
+            epsilon = 1.0e-10
+        );
+    }
+
+    #[test]
+    fn isolat_roundtrip() {
+        for e in [0., E, 0.3, 0.9] {
+            for deg in [-89.9, -60., -1., 0., 15., 45., 75., 89.999] {
+                let phi = f64::to_radians(deg);
+                let psi = isometric_latitude(phi, e);
+                assert_abs_diff_eq!(inverse_isometric_latitude(psi, e), phi, epsilon = 1.0e-14);
+
+                let chi = psi.sinh().atan();
+                if e < 0.1 {
+                    assert_abs_diff_eq!(conformal_latitude_series(phi, e), chi, epsilon = 1.0e-15);
+                }
+            }
+        }
+    }
+}
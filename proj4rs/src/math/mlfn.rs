@@ -69,3 +69,122 @@ pub(crate) fn inv_mlfn(arg: f64, es: f64, en: Enfn) -> Result<f64> {
         Err(Error::InvMeridDistConvError)
     }
 }
+
+/// Coefficients for the 10th degree meridional distance series
+///
+/// Holds the rectifying radius ratio, the 5 coefficients of the
+/// forward series and the 4 coefficients of the inverse series,
+/// expanded in the third flattening `n` (Helmert's series).
+#[cfg(feature = "high-accuracy")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct En10([f64; 10]);
+
+/// Meridional distance coefficients for ellipsoid and inverse
+/// 10th degree - the truncation error of the series is below
+/// 1e-10 meters for terrestrial ellipsoids, so that the meridional
+/// distance is accurate to the floating point precision.
+///
+/// Inverse is refined to 1e-14 radians, about 1e-9 seconds.
+#[cfg(feature = "high-accuracy")]
+pub fn enfn_high(es: f64) -> En10 {
+    let n = {
+        let b = (1. - es).sqrt();
+        (1. - b) / (1. + b)
+    };
+    let n2 = n * n;
+    let n3 = n2 * n;
+    let n4 = n2 * n2;
+    let n5 = n4 * n;
+    En10([
+        // Rectifying radius divided by the semi-major axis
+        (1. + n2 * (1. / 4. + n2 * (1. / 64. + n2 * (1. / 256. + n2 * (25. / 16384.))))) / (1. + n),
+        // Forward series: rectifying latitude from latitude
+        -3. / 2. * n + 9. / 16. * n3 - 3. / 32. * n5,
+        15. / 16. * n2 - 15. / 32. * n4,
+        -35. / 48. * n3 + 105. / 256. * n5,
+        315. / 512. * n4,
+        -693. / 1280. * n5,
+        // Inverse series: latitude from rectifying latitude
+        3. / 2. * n - 27. / 32. * n3 + 269. / 512. * n5,
+        21. / 16. * n2 - 55. / 32. * n4,
+        151. / 96. * n3 - 417. / 128. * n5,
+        1097. / 512. * n4,
+    ])
+}
+
+/// Meridional distance, divided by the semi-major axis,
+/// using 10th degree coefficients from [`enfn_high`]
+#[cfg(feature = "high-accuracy")]
+pub fn mlfn10(phi: f64, en: &En10) -> f64 {
+    en.0[0]
+        * (phi
+            + en.0[1..6]
+                .iter()
+                .enumerate()
+                .map(|(k, c)| c * (2. * (k + 1) as f64 * phi).sin())
+                .sum::<f64>())
+}
+
+/// Latitude from meridional distance divided by the
+/// semi-major axis, using 10th degree coefficients from [`enfn_high`]
+#[cfg(feature = "high-accuracy")]
+pub fn inv_mlfn10(arg: f64, es: f64, en: &En10) -> Result<f64> {
+    const MAX_ITER: usize = 5;
+    const EPS: f64 = 1e-14;
+    let mu = arg / en.0[0];
+    let mut phi = mu
+        + en.0[6..]
+            .iter()
+            .enumerate()
+            .map(|(k, c)| c * (2. * (k + 1) as f64 * mu).sin())
+            .sum::<f64>();
+    let k = 1. / (1. - es);
+    let mut i = MAX_ITER;
+    // Newton refinement, usually a single iteration
+    while i > 0 {
+        let s = phi.sin();
+        let mut t = 1. - es * s * s;
+        t = (mlfn10(phi, en) - arg) * (t * t.sqrt()) * k;
+        phi -= t;
+        if t.abs() < EPS {
+            break;
+        }
+        i -= 1;
+    }
+    if i > 0 {
+        Ok(phi)
+    } else {
+        Err(Error::InvMeridDistConvError)
+    }
+}
+
+#[cfg(all(test, feature = "high-accuracy"))]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn mlfn10_high_latitudes() {
+        // GRS80
+        let a = 6378137.;
+        let f = 1. / 298.257222101;
+        let es = f * (2. - f);
+        let en = enfn_high(es);
+
+        // Reference values from numerical integration
+        // of the meridian arc with 40 digits precision
+        let inputs = [
+            (45., 4984944.377857996620),
+            (80., 8885139.871836758631),
+            (89., 9890271.864314422561),
+            (90., 10001965.729230463692),
+        ];
+
+        for (lat, m) in inputs {
+            let phi = f64::to_radians(lat);
+            assert_abs_diff_eq!(a * mlfn10(phi, &en), m, epsilon = 1.0e-8);
+            assert_abs_diff_eq!(inv_mlfn10(m / a, es, &en).unwrap(), phi, epsilon = 1.0e-15);
+        }
+    }
+}
@@ -1,6 +1,10 @@
 //!
 //! Utilities
 //!
+//! Only a few functions are public, for downstream projections:
+//! authalic latitude ([`authlat`], [`authset`], [`qsfn`]),
+//! isometric and conformal latitudes, Gauss conformal sphere and,
+//! with the *high-accuracy* feature, the 10th order meridional distance.
 //!
 pub(crate) mod consts {
     //!
@@ -82,7 +86,7 @@ pub(crate) mod consts {
 // rust formula. This implementation will give accurate result for `0.89e308f64` while the
 // `[f64::asinh`] implementation overflow (return `f64::INFINITE`)
 #[inline]
-pub fn asinh(x: f64) -> f64 {
+pub(crate) fn asinh(x: f64) -> f64 {
     (x.abs() + 1.0f64.hypot(x)).ln().copysign(x)
 }
 
@@ -95,6 +99,7 @@ mod aasincos;
 mod adjlon;
 mod auth;
 mod gauss;
+mod isolat;
 mod mlfn;
 mod msfn;
 mod phi2;
@@ -103,10 +108,13 @@ mod tsfn;
 
 pub(crate) use aasincos::aasin;
 pub(crate) use adjlon::adjlon;
-pub(crate) use auth::{authlat, authset};
-pub(crate) use gauss::{gauss, gauss_ini, inv_gauss, Gauss};
+pub use auth::{authlat, authset};
+pub use gauss::{gauss, gauss_ini, gauss_sphere_radius, inv_gauss, Gauss};
+pub use isolat::{conformal_latitude_series, inverse_isometric_latitude, isometric_latitude};
 pub(crate) use mlfn::{enfn, inv_mlfn, mlfn, Enfn};
+#[cfg(feature = "high-accuracy")]
+pub use mlfn::{enfn_high, inv_mlfn10, mlfn10, En10};
 pub(crate) use msfn::msfn;
 pub(crate) use phi2::phi2;
-pub(crate) use qsfn::qsfn;
+pub use qsfn::qsfn;
 pub(crate) use tsfn::tsfn;
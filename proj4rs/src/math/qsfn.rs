@@ -1,6 +1,10 @@
 use super::consts::EPS_7;
 
-pub(crate) fn qsfn(sinphi: f64, e: f64, one_es: f64) -> f64 {
+/// Compute the `q` function of the authalic latitude (Snyder eq. 3-12)
+///
+/// `e` is the eccentricity and `one_es` is `1 - e^2`. The authalic
+/// latitude of `phi` is `asin(qsfn(sin(phi)) / qsfn(1))`.
+pub fn qsfn(sinphi: f64, e: f64, one_es: f64) -> f64 {
     if e >= EPS_7 {
         let con = e * sinphi;
         let div1 = 1.0 - con * con;
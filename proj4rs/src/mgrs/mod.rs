@@ -0,0 +1,473 @@
+//!
+//! Military Grid Reference System
+//!
+//! MGRS references are built on top of the UTM grid between 80°S and 84°N
+//! and of the UPS grid for the polar regions, on the WGS84 ellipsoid.
+//!
+//! A reference is made of the grid zone designator (UTM zone number and
+//! latitude band letter, or a single UPS letter), the 100km square
+//! identifier (two letters) and an even number of digits for the
+//! easting and northing within the square.
+//!
+//! Coordinates are in degrees.
+//!
+//! ```rust
+//! use proj4rs::mgrs::{from_mgrs, to_mgrs};
+//!
+//! let mgrs = to_mgrs(36.2361322, -115.0820944, 5).unwrap();
+//! assert_eq!(mgrs, "11SPA7234911844");
+//!
+//! let (lat, lon) = from_mgrs("11S PA 72349 11844").unwrap();
+//! assert!((lat - 36.2361322).abs() < 1.0e-5);
+//! assert!((lon + 115.0820944).abs() < 1.0e-5);
+//! ```
+//!
+//! ref: NGA.STND.0037 "Universal Grids and Grid Reference Systems"
+//!
+use crate::errors::{Error, Result};
+use crate::proj::Proj;
+use crate::transform::transform;
+
+// Latitude bands, 8° each from 80°S; band X spans 12°
+const BANDS: &[u8] = b"CDEFGHJKLMNPQRSTUVWX";
+
+// 100km square letters, I and O are never used
+const ROW_LETTERS: &[u8] = b"ABCDEFGHJKLMNPQRSTUV";
+const UTM_COLUMN_LETTERS: [&[u8]; 3] = [b"ABCDEFGH", b"JKLMNPQR", b"STUVWXYZ"];
+
+const UPS_ROW_LETTERS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ";
+const UPS_WEST_COLUMN_LETTERS: &[u8] = b"JKLPQRSTUXYZ";
+const UPS_EAST_COLUMN_LETTERS: &[u8] = b"ABCFGHJKLPQR";
+
+// Minimum northing of each latitude band, modulo 2000km
+const BAND_MIN_NORTHING: [f64; 20] = [
+    1_100_000., 2_000_000., 2_800_000., 3_700_000., 4_600_000., 5_500_000., 6_400_000., 7_300_000.,
+    8_200_000., 9_100_000., 0., 800_000., 1_700_000., 2_600_000., 3_500_000., 4_400_000.,
+    5_300_000., 6_200_000., 7_000_000., 7_900_000.,
+];
+
+const UPS_FALSE_EASTING: f64 = 2_000_000.;
+const MAX_PRECISION: u8 = 5;
+
+/// UPS grid zone parameters
+struct UpsZone {
+    letter: u8,
+    north: bool,
+    columns: &'static [u8],
+    // Number of valid row letters
+    nrows: usize,
+    // Origin of the lettering
+    easting: f64,
+    northing: f64,
+}
+
+const UPS_ZONES: [UpsZone; 4] = [
+    UpsZone {
+        letter: b'A',
+        north: false,
+        columns: UPS_WEST_COLUMN_LETTERS,
+        nrows: 24,
+        easting: 800_000.,
+        northing: 800_000.,
+    },
+    UpsZone {
+        letter: b'B',
+        north: false,
+        columns: UPS_EAST_COLUMN_LETTERS,
+        nrows: 24,
+        easting: 2_000_000.,
+        northing: 800_000.,
+    },
+    UpsZone {
+        letter: b'Y',
+        north: true,
+        columns: UPS_WEST_COLUMN_LETTERS,
+        nrows: 14,
+        easting: 800_000.,
+        northing: 1_300_000.,
+    },
+    UpsZone {
+        letter: b'Z',
+        north: true,
+        columns: b"ABCFGHJ",
+        nrows: 14,
+        easting: 2_000_000.,
+        northing: 1_300_000.,
+    },
+];
+
+/// Projections of the grids, initialized once
+struct Grids {
+    latlong: Proj,
+    // UTM zones 1 to 60, north then south
+    utm: Vec<Proj>,
+    // UPS north then south
+    ups: [Proj; 2],
+}
+
+impl Grids {
+    fn new() -> Self {
+        let proj = |defn: &str| Proj::from_proj_string(defn).unwrap();
+        Self {
+            latlong: proj("+proj=latlong +ellps=WGS84"),
+            utm: (1..=60)
+                .flat_map(|zone| {
+                    [
+                        proj(&format!("+proj=utm +zone={zone} +ellps=WGS84")),
+                        proj(&format!("+proj=utm +zone={zone} +south +ellps=WGS84")),
+                    ]
+                })
+                .collect(),
+            ups: [
+                proj("+proj=ups +ellps=WGS84"),
+                proj("+proj=ups +south +ellps=WGS84"),
+            ],
+        }
+    }
+
+    // `zone` must be in the 1..=60 range
+    fn utm(&self, zone: u8, south: bool) -> &Proj {
+        &self.utm[2 * (zone as usize - 1) + south as usize]
+    }
+
+    fn ups(&self, south: bool) -> &Proj {
+        &self.ups[south as usize]
+    }
+
+    fn forward(&self, dst: &Proj, lat: f64, lon: f64) -> Result<(f64, f64)> {
+        let mut pt = (lon.to_radians(), lat.to_radians(), 0.);
+        transform(&self.latlong, dst, &mut pt)?;
+        Ok((pt.0, pt.1))
+    }
+
+    fn inverse(&self, src: &Proj, easting: f64, northing: f64) -> Result<(f64, f64)> {
+        let mut pt = (easting, northing, 0.);
+        transform(src, &self.latlong, &mut pt)?;
+        Ok((pt.1.to_degrees(), pt.0.to_degrees()))
+    }
+}
+
+fn with_grids<R>(f: impl FnOnce(&Grids) -> R) -> R {
+    #[cfg(feature = "multi-thread")]
+    {
+        static GRIDS: std::sync::OnceLock<Grids> = std::sync::OnceLock::new();
+        f(GRIDS.get_or_init(Grids::new))
+    }
+
+    // Without multi-thread support, projections are
+    // not shareable between threads
+    #[cfg(not(feature = "multi-thread"))]
+    {
+        thread_local! {
+            static GRIDS: Grids = Grids::new();
+        }
+        GRIDS.with(f)
+    }
+}
+
+// UTM zone number, with the Norway and Svalbard exceptions
+fn utm_zone(lat: f64, lon: f64) -> u8 {
+    if (56. ..64.).contains(&lat) && (3. ..12.).contains(&lon) {
+        return 32;
+    }
+    if (72. ..=84.).contains(&lat) && (0. ..42.).contains(&lon) {
+        return match lon {
+            l if l < 9. => 31,
+            l if l < 21. => 33,
+            l if l < 33. => 35,
+            _ => 37,
+        };
+    }
+    (((lon + 180.) / 6.).floor() as u8).min(59) + 1
+}
+
+fn band_letter(lat: f64) -> u8 {
+    BANDS[(((lat + 80.) / 8.).floor() as usize).min(BANDS.len() - 1)]
+}
+
+// Format the digits within the 100km square
+fn grid_digits(easting: f64, northing: f64, precision: u8) -> String {
+    let div = 10u32.pow((MAX_PRECISION - precision) as u32);
+    let e = (easting.floor() as u32 % 100_000) / div;
+    let n = (northing.floor() as u32 % 100_000) / div;
+    let width = precision as usize;
+    if width == 0 {
+        return String::new();
+    }
+    format!("{e:0width$}{n:0width$}")
+}
+
+/// Encode the location `lat`, `lon` as a MGRS reference
+///
+/// `precision` is the number of digits for each of the
+/// easting and northing, from 0 (100km) to 5 (1m). Locations
+/// are truncated to the south-west corner of the grid square.
+pub fn to_mgrs(lat: f64, lon: f64, precision: u8) -> Result<String> {
+    if precision > MAX_PRECISION {
+        return Err(Error::InvalidParameterValue(
+            "MGRS precision must be between 0 and 5",
+        ));
+    }
+    if lat.is_nan() || lon.is_nan() {
+        return Err(Error::NanCoordinateValue);
+    }
+    if !(-90. ..=90.).contains(&lat) {
+        return Err(Error::LatitudeOutOfRange);
+    }
+    if !(-180. ..=180.).contains(&lon) {
+        return Err(Error::LatOrLongExceedLimit);
+    }
+
+    if !(-80. ..=84.).contains(&lat) {
+        return to_mgrs_ups(lat, lon, precision);
+    }
+
+    let zone = utm_zone(lat, lon);
+    let (easting, northing) = with_grids(|g| g.forward(g.utm(zone, lat < 0.), lat, lon))?;
+
+    let set = (zone as usize - 1) % 6;
+    let col = (easting / 100_000.).floor() as usize;
+    let col = UTM_COLUMN_LETTERS[set % 3]
+        .get(col.wrapping_sub(1))
+        .ok_or(Error::CoordinateOutOfRange)?;
+    // Even sets start with row letter 'F'
+    let row = ((northing / 100_000.).floor() as usize + 5 * (set % 2)) % ROW_LETTERS.len();
+
+    Ok(format!(
+        "{zone:02}{}{}{}{}",
+        band_letter(lat) as char,
+        *col as char,
+        ROW_LETTERS[row] as char,
+        grid_digits(easting, northing, precision),
+    ))
+}
+
+fn to_mgrs_ups(lat: f64, lon: f64, precision: u8) -> Result<String> {
+    let south = lat < 0.;
+    let (easting, northing) = with_grids(|g| g.forward(g.ups(south), lat, lon))?;
+
+    let east = easting >= UPS_FALSE_EASTING;
+    let zone = &UPS_ZONES[2 * (!south as usize) + east as usize];
+
+    let col = ((easting - zone.easting) / 100_000.).floor() as usize;
+    let row = ((northing - zone.northing) / 100_000.).floor() as usize;
+    let col = zone.columns.get(col).ok_or(Error::CoordinateOutOfRange)?;
+    let row = UPS_ROW_LETTERS
+        .get(row)
+        .ok_or(Error::CoordinateOutOfRange)?;
+
+    Ok(format!(
+        "{}{}{}{}",
+        zone.letter as char,
+        *col as char,
+        *row as char,
+        grid_digits(easting, northing, precision),
+    ))
+}
+
+fn letter_index(letters: &[u8], c: u8) -> Result<usize> {
+    letters
+        .iter()
+        .position(|l| *l == c)
+        .ok_or(Error::InvalidMgrsReference("invalid 100km square letter"))
+}
+
+// Easting and northing of the south-west corner of the square
+// within the 100km square, and the size of the square
+fn parse_digits(digits: &[u8]) -> Result<(f64, f64, f64)> {
+    if !digits.len().is_multiple_of(2) || digits.len() > 2 * MAX_PRECISION as usize {
+        return Err(Error::InvalidMgrsReference("invalid number of digits"));
+    }
+    if !digits.iter().all(u8::is_ascii_digit) {
+        return Err(Error::InvalidMgrsReference("invalid digits"));
+    }
+
+    let precision = digits.len() / 2;
+    let size = 10f64.powi((MAX_PRECISION as usize - precision) as i32);
+    let value = |s: &[u8]| s.iter().fold(0., |acc, d| 10. * acc + f64::from(d - b'0'));
+    let (e, n) = digits.split_at(precision);
+    Ok((value(e) * size, value(n) * size, size))
+}
+
+/// Decode a MGRS reference
+///
+/// Return the latitude and the longitude of the center of the grid square:
+/// for UTM zones this is the middle of the geographic extent of the square,
+/// as computed by most MGRS implementations.
+///
+/// Spaces are ignored and letters are case insensitive.
+pub fn from_mgrs(mgrs: &str) -> Result<(f64, f64)> {
+    let s: Vec<u8> = mgrs
+        .bytes()
+        .filter(|c| !c.is_ascii_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    let ndigits = s.iter().take_while(|c| c.is_ascii_digit()).count();
+    match ndigits {
+        0 => from_mgrs_ups(&s),
+        1 | 2 => {
+            let zone = std::str::from_utf8(&s[..ndigits])?
+                .parse::<u8>()
+                .map_err(|_| Error::InvalidMgrsReference("invalid zone"))?;
+            from_mgrs_utm(zone, &s[ndigits..])
+        }
+        _ => Err(Error::InvalidMgrsReference("invalid zone")),
+    }
+}
+
+fn from_mgrs_utm(zone: u8, s: &[u8]) -> Result<(f64, f64)> {
+    if !(1..=60).contains(&zone) {
+        return Err(Error::InvalidUtmZone);
+    }
+    let [band, col, row, digits @ ..] = s else {
+        return Err(Error::InvalidMgrsReference("reference too short"));
+    };
+
+    let band = BANDS
+        .iter()
+        .position(|b| b == band)
+        .ok_or(Error::InvalidMgrsReference("invalid latitude band"))?;
+
+    let set = (zone as usize - 1) % 6;
+    let col = letter_index(UTM_COLUMN_LETTERS[set % 3], *col)?;
+    let row = letter_index(ROW_LETTERS, *row)?;
+    let row = (row + ROW_LETTERS.len() - 5 * (set % 2)) % ROW_LETTERS.len();
+
+    let (e, n, size) = parse_digits(digits)?;
+
+    let easting = (col + 1) as f64 * 100_000. + e;
+    let mut northing = row as f64 * 100_000. + n;
+    while northing < BAND_MIN_NORTHING[band] {
+        northing += 2_000_000.;
+    }
+
+    with_grids(|g| {
+        // Bands up to 'M' are in the southern hemisphere
+        let utm = g.utm(zone, band < 10);
+        let (lat0, lon0) = g.inverse(utm, easting, northing)?;
+        let (lat1, lon1) = g.inverse(utm, easting + size, northing + size)?;
+        Ok((0.5 * (lat0 + lat1), 0.5 * (lon0 + lon1)))
+    })
+}
+
+fn from_mgrs_ups(s: &[u8]) -> Result<(f64, f64)> {
+    let [letter, col, row, digits @ ..] = s else {
+        return Err(Error::InvalidMgrsReference("reference too short"));
+    };
+
+    let zone = UPS_ZONES
+        .iter()
+        .find(|z| z.letter == *letter)
+        .ok_or(Error::InvalidMgrsReference("invalid UPS zone"))?;
+
+    let col = letter_index(zone.columns, *col)?;
+    let row = letter_index(&UPS_ROW_LETTERS[..zone.nrows], *row)?;
+
+    let (e, n, size) = parse_digits(digits)?;
+
+    // Longitudes cannot be averaged close to the poles:
+    // use the center of the square in the grid
+    with_grids(|g| {
+        g.inverse(
+            g.ups(!zone.north),
+            zone.easting + col as f64 * 100_000. + e + 0.5 * size,
+            zone.northing + row as f64 * 100_000. + n + 0.5 * size,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn mgrs_encode() {
+        assert_eq!(
+            to_mgrs(36.2361322, -115.0820944, 5).unwrap(),
+            "11SPA7234911844"
+        );
+        assert_eq!(to_mgrs(36.2361322, -115.0820944, 2).unwrap(), "11SPA7211");
+        assert_eq!(to_mgrs(36.2361322, -115.0820944, 0).unwrap(), "11SPA");
+
+        assert!(to_mgrs(36.2361322, -115.0820944, 6).is_err());
+        assert!(to_mgrs(91., 0., 5).is_err());
+        assert!(to_mgrs(0., 181., 5).is_err());
+    }
+
+    #[test]
+    fn mgrs_decode() {
+        let (lat, lon) = from_mgrs("33UXP04").unwrap();
+        assert_abs_diff_eq!(lon, 16.41450, epsilon = 1.0e-5);
+        assert_abs_diff_eq!(lat, 48.24949, epsilon = 1.0e-5);
+        // Northing is 5344997.72: digits are truncated, not rounded
+        assert_eq!(to_mgrs(lat, lon, 5).unwrap(), "33UXP0500444997");
+        assert_eq!(to_mgrs(lat, lon, 1).unwrap(), "33UXP04");
+
+        // Near a zone border, may be referenced in two zones
+        let (lat, lon) = from_mgrs("24XWT783908").unwrap();
+        assert_abs_diff_eq!(lon, -32.66433, epsilon = 1.0e-5);
+        assert_abs_diff_eq!(lat, 83.62778, epsilon = 1.0e-5);
+        assert_eq!(to_mgrs(lat, lon, 3).unwrap(), "25XEN041865");
+
+        // Leading zero, spaces and lower case
+        assert_eq!(
+            from_mgrs("4qfj 12345 67890").unwrap(),
+            from_mgrs("04QFJ1234567890").unwrap()
+        );
+    }
+
+    #[test]
+    fn mgrs_invalid() {
+        for s in [
+            "", "61UXP04", "0UXP04", "33IXP04", "33UXP0", "33UXP04a", "33UIP04", "33UXO04", "CAA",
+            "ZKA",
+        ] {
+            assert!(from_mgrs(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn mgrs_special_zones() {
+        // Norway
+        assert!(to_mgrs(60., 5., 5).unwrap().starts_with("32V"));
+        // Svalbard
+        assert!(to_mgrs(78., 8., 5).unwrap().starts_with("31X"));
+        assert!(to_mgrs(78., 10., 5).unwrap().starts_with("33X"));
+        assert!(to_mgrs(84., 40., 5).unwrap().starts_with("37X"));
+        // Band C starts at 80°S
+        assert!(to_mgrs(-80., 0., 5).unwrap().starts_with("31C"));
+    }
+
+    #[test]
+    fn mgrs_ups() {
+        assert_eq!(to_mgrs(90., 0., 5).unwrap(), "ZAH0000000000");
+        assert_eq!(to_mgrs(-90., 0., 5).unwrap(), "BAN0000000000");
+        assert!(to_mgrs(85., -10., 5).unwrap().starts_with('Y'));
+        assert!(to_mgrs(-81., -10., 5).unwrap().starts_with('A'));
+        assert!(to_mgrs(-81., 10., 5).unwrap().starts_with('B'));
+    }
+
+    #[test]
+    fn mgrs_roundtrip() {
+        for (lat, lon) in [
+            (0., 0.),
+            (48.8566, 2.3522),
+            (-33.8688, 151.2093),
+            (-54.8019, -68.3030),
+            (64.1466, -21.9426),
+            (83.9, -30.),
+            (-79.9, 166.7),
+            (87.5, 120.),
+            (-85., -45.),
+        ] {
+            let mgrs = to_mgrs(lat, lon, 5).unwrap();
+            let (lat2, lon2) = from_mgrs(&mgrs).unwrap();
+            // Within the 1m square
+            assert_abs_diff_eq!(lat, lat2, epsilon = 1.0e-5);
+            assert_abs_diff_eq!(lon, lon2, epsilon = 1.0e-5 / lat.to_radians().cos());
+            assert_eq!(to_mgrs(lat2, lon2, 5).unwrap(), mgrs);
+        }
+    }
+}
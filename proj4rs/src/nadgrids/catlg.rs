@@ -169,8 +169,58 @@ impl Catalog {
         self.add_node(Node::new(name, grid, parent));
         Ok(())
     }
+
+    /// Find grids, calling the builder if `name`
+    /// is not in the catalog
+    fn find_or_build(&self, name: &str, grids: &mut Vec<GridRef>) -> bool {
+        match self.find(name) {
+            Some(iter) => {
+                grids.extend(iter);
+                true
+            }
+            None => self
+                .builder()
+                .and_then(|b| {
+                    if b(self, name).is_err() {
+                        error!("Error looking for grid shift {}", name);
+                    }
+                    self.find(name).map(|iter| grids.extend(iter))
+                })
+                .is_some(),
+        }
+    }
 }
 
+#[cfg(feature = "multi-thread")]
+impl Catalog {
+    fn builder(&self) -> Option<GridBuilder> {
+        self.builder
+    }
+
+    fn replace_builder(&mut self, builder: Option<GridBuilder>) -> Option<GridBuilder> {
+        std::mem::replace(&mut self.builder, builder)
+    }
+}
+
+#[cfg(not(feature = "multi-thread"))]
+impl Catalog {
+    fn builder(&self) -> Option<GridBuilder> {
+        *self.builder.borrow()
+    }
+
+    fn replace_builder(&self, builder: Option<GridBuilder>) -> Option<GridBuilder> {
+        self.builder.replace(builder)
+    }
+}
+
+/// Global catalog shared between threads
+///
+/// The catalog is protected by a mutex and may be used from
+/// any thread. The builder may be replaced or removed at any time with
+/// [`set_builder`](catalog::set_builder) and
+/// [`reset_builder`](catalog::reset_builder); the new builder is
+/// used for grids that are not already loaded, grids already
+/// in the catalog are kept.
 #[cfg(feature = "multi-thread")]
 pub mod catalog {
     use super::*;
@@ -182,32 +232,31 @@ pub mod catalog {
     }
 
     pub fn find_grids(name: &str, grids: &mut Vec<GridRef>) -> bool {
-        let cat = CATALOG.lock().unwrap();
-        match cat.find(name) {
-            Some(iter) => {
-                grids.extend(iter);
-                true
-            }
-            None => cat
-                .builder
-                .and_then(|b| {
-                    if b(&cat, name).is_err() {
-                        error!("Error looking for grid shift {}", name);
-                    }
-                    cat.find(name).map(|iter| grids.extend(iter))
-                })
-                .is_some(),
-        }
+        CATALOG.lock().unwrap().find_or_build(name, grids)
     }
 
     pub fn add_grid(name: String, grid: Grid) -> Result<(), Error> {
         CATALOG.lock().unwrap().add_grid(name, grid)
     }
 
+    /// Set the grid builder, return the previous one
     pub fn set_builder(builder: GridBuilder) -> Option<GridBuilder> {
-        CATALOG.lock().unwrap().builder.replace(builder)
+        CATALOG.lock().unwrap().replace_builder(Some(builder))
+    }
+
+    /// Remove the grid builder, return the previous one
+    pub fn reset_builder() -> Option<GridBuilder> {
+        CATALOG.lock().unwrap().replace_builder(None)
     }
 }
+/// Thread local catalog
+///
+/// Each thread has its own catalog and builder. The builder may be
+/// replaced or removed at any time with
+/// [`set_builder`](catalog::set_builder) and
+/// [`reset_builder`](catalog::reset_builder); the new builder is
+/// used for grids that are not already loaded, grids already
+/// in the catalog are kept.
 #[cfg(not(feature = "multi-thread"))]
 pub mod catalog {
     use super::*;
@@ -217,29 +266,83 @@ pub mod catalog {
     }
 
     pub fn find_grids(name: &str, grids: &mut Vec<GridRef>) -> bool {
-        CATALOG.with(|cat| match cat.find(name) {
-            Some(iter) => {
-                grids.extend(iter);
-                true
-            }
-            None => cat
-                .builder
-                .borrow()
-                .and_then(|b| {
-                    if b(cat, name).is_err() {
-                        error!("Error looking for grid shift {}", name);
-                    }
-                    cat.find(name).map(|iter| grids.extend(iter))
-                })
-                .is_some(),
-        })
+        CATALOG.with(|cat| cat.find_or_build(name, grids))
     }
 
     pub fn add_grid(name: String, grid: Grid) -> Result<(), Error> {
         CATALOG.with(|cat| cat.add_grid(name, grid))
     }
 
+    /// Set the grid builder, return the previous one
     pub fn set_builder(builder: GridBuilder) -> Option<GridBuilder> {
-        CATALOG.with(|cat| cat.builder.borrow_mut().replace(builder))
+        CATALOG.with(|cat| cat.replace_builder(Some(builder)))
+    }
+
+    /// Remove the grid builder, return the previous one
+    pub fn reset_builder() -> Option<GridBuilder> {
+        CATALOG.with(|cat| cat.replace_builder(None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nadgrids::test_utils::test_grid;
+    use crate::nadgrids::GridId;
+
+    // Add a 2x2 grid with constant shift in latitude
+    fn add_grid(cat: &Catalog, name: &str, shift: f64) -> Result<(), Error> {
+        let grid = test_grid(
+            b"TEST    ",
+            GridId::root(),
+            (0., 0.),
+            1.,
+            2,
+            f64::NAN,
+            |_| shift,
+        );
+        cat.add_grid(name.into(), grid)
+    }
+
+    fn builder_a(cat: &Catalog, name: &str) -> Result<(), Error> {
+        add_grid(cat, name, 1.0e-5)
+    }
+
+    fn builder_b(cat: &Catalog, name: &str) -> Result<(), Error> {
+        add_grid(cat, name, 2.0e-5)
+    }
+
+    // Return the shift of the grids found for `name`
+    fn shift_with(cat: &Catalog, name: &str) -> Option<f64> {
+        let mut grids = vec![];
+        cat.find_or_build(name, &mut grids)
+            .then(|| grids[0].cvs[0].phi)
+    }
+
+    #[test]
+    fn catalog_switch_builder() {
+        // Use a local catalog, the global one is
+        // shared with the other tests
+        #[allow(unused_mut)]
+        let mut cat = Catalog::default();
+
+        assert!(cat.replace_builder(Some(builder_a)).is_none());
+        assert_eq!(shift_with(&cat, "a"), Some(1.0e-5));
+
+        // No builder: unknown grids are not found
+        assert!(cat.replace_builder(None).is_some());
+        assert_eq!(shift_with(&cat, "b"), None);
+
+        cat.replace_builder(Some(builder_b));
+        assert_eq!(shift_with(&cat, "b"), Some(2.0e-5));
+        // Already loaded grids are kept
+        assert_eq!(shift_with(&cat, "a"), Some(1.0e-5));
+    }
+
+    #[cfg(feature = "multi-thread")]
+    #[test]
+    fn catalog_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Catalog>();
     }
 }
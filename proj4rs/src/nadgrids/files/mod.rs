@@ -71,13 +71,27 @@ pub(crate) fn recognize<R: Read + Seek>(key: &str, read: &mut R) -> Result<FileT
 }
 
 /// Grid builder that read from a file
+///
+/// With the `gzip` feature, gzip compressed files are
+/// decompressed transparently.
 pub fn read_from_file(catalog: &Catalog, key: &str) -> Result<()> {
     // Use a BufReader for efficiency
-    read(
-        catalog,
-        key,
-        &mut BufReader::new(File::open(default_file_finder(key)?)?),
-    )
+    let mut file = BufReader::new(File::open(default_file_finder(key)?)?);
+
+    #[cfg(feature = "gzip")]
+    if is_gzip(&mut file)? {
+        let mut buf = Vec::new();
+        flate2::bufread::MultiGzDecoder::new(file).read_to_end(&mut buf)?;
+        return read(catalog, key, &mut std::io::Cursor::new(buf));
+    }
+
+    read(catalog, key, &mut file)
+}
+
+/// Check for the gzip magic bytes
+#[cfg(feature = "gzip")]
+fn is_gzip<R: std::io::BufRead>(read: &mut R) -> Result<bool> {
+    Ok(read.fill_buf()?.starts_with(&[0x1f, 0x8b]))
 }
 
 /// Read a grid from a file given by `key`
@@ -88,3 +102,39 @@ pub(crate) fn read<R: Read + Seek>(catalog: &Catalog, key: &str, read: &mut R) -
         _ => Err(Error::UnknownGridFormat),
     }
 }
+
+#[cfg(all(test, feature = "gzip"))]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn read_gzip_ntv2() {
+        let fixture = |name: &str| {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("fixtures")
+                .join(name)
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        let (plain, gzip) = (fixture("100800401.gsb"), fixture("100800401.gsb.gz"));
+
+        let catalog = Catalog::default();
+        read_from_file(&catalog, &plain).unwrap();
+        read_from_file(&catalog, &gzip).unwrap();
+
+        let a = catalog.find(&plain).unwrap().collect::<Vec<_>>();
+        let b = catalog.find(&gzip).unwrap().collect::<Vec<_>>();
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b.iter()) {
+            assert_eq!(a.id, b.id);
+            assert_eq!(a.gs_count(), b.gs_count());
+            assert!(a
+                .cvs
+                .iter()
+                .zip(b.cvs.iter())
+                .all(|(a, b)| a.lam == b.lam && a.phi == b.phi));
+        }
+    }
+}
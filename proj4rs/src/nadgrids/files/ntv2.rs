@@ -6,7 +6,7 @@ use crate::log::trace;
 use crate::math::consts::SEC_TO_RAD;
 use crate::nadgrids::header::error_str::*;
 use crate::nadgrids::header::{Endianness, Header};
-use crate::nadgrids::{Catalog, Grid, GridId, Lp};
+use crate::nadgrids::{Accuracy, Catalog, Grid, GridId, Lp};
 use std::io::Read;
 
 const NTV2_HEADER_SIZE: usize = 11 * 16;
@@ -98,9 +98,11 @@ fn read_ntv2_grid<R: Read>(
     );
 
     let mut buf = head.rebind::<16>();
+    let mut acc = Accuracy::default();
     let mut cvs: Vec<Lp> = (0..gs_count)
         .map(|_| {
             buf.read(read)?;
+            acc.add(buf.get_f32(8), buf.get_f32(12));
             Ok(Lp {
                 lam: SEC_TO_RAD * (buf.get_f32(0) as f64),
                 phi: SEC_TO_RAD * (buf.get_f32(4) as f64),
@@ -128,6 +130,7 @@ fn read_ntv2_grid<R: Read>(
             del,
             lim,
             epsilon,
+            acc: acc.mean(),
             cvs: cvs.into_boxed_slice(),
         },
     )
@@ -136,7 +139,7 @@ fn read_ntv2_grid<R: Read>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::nadgrids::Catalog;
+    use crate::nadgrids::{Catalog, InterpolationMethod};
     use crate::tests::setup;
     use std::env;
     use std::fs::File;
@@ -176,6 +179,46 @@ mod tests {
         assert!(grid.is_root());
         assert_eq!(grid.id.as_str(), "0INT2GRS");
         assert_eq!(grid.cvs.len(), 1591);
+        assert!(grid.acc.is_nan() || grid.acc >= 0.);
+    }
+
+    #[test]
+    fn ntv2_interpolate_at() {
+        setup();
+
+        let catalog = Catalog::default();
+        load_ntv2!(&catalog, "100800401.gsb");
+
+        let grid = catalog.find("100800401.gsb").unwrap().next().unwrap();
+
+        // Grid node at the lower left corner
+        let (dlam, dphi) = grid
+            .interpolate_at(0.0f64.to_radians(), 40.0f64.to_radians())
+            .unwrap();
+        assert_eq!((dlam, dphi), (-grid.cvs[0].lam, grid.cvs[0].phi));
+
+        // Interpolated point must match the forward grid shift
+        let (lam, phi) = (2.1f64.to_radians(), 41.4f64.to_radians());
+        let (dlam, dphi) = grid.interpolate_at(lam, phi).unwrap();
+        let (x, y, _) = grid
+            .nad_cvt(
+                crate::transform::Direction::Forward,
+                lam,
+                phi,
+                0.,
+                InterpolationMethod::Bilinear,
+            )
+            .unwrap();
+        assert_eq!((lam + dlam, phi + dphi), (x, y));
+
+        // ED50 to ETRS89 shifts are a few seconds of arc
+        assert!((1. ..10.).contains(&(dlam.abs() / SEC_TO_RAD)));
+        assert!((1. ..10.).contains(&(dphi.abs() / SEC_TO_RAD)));
+
+        // Outside the grid
+        assert!(grid
+            .interpolate_at(10.0f64.to_radians(), 41.0f64.to_radians())
+            .is_none());
     }
 
     #[test]
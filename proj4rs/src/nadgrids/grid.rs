@@ -2,7 +2,10 @@
 //! Nad grid table
 //!
 use crate::errors::{Error, Result};
-use crate::math::{adjlon, consts::PI};
+use crate::math::{
+    adjlon,
+    consts::{PI, SEC_TO_RAD},
+};
 use crate::transform::Direction;
 use std::fmt::{self, Display};
 
@@ -13,8 +16,9 @@ pub(crate) struct Lp {
     pub phi: f64,
 }
 
+/// NTv2 grid identifier
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub(crate) struct GridId([u8; 8]);
+pub struct GridId([u8; 8]);
 
 impl Default for GridId {
     fn default() -> Self {
@@ -29,6 +33,8 @@ impl PartialEq<[u8; 8]> for GridId {
 }
 
 impl GridId {
+    /// Return the identifier as a string, with the
+    /// NTv2 trailing spaces
     pub fn as_str(&self) -> &str {
         std::str::from_utf8(&self.0).unwrap_or("<n/a>")
     }
@@ -59,6 +65,60 @@ impl From<(u32, u32)> for GridId {
     }
 }
 
+/// Accumulate NTv2 node accuracies
+///
+/// Accuracies are given in seconds of arc, negative
+/// values stand for unknown accuracy.
+#[derive(Default)]
+pub(crate) struct Accuracy {
+    sum: f64,
+    count: usize,
+}
+
+impl Accuracy {
+    pub fn add(&mut self, lat_acc: f32, lon_acc: f32) {
+        if lat_acc >= 0. && lon_acc >= 0. {
+            self.sum += 0.5 * (lat_acc as f64 + lon_acc as f64);
+            self.count += 1;
+        }
+    }
+
+    /// Return the mean accuracy in radians
+    pub fn mean(&self) -> f64 {
+        if self.count > 0 {
+            SEC_TO_RAD * self.sum / self.count as f64
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+/// Grid shift interpolation method
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum InterpolationMethod {
+    /// Interpolate from the 4 surrounding nodes
+    #[default]
+    Bilinear,
+    /// Interpolate from the 16 surrounding nodes, using
+    /// the Keys cubic convolution kernel with `a = -0.5`
+    Bicubic,
+    /// Use the shift of the nearest node
+    Nearest,
+}
+
+impl TryFrom<&str> for InterpolationMethod {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        match s {
+            "bilinear" => Ok(Self::Bilinear),
+            "bicubic" => Ok(Self::Bicubic),
+            "nearest" => Ok(Self::Nearest),
+            _ => Err(Error::InvalidParameterValue("nadgrid_interpolation")),
+        }
+    }
+}
+
 /// Grid table
 #[derive(Debug)]
 pub struct Grid {
@@ -72,6 +132,9 @@ pub struct Grid {
     /// Computed epsilon value
     /// as (fabs(del.lam)+fabs(del.phi))/10000.0
     pub(crate) epsilon: f64,
+    /// Mean accuracy of the grid shifts in radians,
+    /// NaN if unknown
+    pub(crate) acc: f64,
     /// Conversion matrix: usually stored as f32, f32
     /// and converted to f64, f64
     pub(crate) cvs: Box<[Lp]>,
@@ -108,6 +171,19 @@ impl Display for Grid {
 }
 
 impl Grid {
+    /// Return the grid identifier
+    #[inline]
+    pub fn id(&self) -> GridId {
+        self.id
+    }
+
+    /// Return the parent grid identifier, [`GridId::root()`]
+    /// for root grids
+    #[inline]
+    pub fn lineage(&self) -> GridId {
+        self.lineage
+    }
+
     /// Check if grid is direct child of other.
     #[inline]
     pub fn is_child_of(&self, other: &Grid) -> bool {
@@ -142,45 +218,75 @@ impl Grid {
         self.num_rows() * self.row_len()
     }
 
+    /// Return the interpolated shift at `lam`, `phi`
+    ///
+    /// Input coordinates are in radians. The returned `(dlam, dphi)`
+    /// shift, in radians, is to be added to the input coordinates.
+    ///
+    /// Return `None` if the point is outside the grid.
+    pub fn interpolate_at(&self, lam: f64, phi: f64) -> Option<(f64, f64)> {
+        self.nad_intr(
+            adjlon(lam - self.ll.lam - PI) + PI,
+            phi - self.ll.phi,
+            InterpolationMethod::Bilinear,
+        )
+        .ok()
+        .map(|(t_lam, t_phi)| (-t_lam, t_phi))
+    }
+
     pub(crate) fn nad_cvt(
         &self,
         dir: Direction,
         lam: f64,
         phi: f64,
         z: f64,
+        method: InterpolationMethod,
     ) -> Result<(f64, f64, f64)> {
         match dir {
-            Direction::Forward => self.nad_cvt_forward(lam, phi, z),
-            Direction::Inverse => self.nad_cvt_inverse(lam, phi, z),
+            Direction::Forward => self.nad_cvt_forward(lam, phi, z, method),
+            Direction::Inverse => self.nad_cvt_inverse(lam, phi, z, method),
         }
     }
 
     /// Assume that coordinates matches the grid
-    fn nad_cvt_forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+    fn nad_cvt_forward(
+        &self,
+        lam: f64,
+        phi: f64,
+        z: f64,
+        method: InterpolationMethod,
+    ) -> Result<(f64, f64, f64)> {
         let (t_lam, t_phi) = self.nad_intr(
             // normalize input to ll origin
             adjlon(lam - self.ll.lam - PI) + PI,
             phi - self.ll.phi,
+            method,
         )?;
 
         Ok((lam - t_lam, phi + t_phi, z))
     }
 
-    fn nad_cvt_inverse(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+    fn nad_cvt_inverse(
+        &self,
+        lam: f64,
+        phi: f64,
+        z: f64,
+        method: InterpolationMethod,
+    ) -> Result<(f64, f64, f64)> {
         const MAX_ITER: usize = 10;
         const TOL: f64 = 1.0e-24;
         const TOL2: f64 = TOL * TOL;
 
         // normalize input to ll origin
         let (tb_lam, tb_phi) = (adjlon(lam - self.ll.lam - PI) + PI, phi - self.ll.phi);
-        let (mut t_lam, mut t_phi) = self.nad_intr(tb_lam, tb_phi)?;
+        let (mut t_lam, mut t_phi) = self.nad_intr(tb_lam, tb_phi, method)?;
 
         t_lam += tb_lam;
         t_phi = tb_phi - t_phi;
 
         let mut i = MAX_ITER;
         while i > 0 {
-            if let Ok((del_lam, del_phi)) = self.nad_intr(t_lam, t_phi) {
+            if let Ok((del_lam, del_phi)) = self.nad_intr(t_lam, t_phi, method) {
                 let (diff_lam, diff_phi) = (t_lam - del_lam - tb_lam, t_phi + del_phi - tb_phi);
 
                 if diff_lam * diff_lam + diff_phi * diff_phi <= TOL2 {
@@ -204,34 +310,34 @@ impl Grid {
         Ok((adjlon(t_lam + self.ll.lam), t_phi + self.ll.phi, z))
     }
 
-    fn nad_intr(&self, lam: f64, phi: f64) -> Result<(f64, f64)> {
+    fn nad_intr(&self, lam: f64, phi: f64, method: InterpolationMethod) -> Result<(f64, f64)> {
         let (t_lam, t_phi) = (lam / self.del.lam, phi / self.del.phi);
 
-        fn _check_lim(t: f64, lim: f64) -> Result<(f64, f64)> {
-            let mut i = t.floor();
-            let mut f = t - i;
-            if i < 0. {
-                if i == -1. && f > 0.99999999999 {
-                    i += 1.;
-                    f = 0.
-                } else {
-                    return Err(Error::PointOutsideNadShiftArea);
-                }
-            } else {
-                match i + 1. {
-                    n if n == lim && f < 1.0e-11 => {
-                        i -= 1.;
-                        f = 1.;
-                    }
-                    n if n > lim => return Err(Error::PointOutsideNadShiftArea),
-                    _ => (),
-                }
+        // Points on the grid border, or outside by less than
+        // the grid epsilon, are clamped to the border cells
+        fn check_lim(t: f64, lim: f64, eps: f64) -> Result<(f64, f64)> {
+            if t < -eps || t > lim - 1. + eps {
+                return Err(Error::PointOutsideNadShiftArea);
             }
-            Ok((i, f))
+            let t = t.clamp(0., lim - 1.);
+            let i = t.floor().min(lim - 2.);
+            Ok((i, t - i))
         }
 
-        let (i_lam, f_lam) = _check_lim(t_lam, self.lim.lam)?;
-        let (i_phi, f_phi) = _check_lim(t_phi, self.lim.phi)?;
+        let (i_lam, f_lam) = check_lim(t_lam, self.lim.lam, self.epsilon / self.del.lam.abs())?;
+        let (i_phi, f_phi) = check_lim(t_phi, self.lim.phi, self.epsilon / self.del.phi.abs())?;
+
+        match method {
+            InterpolationMethod::Bicubic => {
+                return Ok(self.bicubic(i_lam as usize, f_lam, i_phi as usize, f_phi));
+            }
+            InterpolationMethod::Nearest => {
+                let (i_lam, i_phi) = (i_lam + f_lam.round(), i_phi + f_phi.round());
+                let f = &self.cvs[(i_phi * self.lim.lam + i_lam) as usize];
+                return Ok((f.lam, f.phi));
+            }
+            InterpolationMethod::Bilinear => (),
+        }
 
         let mut index = (i_phi * self.lim.lam + i_lam) as usize;
         let f00 = &self.cvs[index];
@@ -250,4 +356,39 @@ impl Grid {
             m00 * f00.phi + m10 * f10.phi + m01 * f01.phi + m11 * f11.phi,
         ))
     }
+
+    /// Bicubic interpolation from the 4x4 nodes around the cell
+    /// `(i_lam, i_phi)`. Nodes outside the grid are clamped to the
+    /// grid border.
+    fn bicubic(&self, i_lam: usize, f_lam: f64, i_phi: usize, f_phi: f64) -> (f64, f64) {
+        // Keys cubic convolution kernel with a = -0.5
+        fn weights(t: f64) -> [f64; 4] {
+            const A: f64 = -0.5;
+            let w = |x: f64| {
+                let x = x.abs();
+                if x <= 1. {
+                    ((A + 2.) * x - (A + 3.)) * x * x + 1.
+                } else {
+                    ((A * x - 5. * A) * x + 8. * A) * x - 4. * A
+                }
+            };
+            [w(1. + t), w(t), w(1. - t), w(2. - t)]
+        }
+
+        let (n_lam, n_phi) = (self.row_len(), self.num_rows());
+        let clamp = |i: usize, k: usize, n: usize| (i + k).saturating_sub(1).min(n - 1);
+
+        let (w_lam, w_phi) = (weights(f_lam), weights(f_phi));
+        let (mut t_lam, mut t_phi) = (0., 0.);
+        for (k, wp) in w_phi.iter().enumerate() {
+            let row = clamp(i_phi, k, n_phi) * n_lam;
+            for (l, wl) in w_lam.iter().enumerate() {
+                let f = &self.cvs[row + clamp(i_lam, l, n_lam)];
+                let w = wp * wl;
+                t_lam += w * f.lam;
+                t_phi += w * f.phi;
+            }
+        }
+        (t_lam, t_phi)
+    }
 }
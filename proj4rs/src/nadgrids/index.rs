@@ -0,0 +1,115 @@
+//!
+//! Spatial index of nested grids
+//!
+//! Subgrids are linked to their parent from the lineage and siblings
+//! are sorted by their western bound, so that candidates are found by
+//! a binary search followed by a scan limited to the grids overlapping
+//! the input longitude.
+//!
+use super::GridRef;
+
+#[cfg(feature = "multi-thread")]
+pub(super) type Shared<T> = std::sync::Arc<T>;
+
+#[cfg(not(feature = "multi-thread"))]
+pub(super) type Shared<T> = std::rc::Rc<T>;
+
+/// Grids sharing the same parent
+#[derive(Debug, Default)]
+struct Siblings {
+    /// Index of the grids in the grid list, sorted by western bound
+    grids: Vec<usize>,
+    /// Western bounds, ascending
+    west: Vec<f64>,
+    /// Running maximum of the eastern bounds
+    east_max: Vec<f64>,
+}
+
+impl Siblings {
+    fn new(mut grids: Vec<usize>, list: &[GridRef]) -> Self {
+        grids.sort_by(|a, b| west(list[*a]).total_cmp(&west(list[*b])));
+        let west = grids.iter().map(|i| west(list[*i])).collect();
+        let east_max = grids
+            .iter()
+            .scan(f64::NEG_INFINITY, |max, i| {
+                *max = max.max(east(list[*i]));
+                Some(*max)
+            })
+            .collect();
+        Self {
+            grids,
+            west,
+            east_max,
+        }
+    }
+
+    /// Return the first grid, in list order, matching the point
+    fn find(&self, list: &[GridRef], lam: f64, phi: f64, z: f64) -> Option<usize> {
+        let end = self.west.partition_point(|w| *w <= lam);
+        (0..end)
+            .rev()
+            .take_while(|j| self.east_max[*j] >= lam)
+            .map(|j| self.grids[j])
+            .filter(|i| list[*i].matches(lam, phi, z))
+            .min()
+    }
+}
+
+// Bounds including the grid epsilon, see `Grid::matches`
+fn west(g: GridRef) -> f64 {
+    g.ll.lam - g.epsilon
+}
+
+fn east(g: GridRef) -> f64 {
+    g.ll.lam + (g.lim.lam - 1.) * g.del.lam + g.epsilon
+}
+
+/// Index of the root grids and of the children of each grid
+#[derive(Debug, Default)]
+pub(super) struct GridIndex {
+    roots: Siblings,
+    children: Vec<Siblings>,
+}
+
+impl GridIndex {
+    /// Build the index for the grid list
+    ///
+    /// The parent of a subgrid is the nearest preceding grid with
+    /// the subgrid's lineage as identifier; subgrids without parent
+    /// are never selected.
+    pub fn new(list: &[GridRef]) -> Self {
+        let mut roots = vec![];
+        let mut children = vec![vec![]; list.len()];
+        for (i, g) in list.iter().enumerate() {
+            if g.is_root() {
+                roots.push(i);
+            } else if let Some(p) = list[..i].iter().rposition(|p| g.is_child_of(p)) {
+                children[p].push(i);
+            }
+        }
+        Self {
+            roots: Siblings::new(roots, list),
+            children: children
+                .into_iter()
+                .map(|c| Siblings::new(c, list))
+                .collect(),
+        }
+    }
+
+    /// Return the root grid matching the point
+    pub fn root(&self, list: &[GridRef], lam: f64, phi: f64, z: f64) -> Option<usize> {
+        self.roots.find(list, lam, phi, z)
+    }
+
+    /// Return the child of the grid `parent` matching the point
+    pub fn child(
+        &self,
+        list: &[GridRef],
+        parent: usize,
+        lam: f64,
+        phi: f64,
+        z: f64,
+    ) -> Option<usize> {
+        self.children[parent].find(list, lam, phi, z)
+    }
+}
@@ -2,10 +2,12 @@
 //! Handle Nadgrids
 //!
 use crate::errors::{Error, Result};
+use crate::log::trace;
 use crate::transform::Direction;
 
 mod catlg;
 mod grid;
+mod index;
 
 pub use catlg::{catalog, Catalog, GridRef};
 
@@ -15,26 +17,81 @@ mod header;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod files;
 
+use index::{GridIndex, Shared};
 use std::ops::ControlFlow;
 
+pub(crate) use grid::Accuracy;
 pub(crate) use grid::Lp;
-pub(crate) use grid::{Grid, GridId};
+pub use grid::{Grid, GridId, InterpolationMethod};
+
+/// Subgrid selection mode
+///
+/// Control which grid is used when nested NTv2 subgrids
+/// overlap the input point.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum AccuracyMode {
+    /// Use the most specific (deepest) subgrid
+    #[default]
+    BestFit,
+    /// Use the grid with the smallest accuracy value
+    MostAccurate,
+    /// Always use the root grid
+    Coarsest,
+}
+
+impl TryFrom<&str> for AccuracyMode {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        match s {
+            "best_fit" => Ok(Self::BestFit),
+            "most_accurate" => Ok(Self::MostAccurate),
+            "coarsest" => Ok(Self::Coarsest),
+            _ => Err(Error::InvalidParameterValue("nadgrid_accuracy")),
+        }
+    }
+}
 
 /// NadGrids
 ///
 /// Returned from the sequence
 /// of nadgrids from projstring definition
+///
+/// The spatial index of the subgrids is built once and
+/// shared between clones.
 #[derive(Debug, Clone)]
-pub struct NadGrids(Vec<GridRef>);
+pub struct NadGrids(
+    Vec<GridRef>,
+    AccuracyMode,
+    InterpolationMethod,
+    Shared<GridIndex>,
+);
 
 impl PartialEq for NadGrids {
     fn eq(&self, other: &Self) -> bool {
-        // Don't bother to compare all names
-        self.0.is_empty() && other.0.is_empty()
+        // Grids are unique in the catalog: compare references
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| std::ptr::eq(*a, *b))
+            && self.1 == other.1
+            && self.2 == other.2
     }
 }
 
 impl NadGrids {
+    fn new(grids: Vec<GridRef>) -> Self {
+        let index = Shared::new(GridIndex::new(&grids));
+        Self(
+            grids,
+            AccuracyMode::default(),
+            InterpolationMethod::default(),
+            index,
+        )
+    }
+
     pub fn apply_shift(
         &self,
         dir: Direction,
@@ -42,35 +99,69 @@ impl NadGrids {
         phi: f64,
         z: f64,
     ) -> Result<(f64, f64, f64)> {
+        self.apply_shift_traced(dir, lam, phi, z)
+            .map(|(lpz, _)| lpz)
+    }
+
+    /// Apply the grid shift and return the identifier
+    /// of the grid used for the shift
+    ///
+    /// The identifier is `None` if the grid list is empty, i.e.
+    /// the point is left unshifted.
+    pub fn apply_shift_traced(
+        &self,
+        dir: Direction,
+        lam: f64,
+        phi: f64,
+        z: f64,
+    ) -> Result<((f64, f64, f64), Option<GridId>)> {
         if self.0.is_empty() {
-            return Ok((lam, phi, z));
+            return Ok(((lam, phi, z), None));
         }
 
+        match self.select_grid(lam, phi, z) {
+            Some(g) => {
+                trace!("Using grid '{}' at ({}, {})", g.id.as_str(), lam, phi);
+                g.nad_cvt(dir, lam, phi, z, self.2)
+                    .map(|lpz| (lpz, Some(g.id)))
+            }
+            None => Err(Error::PointOutsideNadShiftArea),
+        }
+    }
+
+    /// Return the grid matching the input point
+    /// according to the subgrid selection mode
+    fn select_grid(&self, lam: f64, phi: f64, z: f64) -> Option<GridRef> {
+        let (grids, index) = (&self.0, &self.3);
+
         // Find the correct (root)  grid for an input
-        let mut iter = self.0.iter();
-        let mut candidate = iter.find(|g| g.is_root() && g.matches(lam, phi, z));
-
-        // Check for childs grid
-        if let Some(grid) = candidate {
-            iter.try_fold(grid, |grid, g| {
-                if !g.is_child_of(grid) {
-                    // No more childs, stop with the last candidate
-                    ControlFlow::Break(())
-                } else if g.matches(lam, phi, z) {
-                    // Match, check for childs
-                    candidate.replace(g);
-                    ControlFlow::Continue(g)
-                } else {
-                    // Go next child
-                    ControlFlow::Continue(grid)
+        let mut parent = index.root(grids, lam, phi, z)?;
+        let mut candidate = grids[parent];
+
+        if self.1 != AccuracyMode::Coarsest {
+            // Walk down the matching childs
+            while let Some(i) = index.child(grids, parent, lam, phi, z) {
+                let g = grids[i];
+                if self.1 == AccuracyMode::BestFit
+                    || g.acc < candidate.acc
+                    || candidate.acc.is_nan()
+                {
+                    candidate = g;
                 }
-            });
+                parent = i;
+            }
         }
 
-        match candidate {
-            Some(g) => g.nad_cvt(dir, lam, phi, z),
-            None => Err(Error::PointOutsideNadShiftArea),
-        }
+        Some(candidate)
+    }
+
+    /// Return the identifiers of all the grids and subgrids
+    /// available for the shift
+    ///
+    /// Use [`NadGrids::apply_shift_traced`] to get the grid
+    /// actually used for a point.
+    pub fn grids(&self) -> impl Iterator<Item = GridId> + '_ {
+        self.0.iter().map(|g| g.id)
     }
 
     /// Return a list of grids from the catalog
@@ -99,19 +190,414 @@ impl NadGrids {
                 }
             }
         }) {
-            ControlFlow::Break(true) => Ok(Self(v)),
+            ControlFlow::Break(true) => Ok(Self::new(v)),
             ControlFlow::Break(false) => Err(Error::NadGridNotAvailable),
             _ => {
                 if v.is_empty() {
                     Err(Error::NadGridNotAvailable)
                 } else {
-                    Ok(Self(v))
+                    Ok(Self::new(v))
                 }
             }
         }
     }
 
+    /// Set the subgrid selection mode
+    pub fn with_accuracy(self, mode: AccuracyMode) -> Self {
+        Self(self.0, mode, self.2, self.3)
+    }
+
+    /// Return the subgrid selection mode
+    pub fn accuracy(&self) -> AccuracyMode {
+        self.1
+    }
+
+    /// Set the grid shift interpolation method
+    pub fn with_interpolation(self, method: InterpolationMethod) -> Self {
+        Self(self.0, self.1, method, self.3)
+    }
+
+    /// Return the grid shift interpolation method
+    pub fn interpolation(&self) -> InterpolationMethod {
+        self.2
+    }
+
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use super::*;
+
+    /// Build a `n`x`n` grid with cell size `size`, the latitude
+    /// shift of each node is given by `shift` from its index
+    pub(crate) fn test_grid(
+        id: &[u8; 8],
+        lineage: GridId,
+        ll: (f64, f64),
+        size: f64,
+        n: usize,
+        acc: f64,
+        shift: impl Fn(usize) -> f64,
+    ) -> Grid {
+        let del = Lp {
+            lam: size,
+            phi: size,
+        };
+        let cells = (n - 1) as f64;
+        Grid {
+            id: GridId::from(*id),
+            lineage,
+            ll: Lp {
+                lam: ll.0,
+                phi: ll.1,
+            },
+            ur: Lp {
+                lam: ll.0 + cells * del.lam,
+                phi: ll.1 + cells * del.phi,
+            },
+            epsilon: (del.lam + del.phi) / 10_000.,
+            del,
+            lim: Lp {
+                lam: n as f64,
+                phi: n as f64,
+            },
+            acc,
+            cvs: (0..n * n)
+                .map(|i| Lp {
+                    lam: 0.,
+                    phi: shift(i),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::test_grid;
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    // Build a 2x2 grid with constant shift
+    fn grid(id: &[u8; 8], lineage: GridId, ll: (f64, f64), shift: f64, acc: f64) -> GridRef {
+        sized_grid(id, lineage, ll, 1., shift, acc)
+    }
+
+    // Build a 2x2 grid with constant shift and cell size `size`
+    fn sized_grid(
+        id: &[u8; 8],
+        lineage: GridId,
+        ll: (f64, f64),
+        size: f64,
+        shift: f64,
+        acc: f64,
+    ) -> GridRef {
+        Box::leak(Box::new(test_grid(id, lineage, ll, size, 2, acc, |_| {
+            shift
+        })))
+    }
+
+    #[test]
+    fn nadgrids_accuracy_mode() {
+        let root = grid(b"ROOT    ", GridId::root(), (0., 0.), 1.0e-5, 1.0e-7);
+        let child = grid(b"CHILD   ", root.id, (0.25, 0.25), 2.0e-5, 1.0e-6);
+        let grids = NadGrids::new(vec![root, child]);
+
+        let shift = |grids: &NadGrids| {
+            grids
+                .apply_shift(Direction::Forward, 0.5, 0.5, 0.)
+                .unwrap()
+                .1
+                - 0.5
+        };
+
+        assert_eq!(grids.accuracy(), AccuracyMode::BestFit);
+        assert_abs_diff_eq!(shift(&grids), 2.0e-5, epsilon = 1.0e-12);
+
+        let grids = grids.with_accuracy(AccuracyMode::MostAccurate);
+        assert_abs_diff_eq!(shift(&grids), 1.0e-5, epsilon = 1.0e-12);
+
+        let grids = grids.with_accuracy(AccuracyMode::Coarsest);
+        assert_abs_diff_eq!(shift(&grids), 1.0e-5, epsilon = 1.0e-12);
+
+        // Outside child grid
+        let grids = grids.with_accuracy(AccuracyMode::BestFit);
+        let (_, phi, _) = grids.apply_shift(Direction::Forward, 0.1, 0.1, 0.).unwrap();
+        assert_abs_diff_eq!(phi - 0.1, 1.0e-5, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn nadgrids_many_subgrids() {
+        // Root grid tiled by 8x8 subgrids, listed in shuffled order
+        let root = sized_grid(b"ROOT    ", GridId::root(), (0., 0.), 8., 0., 1.0e-5);
+        let mut list = vec![root];
+        for k in (0..64).map(|k| (k * 37) % 64) {
+            let (i, j) = (k % 8, k / 8);
+            let id = format!("TILE{k:04}");
+            list.push(sized_grid(
+                id.as_bytes().try_into().unwrap(),
+                root.id,
+                (i as f64, j as f64),
+                1.,
+                1.0e-6 * (k + 1) as f64,
+                1.0e-6,
+            ));
+        }
+        let grids = NadGrids::new(list.clone());
+
+        // Linear search of the first matching child
+        let brute_force = |lam: f64, phi: f64| {
+            list.iter()
+                .skip(1)
+                .find(|g| g.matches(lam, phi, 0.))
+                .map(|g| g.id)
+        };
+
+        for k in 0..64 {
+            let (lam, phi) = ((k % 8) as f64 + 0.5, (k / 8) as f64 + 0.5);
+            assert_eq!(
+                grids.select_grid(lam, phi, 0.).map(|g| g.id),
+                brute_force(lam, phi)
+            );
+            let (_, phi_s, _) = grids.apply_shift(Direction::Forward, lam, phi, 0.).unwrap();
+            assert_abs_diff_eq!(phi_s - phi, 1.0e-6 * (k + 1) as f64, epsilon = 1.0e-12);
+        }
+
+        // On shared edges, the first child in list order wins
+        for (lam, phi) in [(1., 1.), (4., 0.5), (7.5, 3.), (8., 8.)] {
+            assert_eq!(
+                grids.select_grid(lam, phi, 0.).map(|g| g.id),
+                brute_force(lam, phi)
+            );
+        }
+
+        // Outside the root grid
+        assert!(grids.select_grid(-0.5, 4., 0.).is_none());
+        assert!(grids.select_grid(4., 8.5, 0.).is_none());
+    }
+
+    #[test]
+    fn nadgrids_apply_shift_traced() {
+        let root = grid(b"ROOT    ", GridId::root(), (0., 0.), 1.0e-5, 1.0e-6);
+        let child = grid(b"CHILD   ", root.id, (0.25, 0.25), 2.0e-5, 1.0e-6);
+        let subchild = grid(b"SUBCHILD", child.id, (0.4, 0.4), 3.0e-5, 1.0e-6);
+        let grids = NadGrids::new(vec![root, child, subchild]);
+
+        assert_eq!(
+            grids.grids().collect::<Vec<_>>(),
+            [root.id(), child.id(), subchild.id()]
+        );
+
+        let traced = |grids: &NadGrids, lam: f64, phi: f64| {
+            let ((_, p, _), id) = grids
+                .apply_shift_traced(Direction::Forward, lam, phi, 0.)
+                .unwrap();
+            (id.unwrap(), p - phi)
+        };
+
+        // The deepest matching subgrid is chosen
+        let (id, shift) = traced(&grids, 0.5, 0.5);
+        assert_eq!(id.as_str(), "SUBCHILD");
+        assert_abs_diff_eq!(shift, 3.0e-5, epsilon = 1.0e-12);
+
+        let (id, shift) = traced(&grids, 0.3, 0.3);
+        assert_eq!(id, child.id());
+        assert_abs_diff_eq!(shift, 2.0e-5, epsilon = 1.0e-12);
+
+        let (id, _) = traced(&grids, 0.1, 0.1);
+        assert_eq!(id, root.id());
+        assert_eq!(id.as_str(), "ROOT    ");
+
+        let grids = grids.with_accuracy(AccuracyMode::Coarsest);
+        assert_eq!(traced(&grids, 0.5, 0.5).0, root.id());
+
+        // Outside of all grids
+        assert!(grids
+            .apply_shift_traced(Direction::Forward, 2., 2., 0.)
+            .is_err());
+
+        // No grid: no shift
+        let empty = NadGrids::new(vec![]);
+        assert_eq!(
+            empty
+                .apply_shift_traced(Direction::Forward, 0.5, 0.5, 0.)
+                .unwrap(),
+            ((0.5, 0.5, 0.), None)
+        );
+    }
+
+    #[test]
+    fn nadgrids_accuracy_param() {
+        assert_eq!(
+            AccuracyMode::try_from("most_accurate").unwrap(),
+            AccuracyMode::MostAccurate
+        );
+        assert!(crate::Proj::from_proj_string(
+            "+proj=latlong +ellps=GRS80 +nadgrids=@null +nadgrid_accuracy=coarsest"
+        )
+        .is_ok());
+        assert!(matches!(
+            crate::Proj::from_proj_string(
+                "+proj=latlong +ellps=GRS80 +nadgrids=@null +nadgrid_accuracy=foo"
+            ),
+            Err(Error::InvalidParameterValue("nadgrid_accuracy"))
+        ));
+    }
+
+    // Build a 6x6 grid with a latitude shift varying as `c * i^2`
+    // where `i` is the longitude index of the node
+    fn quadratic_grid(c: f64) -> GridRef {
+        Box::leak(Box::new(test_grid(
+            b"QUAD    ",
+            GridId::root(),
+            (0., 0.),
+            0.01,
+            6,
+            f64::NAN,
+            |n| c * ((n % 6) as f64).powi(2),
+        )))
+    }
+
+    #[test]
+    fn nadgrids_eq() {
+        let (g1, g2) = (quadratic_grid(1.0e-6), quadratic_grid(1.0e-6));
+        let grids = NadGrids::new(vec![g1, g2]);
+
+        assert_eq!(grids, NadGrids::new(vec![g1, g2]));
+        assert_ne!(grids, NadGrids::new(vec![g1]));
+        assert_ne!(grids, NadGrids::new(vec![g2, g1]));
+        assert_ne!(
+            grids,
+            NadGrids::new(vec![g1, g2]).with_accuracy(AccuracyMode::Coarsest)
+        );
+        assert_ne!(
+            grids,
+            NadGrids::new(vec![g1, g2]).with_interpolation(InterpolationMethod::Nearest)
+        );
+    }
+
+    #[test]
+    fn nadgrids_bicubic_interpolation() {
+        const C: f64 = 1.0e-6;
+        let grids = NadGrids::new(vec![quadratic_grid(C)]);
+
+        let shift = |grids: &NadGrids, t: f64| {
+            grids
+                .apply_shift(Direction::Forward, t * 0.01, 0.025, 0.)
+                .unwrap()
+                .1
+                - 0.025
+        };
+
+        // At cell center, bilinear interpolation is off by c/4
+        // while bicubic reproduce the quadratic field.
+        assert_eq!(grids.interpolation(), InterpolationMethod::Bilinear);
+        assert_abs_diff_eq!(shift(&grids, 2.5), 6.5 * C, epsilon = 1.0e-15);
+
+        let grids = grids.with_interpolation(InterpolationMethod::Bicubic);
+        assert_abs_diff_eq!(shift(&grids, 2.5), 6.25 * C, epsilon = 1.0e-15);
+
+        // Nodes are preserved
+        assert_abs_diff_eq!(shift(&grids, 3.), 9. * C, epsilon = 1.0e-15);
+
+        // The slope is continuous across the node at i = 3, while
+        // bilinear interpolation has a kink: 5c on the left and 7c on the right.
+        let slopes = |grids: &NadGrids| {
+            let h = 1.0e-3;
+            (
+                (shift(grids, 3.) - shift(grids, 3. - h)) / h,
+                (shift(grids, 3. + h) - shift(grids, 3.)) / h,
+            )
+        };
+        let (left, right) = slopes(&grids);
+        assert_abs_diff_eq!(left, right, epsilon = 1.0e-2 * C);
+        assert_abs_diff_eq!(left, 6. * C, epsilon = 1.0e-2 * C);
+
+        let (left, right) = slopes(&grids.with_interpolation(InterpolationMethod::Bilinear));
+        assert_abs_diff_eq!(left, 5. * C, epsilon = 1.0e-9);
+        assert_abs_diff_eq!(right, 7. * C, epsilon = 1.0e-9);
+    }
+
+    #[test]
+    fn nadgrids_nearest_interpolation() {
+        const C: f64 = 1.0e-6;
+        let grids =
+            NadGrids::new(vec![quadratic_grid(C)]).with_interpolation(InterpolationMethod::Nearest);
+
+        let shift = |grids: &NadGrids, t: f64| {
+            grids
+                .apply_shift(Direction::Forward, t * 0.01, 0.025, 0.)
+                .unwrap()
+                .1
+                - 0.025
+        };
+
+        assert_abs_diff_eq!(shift(&grids, 2.4), 4. * C, epsilon = 1.0e-15);
+        assert_abs_diff_eq!(shift(&grids, 2.6), 9. * C, epsilon = 1.0e-15);
+        assert_abs_diff_eq!(shift(&grids, 3.), 9. * C, epsilon = 1.0e-15);
+
+        // Bilinear interpolation between the nodes
+        let grids = grids.with_interpolation(InterpolationMethod::Bilinear);
+        assert_abs_diff_eq!(shift(&grids, 2.4), 6. * C, epsilon = 1.0e-15);
+        assert_abs_diff_eq!(shift(&grids, 2.6), 7. * C, epsilon = 1.0e-15);
+    }
+
+    #[test]
+    fn nadgrids_grid_corner() {
+        const C: f64 = 1.0e-6;
+        let grid = quadratic_grid(C);
+
+        for method in [
+            InterpolationMethod::Bilinear,
+            InterpolationMethod::Bicubic,
+            InterpolationMethod::Nearest,
+        ] {
+            let grids = NadGrids::new(vec![grid]).with_interpolation(method);
+            let shift = |lam: f64, phi: f64| {
+                grids
+                    .apply_shift(Direction::Forward, lam, phi, 0.)
+                    .map(|(_, p, _)| p - phi)
+            };
+
+            // Upper right corner, on the border and within the grid epsilon
+            assert_abs_diff_eq!(shift(0.05, 0.05).unwrap(), 25. * C, epsilon = 1.0e-15);
+            assert_abs_diff_eq!(
+                shift(0.05 + 1.0e-7, 0.05 + 1.0e-7).unwrap(),
+                25. * C,
+                epsilon = 1.0e-15
+            );
+            // Lower left corner
+            assert_abs_diff_eq!(shift(0., 0.).unwrap(), 0., epsilon = 1.0e-15);
+            assert_abs_diff_eq!(shift(0., -1.0e-7).unwrap(), 0., epsilon = 1.0e-15);
+
+            // Outside
+            assert!(shift(0.051, 0.05).is_err());
+            assert!(shift(0.05, -0.001).is_err());
+        }
+    }
+
+    #[test]
+    fn nadgrids_interpolation_param() {
+        assert_eq!(
+            InterpolationMethod::try_from("bicubic").unwrap(),
+            InterpolationMethod::Bicubic
+        );
+        assert_eq!(
+            InterpolationMethod::try_from("nearest").unwrap(),
+            InterpolationMethod::Nearest
+        );
+        assert!(crate::Proj::from_proj_string(
+            "+proj=latlong +ellps=GRS80 +nadgrids=@null +nadgrid_interpolation=bicubic"
+        )
+        .is_ok());
+        assert!(matches!(
+            crate::Proj::from_proj_string(
+                "+proj=latlong +ellps=GRS80 +nadgrids=@null +nadgrid_interpolation=foo"
+            ),
+            Err(Error::InvalidParameterValue("nadgrid_interpolation"))
+        ));
+    }
+}
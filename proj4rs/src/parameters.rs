@@ -4,6 +4,8 @@
 //!
 use crate::errors::{Error, Result};
 use crate::parse::FromStr;
+use std::cell::Cell;
+use std::fmt;
 
 /// Struct holding a pair key/value
 pub struct Parameter<'a> {
@@ -50,8 +52,6 @@ impl Parameter<'_> {
     ///
     /// By default it is assumed that unspecified input
     /// is in degree.
-    ///
-    /// TODO: parse dms value
     pub fn try_angular_value(&self) -> Result<f64> {
         self.try_value::<f64>().map(|v| v.to_radians())
     }
@@ -70,12 +70,32 @@ impl Parameter<'_> {
 }
 
 /// List of parameters
-pub struct ParamList<'a>(Vec<Parameter<'a>>);
+///
+/// Parameters retrieved by name are marked as used.
+pub struct ParamList<'a>(Vec<Parameter<'a>>, Vec<Cell<bool>>);
 
 impl<'a> ParamList<'a> {
     /// Return Some(param) if the parameter `name` exists `None` otherwise.
     pub fn get(&self, name: &str) -> Option<&Parameter<'a>> {
-        self.0.iter().find(|p| p.name == name)
+        self.0.iter().position(|p| p.name == name).map(|i| {
+            self.1[i].set(true);
+            &self.0[i]
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Parameter<'a>> {
+        self.0.iter()
+    }
+
+    /// Return the parameters that have never been retrieved by name
+    ///
+    /// Parameters overridden by a previous parameter with the
+    /// same name are not returned.
+    pub fn unused(&self) -> impl Iterator<Item = &Parameter<'a>> {
+        self.0.iter().enumerate().filter_map(|(i, p)| {
+            let overridden = self.0[..i].iter().any(|q| q.name == p.name);
+            (!overridden && !self.1[i].get()).then_some(p)
+        })
     }
 
     pub fn check_option(&self, name: &str) -> Result<bool> {
@@ -96,10 +116,34 @@ impl<'a> ParamList<'a> {
     }
 }
 
+// Format as projstring
+impl fmt::Display for Parameter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.value {
+            Some(v) if v.contains(char::is_whitespace) => write!(f, "+{}=\"{}\"", self.name, v),
+            Some(v) => write!(f, "+{}={}", self.name, v),
+            None => write!(f, "+{}", self.name),
+        }
+    }
+}
+
+impl fmt::Display for ParamList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.iter().enumerate().try_for_each(|(i, p)| {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            p.fmt(f)
+        })
+    }
+}
+
 // Create from Parameter iterator
 impl<'a> FromIterator<Parameter<'a>> for ParamList<'a> {
     fn from_iter<I: IntoIterator<Item = Parameter<'a>>>(iter: I) -> Self {
-        Self(iter.into_iter().collect())
+        let params: Vec<_> = iter.into_iter().collect();
+        let used = params.iter().map(|_| Cell::new(false)).collect();
+        Self(params, used)
     }
 }
 
@@ -111,10 +155,10 @@ mod tests {
     fn param_check_options() {
         let params = parse("+foo +bar=true +baz=false +bad=foobar").unwrap();
 
-        assert_eq!(params.check_option("foo").unwrap(), true);
-        assert_eq!(params.check_option("bar").unwrap(), true);
-        assert_eq!(params.check_option("baz").unwrap(), false);
-        assert_eq!(params.check_option("foobar").unwrap(), false);
+        assert!(params.check_option("foo").unwrap());
+        assert!(params.check_option("bar").unwrap());
+        assert!(!params.check_option("baz").unwrap());
+        assert!(!params.check_option("foobar").unwrap());
 
         assert!(params.check_option("bad").is_err());
     }
@@ -128,6 +172,15 @@ mod tests {
         assert_eq!(f64::try_from(params.get("baz").unwrap()).unwrap(), -2.);
     }
 
+    #[test]
+    fn param_display() {
+        let params = parse("+proj=longlat  +title='My CRS' +no_defs").unwrap();
+        assert_eq!(
+            params.to_string(),
+            r#"+proj=longlat +title="My CRS" +no_defs"#
+        );
+    }
+
     #[test]
     fn param_try_value() {
         let params = parse("+foo=1234").unwrap();
@@ -135,4 +188,15 @@ mod tests {
         assert_eq!(params.try_value::<f64>("foo").unwrap().unwrap_or(0.), 1234.);
         assert_eq!(params.try_value::<f64>("bar").unwrap().unwrap_or(0.), 0.);
     }
+
+    #[test]
+    fn param_unused() {
+        let params = parse("+foo=1 +bar +baz=2").unwrap();
+        assert_eq!(params.unused().count(), 3);
+
+        assert!(params.get("bar").is_some());
+        assert!(params.try_value::<f64>("baz").unwrap().is_some());
+        assert!(params.get("qux").is_none());
+        assert_eq!(params.unused().map(|p| p.name).collect::<Vec<_>>(), ["foo"]);
+    }
 }
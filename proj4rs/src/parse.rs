@@ -60,5 +60,5 @@ mod wasm {
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 pub use wasm::FromStr;
 
-#[cfg(all(not(target_arch = "wasm32"), not(target_os = "unknown")))]
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
 pub use std::str::FromStr;
@@ -10,8 +10,11 @@ use crate::datum_transform::Datum;
 use crate::datums::{self, DatumDefn};
 use crate::ellps::Ellipsoid;
 use crate::errors::{Error, Result};
+use crate::math::{adjlon, qsfn};
+use crate::nadgrids::{AccuracyMode, InterpolationMethod};
 use crate::parameters::ParamList;
 use crate::projections::{find_projection, ProjDelegate};
+use crate::transform::{self, Transform};
 use crate::{ellipsoids, prime_meridians, projstring, units};
 
 use std::fmt;
@@ -28,6 +31,51 @@ pub type Axis = [u8; 3];
 
 const NORMALIZED_AXIS: Axis = [b'e', b'n', b'u'];
 
+/// Direction of a single axis
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AxisDirection {
+    East,
+    West,
+    North,
+    South,
+    Up,
+    Down,
+}
+
+impl AxisDirection {
+    fn from_byte(b: u8) -> Self {
+        match b {
+            b'w' => Self::West,
+            b'n' => Self::North,
+            b's' => Self::South,
+            b'u' => Self::Up,
+            b'd' => Self::Down,
+            _ => Self::East,
+        }
+    }
+}
+
+/// Axis order of a projection
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AxisOrder {
+    /// Normalized axis order: `enu`
+    EastingNorthingUp,
+    /// Swapped horizontal axis: `neu`
+    NorthingEastingUp,
+    /// Any other combination
+    Custom([AxisDirection; 3]),
+}
+
+impl From<&Axis> for AxisOrder {
+    fn from(axis: &Axis) -> Self {
+        match axis {
+            b"enu" => Self::EastingNorthingUp,
+            b"neu" => Self::NorthingEastingUp,
+            _ => Self::Custom(axis.map(AxisDirection::from_byte)),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum ProjType {
     Geocentric,
@@ -37,8 +85,8 @@ pub enum ProjType {
 
 /// A Proj object hold informations and parameters
 /// for a projection
-#[derive(Debug, Clone)]
-pub(crate) struct ProjData {
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjData {
     pub(crate) ellps: Ellipsoid,
     pub(crate) axis: Axis,
     pub(crate) proj_type: ProjType,
@@ -52,6 +100,49 @@ pub(crate) struct ProjData {
     pub(crate) phi0: f64,
 }
 
+impl ProjData {
+    /// Semi-major axis of the ellipsoid
+    #[inline]
+    pub fn a(&self) -> f64 {
+        self.ellps.a
+    }
+    /// Eccentricity of the ellipsoid
+    #[inline]
+    pub fn e(&self) -> f64 {
+        self.ellps.e
+    }
+    /// Eccentricity squared of the ellipsoid
+    #[inline]
+    pub fn es(&self) -> f64 {
+        self.ellps.es
+    }
+    /// Scale factor
+    #[inline]
+    pub fn k0(&self) -> f64 {
+        self.k0
+    }
+    /// Central meridian in radians
+    #[inline]
+    pub fn lam0(&self) -> f64 {
+        self.lam0
+    }
+    /// Latitude of origin in radians
+    #[inline]
+    pub fn phi0(&self) -> f64 {
+        self.phi0
+    }
+    /// False easting
+    #[inline]
+    pub fn x0(&self) -> f64 {
+        self.x0
+    }
+    /// False northing
+    #[inline]
+    pub fn y0(&self) -> f64 {
+        self.y0
+    }
+}
+
 ///
 /// Projection definition
 ///
@@ -73,6 +164,8 @@ pub(crate) struct ProjData {
 #[derive(Clone)]
 pub struct Proj {
     datum: Datum,
+    // Identifier from the `+datum` parameter
+    datum_id: Option<&'static str>,
     geoc: bool,
     over: bool, // over-ranging flag
     // Units
@@ -82,6 +175,7 @@ pub struct Proj {
     projdata: ProjData,
     projname: &'static str,
     projection: ProjDelegate,
+    defn: String,
 }
 
 //----------------------
@@ -93,6 +187,107 @@ impl Proj {
     pub fn projname(&self) -> &'static str {
         self.projname
     }
+    /// Return the name of the datum
+    ///
+    /// Return the datum given by the `+datum` parameter, or `None` if
+    /// there is none or if its ellipsoid or parameters are overridden.
+    pub fn datum_name(&self) -> Option<&'static str> {
+        let (a, es) = (self.projdata.ellps.a, self.projdata.ellps.es);
+        let params = self.datum.params();
+        self.datum_id
+            .and_then(datums::find_datum)
+            .filter(|defn| {
+                defn.ellps.matches(a, es)
+                    && DatumParams::try_from(&defn.params)
+                        .is_ok_and(|p| Datum::new(&self.projdata.ellps, p).params() == params)
+            })
+            .map(|defn| defn.id)
+    }
+
+    /// Return the name of the ellipsoid
+    ///
+    /// Return `None` if the ellipsoid does not match a named
+    /// ellipsoid.
+    pub fn ellipsoid_name(&self) -> Option<&'static str> {
+        ellipsoids::find_ellipsoid_by_params(self.projdata.ellps.a, self.projdata.ellps.es)
+            .map(|defn| defn.id)
+    }
+
+    /// Return a short description of the projection
+    ///
+    /// The description combines the projection name with the datum
+    /// or ellipsoid name, and an EPSG code hint for known
+    /// geographic CRS.
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let p = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+    /// assert_eq!(p.proj_string_hint(), "+proj=longlat +datum=WGS84 (EPSG:4326)");
+    /// ```
+    pub fn proj_string_hint(&self) -> String {
+        // EPSG codes of geographic CRS from datum names
+        const EPSG_GEOGRAPHIC: [(&str, u16); 9] = [
+            ("WGS84", 4326),
+            ("NAD83", 4269),
+            ("GGRS87", 4121),
+            ("potsdam", 4314),
+            ("nzgd49", 4272),
+            ("OSGB36", 4277),
+            ("ch1903", 4149),
+            ("s_jtsk", 4156),
+            ("rnb72", 4313),
+        ];
+
+        let mut hint = format!("+proj={}", self.projname);
+        if let Some(datum) = self.datum_name() {
+            hint.push_str(" +datum=");
+            hint.push_str(datum);
+            if self.is_latlong() {
+                if let Some((_, code)) = EPSG_GEOGRAPHIC.iter().find(|(d, _)| *d == datum) {
+                    hint.push_str(&format!(" (EPSG:{code})"));
+                }
+            }
+        } else if let Some(ellps) = self.ellipsoid_name() {
+            hint.push_str(" +ellps=");
+            hint.push_str(ellps);
+        }
+        hint
+    }
+
+    /// Check if two projections resolve to the same definition
+    ///
+    /// Projections are equivalent when they use the same projection
+    /// algorithm with numerically identical parameters, datum, axis and
+    /// units, whatever the way they were written. Datums defined by
+    /// nadgrids are never equivalent.
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let p1 = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+    /// let p2 = Proj::from_proj_string("+ellps=WGS84 +proj=latlong +towgs84=0,0,0").unwrap();
+    /// assert!(p1.is_equivalent_to(&p2));
+    /// ```
+    pub fn is_equivalent_to(&self, other: &Proj) -> bool {
+        self.projection == other.projection
+            && self.projdata == other.projdata
+            && self.datum.params() == other.datum.params()
+            && self.units == other.units
+            && self.vunits == other.vunits
+            && self.geoc == other.geoc
+            && self.over == other.over
+    }
+
+    /// Check if `self` and `other` are the same CRS
+    ///
+    /// This is true if both refer to the same object or if
+    /// they are equivalent (see [`Proj::is_equivalent_to`]).
+    #[inline]
+    pub fn is_same_crs(&self, other: &Proj) -> bool {
+        std::ptr::eq(self, other) || self.is_equivalent_to(other)
+    }
+
     #[inline]
     pub(crate) fn projection(&self) -> &ProjDelegate {
         &self.projection
@@ -107,8 +302,9 @@ impl Proj {
     pub fn has_forward(&self) -> bool {
         self.projection.has_forward()
     }
+    /// Return the projection parameters
     #[inline]
-    pub(crate) fn data(&self) -> &ProjData {
+    pub fn data(&self) -> &ProjData {
         &self.projdata
     }
     #[inline]
@@ -123,11 +319,67 @@ impl Proj {
     pub(crate) fn over(&self) -> bool {
         self.over
     }
-    // Delegate
+    /// Return the ellipsoid of the projection
     #[inline]
-    pub(crate) fn ellipsoid(&self) -> &Ellipsoid {
+    pub fn ellipsoid(&self) -> &Ellipsoid {
         &self.projdata.ellps
     }
+    /// Return the semi-major axis of the ellipsoid in meters
+    #[inline]
+    pub fn semi_major(&self) -> f64 {
+        self.projdata.ellps.a
+    }
+    /// Return the semi-minor axis of the ellipsoid in meters
+    #[inline]
+    pub fn semi_minor(&self) -> f64 {
+        self.projdata.ellps.b
+    }
+    /// Return the first eccentricity squared of the ellipsoid
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let p = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+    /// assert!((p.eccentricity_squared() - 0.00669437999014).abs() < 1.0e-12);
+    /// assert_eq!(p.semi_major(), 6378137.0);
+    /// ```
+    #[inline]
+    pub fn eccentricity_squared(&self) -> f64 {
+        self.projdata.ellps.es
+    }
+    /// Return the radius of the sphere having the same surface area
+    /// as the ellipsoid
+    ///
+    /// This is computed in closed form, while `+R_A` uses a series
+    /// expansion.
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let p = Proj::from_proj_string("+proj=longlat +ellps=WGS84").unwrap();
+    /// assert!((p.authalic_sphere_radius_exact() - 6371007.1809).abs() < 1.0e-4);
+    /// ```
+    pub fn authalic_sphere_radius_exact(&self) -> f64 {
+        self.projdata.ellps.authalic_sphere_radius_exact()
+    }
+    /// Return the authalic latitude of the geodetic latitude `phi`
+    ///
+    /// The authalic latitude is the latitude on the sphere having the same
+    /// surface area as the ellipsoid. Angles are in radians.
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let p = Proj::from_proj_string("+proj=longlat +ellps=GRS80").unwrap();
+    /// let beta = p.authalic_latitude(45f64.to_radians());
+    /// assert!((beta - 0.78315895610702690).abs() < 1.0e-14);
+    /// assert_eq!(p.authalic_latitude(90f64.to_radians()), 90f64.to_radians());
+    /// ```
+    pub fn authalic_latitude(&self, phi: f64) -> f64 {
+        let el = &self.projdata.ellps;
+        let q = qsfn(phi.sin(), el.e, el.one_es) / qsfn(1., el.e, el.one_es);
+        q.clamp(-1., 1.).asin()
+    }
     #[inline]
     pub fn vto_meter(&self) -> f64 {
         self.projdata.vto_meter
@@ -136,10 +388,20 @@ impl Proj {
     pub fn to_meter(&self) -> f64 {
         self.projdata.to_meter
     }
+    /// Return the projstring definition as parsed
+    #[inline]
+    pub fn definition(&self) -> &str {
+        &self.defn
+    }
     #[inline]
     pub fn axis(&self) -> &Axis {
         &self.projdata.axis
     }
+    /// Return the axis order as directions
+    #[inline]
+    pub fn axis_order(&self) -> AxisOrder {
+        AxisOrder::from(&self.projdata.axis)
+    }
     /// Return true if the axis are normalized
     #[inline]
     pub fn is_normalized_axis(&self) -> bool {
@@ -153,10 +415,47 @@ impl Proj {
     pub fn is_geocent(&self) -> bool {
         self.projdata.proj_type == ProjType::Geocentric
     }
+    /// Return true if the projection is neither geographic
+    /// nor geocentric
+    #[inline]
+    pub fn is_projected(&self) -> bool {
+        self.projdata.proj_type == ProjType::Other
+    }
+    /// Return the longitude of the prime meridian (`+pm`)
+    /// relative to Greenwich, in radians
     #[inline]
     pub fn from_greenwich(&self) -> f64 {
         self.projdata.from_greenwich
     }
+    /// Return the natural origin `(lon_0, lat_0)` in radians
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let p = Proj::from_proj_string("+proj=tmerc +lon_0=3 +lat_0=46.5 +ellps=GRS80").unwrap();
+    /// let (lam0, phi0) = p.natural_origin();
+    /// assert!((lam0.to_degrees() - 3.).abs() < 1.0e-12);
+    /// assert!((phi0.to_degrees() - 46.5).abs() < 1.0e-12);
+    /// ```
+    #[inline]
+    pub fn natural_origin(&self) -> (f64, f64) {
+        (self.projdata.lam0, self.projdata.phi0)
+    }
+    /// Return the false easting in meters
+    #[inline]
+    pub fn false_easting(&self) -> f64 {
+        self.projdata.x0
+    }
+    /// Return the false northing in meters
+    #[inline]
+    pub fn false_northing(&self) -> f64 {
+        self.projdata.y0
+    }
+    /// Return the scale factor at the natural origin
+    #[inline]
+    pub fn scale_factor(&self) -> f64 {
+        self.projdata.k0
+    }
 
     #[inline]
     pub fn projection_type(&self) -> ProjType {
@@ -175,6 +474,52 @@ impl Proj {
     pub fn vunits(&self) -> &'static str {
         self.vunits
     }
+
+    /// Cheap check that a geographic point is in the
+    /// domain of the projection
+    ///
+    /// `lam` and `phi` are in radians, as input of the forward projection.
+    /// This allows skipping invalid points before transforming them;
+    /// returning `true` does not guarantee that the projection succeeds.
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let p = Proj::from_proj_string("+proj=geos +h=35785831 +ellps=GRS80").unwrap();
+    /// assert!(p.domain_check(0., 0.));
+    /// assert!(!p.domain_check(180f64.to_radians(), 0.));
+    /// ```
+    pub fn domain_check(&self, lam: f64, phi: f64) -> bool {
+        match self.projdata.proj_type {
+            ProjType::Other => {
+                let lam = lam - self.projdata.lam0;
+                self.projection
+                    .domain_check(if self.over { lam } else { adjlon(lam) }, phi)
+            }
+            _ => true,
+        }
+    }
+
+    /// Transform `points` from this CRS to `dst`
+    ///
+    /// This is a shortcut for [`transform()`](crate::transform::transform).
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+    /// let dst = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+    ///
+    /// let mut pt = (3f64.to_radians(), 45f64.to_radians(), 0.);
+    /// src.transform_to(&dst, &mut pt).unwrap();
+    /// assert!((pt.0 - 500000.).abs() < 1.0e-6);
+    /// ```
+    pub fn transform_to<P>(&self, dst: &Proj, points: &mut P) -> Result<()>
+    where
+        P: Transform + ?Sized,
+    {
+        transform::transform(self, dst, points)
+    }
 }
 
 //-------------------------
@@ -198,6 +543,7 @@ impl Proj {
     // --------------
     // Prime meridian
     // --------------
+    // Prime meridian offset in radians
     fn get_prime_meridian(params: &ParamList) -> Result<f64> {
         params
             .get("pm")
@@ -208,19 +554,34 @@ impl Proj {
                 },
             )
             .unwrap_or(Ok(0.))
+            .map(f64::to_radians)
     }
 
     // -----------------
     // Datum parameters
     // ----------------
-    fn get_datum_params(params: &ParamList, defn: Option<&DatumDefn>) -> Result<DatumParams> {
-        // Precedence order is 'nadgrids', 'towgs84', 'datum'
+    fn get_datum_params(
+        params: &ParamList,
+        defn: Option<&DatumDefn>,
+        project: &ProjDelegate,
+    ) -> Result<DatumParams> {
+        // Precedence order is 'nadgrids', 'towgs84', projection, 'datum'
         if let Some(p) = params.get("nadgrids") {
             // Nadgrids
-            DatumParams::from_nadgrid_str(p.try_into()?)
+            let accuracy = match params.get("nadgrid_accuracy") {
+                Some(mode) => AccuracyMode::try_from(<&str>::try_from(mode)?)?,
+                None => AccuracyMode::default(),
+            };
+            let interpolation = match params.get("nadgrid_interpolation") {
+                Some(method) => InterpolationMethod::try_from(<&str>::try_from(method)?)?,
+                None => InterpolationMethod::default(),
+            };
+            DatumParams::from_nadgrid_str(p.try_into()?, accuracy, interpolation)
         } else if let Some(p) = params.get("towgs84") {
             DatumParams::from_towgs84_str(p.try_into()?)
             // ToWGS84
+        } else if let Some(p) = project.datum_params()? {
+            Ok(p)
         } else if let Some(p) = defn {
             DatumParams::try_from(&p.params)
         } else {
@@ -316,6 +677,31 @@ impl Proj {
     /// Consume a ParamList and create a Proj object
     ///
     pub fn init(params: ParamList) -> Result<Self> {
+        Self::init_from(&params)
+    }
+
+    ///
+    /// Strict Proj object constructor
+    ///
+    /// Same as [`init`](Self::init) but return [`Error::UnknownParameter`]
+    /// for the first parameter that is not used by the
+    /// initialization, i.e a parameter unknown or meaningless for the
+    /// selected projection.
+    ///
+    /// Informative parameters (`no_defs`, `type`, `wktext` and `title`)
+    /// are accepted.
+    ///
+    pub fn init_strict(params: ParamList) -> Result<Self> {
+        const INFORMATIVE: [&str; 4] = ["no_defs", "type", "wktext", "title"];
+
+        let proj = Self::init_from(&params)?;
+        match params.unused().find(|p| !INFORMATIVE.contains(&p.name)) {
+            Some(p) => Err(Error::UnknownParameter(p.name.into())),
+            None => Ok(proj),
+        }
+    }
+
+    fn init_from(params: &ParamList) -> Result<Self> {
         // Find projection
         let proj_init = params
             .get("proj")
@@ -323,32 +709,26 @@ impl Proj {
             .and_then(|name| find_projection(name.try_into()?).ok_or(Error::ProjectionNotFound))?;
 
         // Get datum definition (if any)
-        let datum_defn = Self::get_datum_defn(&params)?;
-
-        // Get datum parameters
-        let datum_params = Self::get_datum_params(&params, datum_defn)?;
+        let datum_defn = Self::get_datum_defn(params)?;
 
         // Do we have an ellipse ?
-        let ellps = Self::get_ellipsoid(&params, datum_defn)?;
+        let ellps = Self::get_ellipsoid(params, datum_defn)?;
 
         // Get prime meridian
-        let from_greenwich = Self::get_prime_meridian(&params)?;
+        let from_greenwich = Self::get_prime_meridian(params)?;
 
         // Axis
-        let axis = Self::get_axis(&params)?;
+        let axis = Self::get_axis(params)?;
 
         // horizontal units
-        let horz_units = Self::get_horizontal_units(&params)?;
-        let vert_units = Self::get_vertical_units(&params)?;
+        let horz_units = Self::get_horizontal_units(params)?;
+        let vert_units = Self::get_vertical_units(params)?;
 
         let to_meter = horz_units.to_meter;
         let vto_meter = vert_units.to_meter;
 
-        // Datum
-        let datum = Datum::new(&ellps, datum_params);
-
         let mut projdata = ProjData {
-            ellps,
+            ellps: ellps.clone(),
             axis,
             proj_type: ProjType::Other,
             from_greenwich,
@@ -360,16 +740,25 @@ impl Proj {
             x0: params.try_value("x_0")?.unwrap_or(0.),
             y0: params.try_value("y_0")?.unwrap_or(0.),
             // Proj4 compatibility
-            k0: match params.get("k0") {
+            k0: match params.get("k_0").or_else(|| params.get("k0")) {
                 Some(p) => Some(p.try_into()).transpose(),
                 None => params.try_value("k"),
             }?
             .unwrap_or(1.),
         };
 
-        let project = proj_init.init(&mut projdata, &params)?;
+        let project = proj_init.init(&mut projdata, params)?;
+
+        // Datum
+        let datum_params = Self::get_datum_params(params, datum_defn, &project)?;
+        let datum = if project.fixed_ellipsoid() {
+            Datum::new(&projdata.ellps, datum_params)
+        } else {
+            Datum::new(&ellps, datum_params)
+        };
         Ok(Self {
             datum,
+            datum_id: datum_defn.map(|defn| defn.id),
             // Use Geocentric Latitude
             // see https://proj.org/operations/conversions/geoc.html
             geoc: params.check_option("geoc")?,
@@ -379,12 +768,60 @@ impl Proj {
             projdata,
             projname: proj_init.name(),
             projection: project,
+            defn: params.to_string(),
         })
     }
 
     /// Create from projstring definition
+    ///
+    /// The legacy `+init=epsg:<code>` form is supported with the
+    /// feature *crs-definitions*: other parameters override those
+    /// of the EPSG definition.
     pub fn from_proj_string(s: &str) -> Result<Self> {
-        Self::init(projstring::parse(s)?)
+        let params = projstring::parse(s)?;
+        match params.get("init") {
+            Some(init) => Self::from_proj_string(&Self::expand_init(init.try_into()?, &params)?),
+            None => Self::init(params),
+        }
+    }
+
+    ///
+    /// Strict version of [`from_proj_string`](Self::from_proj_string)
+    ///
+    /// Return [`Error::UnknownParameter`] for parameters not used by the
+    /// initialization, see [`init_strict`](Self::init_strict).
+    ///
+    pub fn from_proj_string_strict(s: &str) -> Result<Self> {
+        let params = projstring::parse(s)?;
+        match params.get("init") {
+            Some(init) => {
+                Self::from_proj_string_strict(&Self::expand_init(init.try_into()?, &params)?)
+            }
+            None => Self::init_strict(params),
+        }
+    }
+
+    #[cfg(feature = "crs-definitions")]
+    fn expand_init(init: &str, params: &ParamList) -> Result<String> {
+        let defn = match init.split_once(':') {
+            Some((auth, code)) if auth.eq_ignore_ascii_case("epsg") => u16::from_str(code)
+                .map_err(|_| Error::UnrecognizedFormat)
+                .and_then(|code| crs_definitions::from_code(code).ok_or(Error::ProjectionNotFound)),
+            _ => Err(Error::UnrecognizedFormat),
+        }?;
+        // First parameters take precedence
+        Ok(params
+            .iter()
+            .filter(|p| p.name != "init")
+            .map(|p| p.to_string())
+            .chain(std::iter::once(defn.proj4.to_string()))
+            .collect::<Vec<_>>()
+            .join(" "))
+    }
+
+    #[cfg(not(feature = "crs-definitions"))]
+    fn expand_init(_: &str, _: &ParamList) -> Result<String> {
+        Err(Error::UnrecognizedFormat)
     }
 
     ///
@@ -470,12 +907,44 @@ impl fmt::Debug for Proj {
     }
 }
 
+/// Return the `+proj=longlat +datum=WGS84` projection
+///
+/// The projection is initialized once and cloned on each call.
+///
+/// ```rust
+/// use proj4rs::proj::wgs84;
+///
+/// assert!(wgs84().is_latlong());
+/// ```
+pub fn wgs84() -> Proj {
+    const DEFN: &str = "+proj=longlat +datum=WGS84";
+
+    #[cfg(feature = "multi-thread")]
+    {
+        static WGS84: std::sync::OnceLock<Proj> = std::sync::OnceLock::new();
+        WGS84
+            .get_or_init(|| Proj::from_proj_string(DEFN).unwrap())
+            .clone()
+    }
+
+    // Without multi-thread support, projections are
+    // not shareable between threads
+    #[cfg(not(feature = "multi-thread"))]
+    {
+        thread_local! {
+            static WGS84: Proj = Proj::from_proj_string(DEFN).unwrap();
+        }
+        WGS84.with(Proj::clone)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(non_snake_case)]
 
     use super::*;
     use crate::errors::{Error, Result};
+    use approx::assert_abs_diff_eq;
 
     const INVALID_ELLPS: &str = "+proj=latlong +lon_0=5.937 +lat_ts=45.027 +ellps=foo";
 
@@ -488,4 +957,267 @@ mod tests {
         println!("{:?}", err);
         assert!(matches!(err, Error::InvalidEllipsoid));
     }
+
+    #[test]
+    fn proj_is_projected() {
+        let p = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+        assert_eq!(p.projection_type(), ProjType::Other);
+        assert!(p.is_projected());
+
+        let p = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+        assert!(p.is_latlong() && !p.is_projected());
+
+        let p = Proj::from_proj_string("+proj=cart +ellps=GRS80").unwrap();
+        assert!(p.is_geocent() && !p.is_projected());
+    }
+
+    #[test]
+    fn proj_axis_order() {
+        let p = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+        assert_eq!(p.axis_order(), AxisOrder::EastingNorthingUp);
+
+        let p = Proj::from_proj_string("+proj=latlong +axis=neu +ellps=WGS84").unwrap();
+        assert_eq!(p.axis_order(), AxisOrder::NorthingEastingUp);
+
+        let p = Proj::from_proj_string("+proj=latlong +axis=wsu +ellps=WGS84").unwrap();
+        assert_eq!(
+            p.axis_order(),
+            AxisOrder::Custom([AxisDirection::West, AxisDirection::South, AxisDirection::Up])
+        );
+    }
+
+    #[test]
+    fn proj_datum_and_ellipsoid_names() {
+        let p = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+        assert_eq!(p.datum_name(), Some("WGS84"));
+        assert_eq!(p.ellipsoid_name(), Some("WGS84"));
+        assert_eq!(
+            p.proj_string_hint(),
+            "+proj=longlat +datum=WGS84 (EPSG:4326)"
+        );
+
+        let p = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+        assert_eq!(p.datum_name(), None);
+        assert_eq!(p.ellipsoid_name(), Some("GRS80"));
+        assert_eq!(p.proj_string_hint(), "+proj=utm +ellps=GRS80");
+
+        let p = Proj::from_proj_string("+proj=tmerc +datum=OSGB36").unwrap();
+        assert_eq!(p.datum_name(), Some("OSGB36"));
+        assert_eq!(p.ellipsoid_name(), Some("airy"));
+        assert_eq!(p.proj_string_hint(), "+proj=tmerc +datum=OSGB36");
+
+        // Datums sharing the same parameters
+        let p = Proj::from_proj_string("+proj=longlat +datum=GDA2020").unwrap();
+        assert_eq!(p.datum_name(), Some("GDA2020"));
+        let p = Proj::from_proj_string("+proj=longlat +datum=NAD83").unwrap();
+        assert_eq!(p.datum_name(), Some("NAD83"));
+        let p = Proj::from_proj_string("+proj=longlat +ellps=GRS80 +towgs84=0,0,0").unwrap();
+        assert_eq!(p.datum_name(), None);
+
+        // Overridden datum parameters
+        let p = Proj::from_proj_string("+proj=longlat +datum=WGS84 +towgs84=1,2,3").unwrap();
+        assert_eq!(p.datum_name(), None);
+        let p = Proj::from_proj_string("+proj=longlat +datum=WGS84 +ellps=intl").unwrap();
+        assert_eq!(p.datum_name(), None);
+
+        // Custom ellipsoid
+        let p = Proj::from_proj_string("+proj=longlat +a=6378000 +rf=300").unwrap();
+        assert_eq!(p.datum_name(), None);
+        assert_eq!(p.ellipsoid_name(), None);
+        assert_eq!(p.proj_string_hint(), "+proj=longlat");
+    }
+
+    #[test]
+    fn proj_is_equivalent_to() {
+        let utm = Proj::from_proj_string("+proj=utm +zone=30 +ellps=GRS80").unwrap();
+        let p = Proj::from_proj_string("+ellps=GRS80 +units=m +zone=30 +proj=utm").unwrap();
+        assert!(utm.is_equivalent_to(&p));
+
+        // Explicit parameters with the exact central meridian
+        let etmerc = Proj::from_proj_string(&format!(
+            "+proj=etmerc +lon_0={:?} +k=0.9996 +x_0=500000 +y_0=0 +ellps=GRS80 +units=m",
+            utm.data().lam0().to_degrees(),
+        ))
+        .unwrap();
+        assert!(utm.is_equivalent_to(&etmerc));
+        assert!(etmerc.is_equivalent_to(&utm));
+
+        // The utm central meridian is computed as in proj and differs
+        // from lon_0=-3 by one ulp
+        let p = Proj::from_proj_string("+proj=etmerc +lon_0=-3 +k=0.9996 +x_0=500000 +ellps=GRS80")
+            .unwrap();
+        assert_ne!(utm.data().lam0(), p.data().lam0());
+        assert!(!utm.is_equivalent_to(&p));
+
+        // Different scale factor
+        let p = Proj::from_proj_string("+proj=etmerc +lon_0=-3 +k=0.9999 +x_0=500000 +ellps=GRS80")
+            .unwrap();
+        assert!(!utm.is_equivalent_to(&p));
+
+        // Same parameters with a different algorithm
+        let p = Proj::from_proj_string("+proj=tmerc +lon_0=-3 +k=0.9996 +x_0=500000 +ellps=GRS80")
+            .unwrap();
+        assert!(!utm.is_equivalent_to(&p));
+
+        // Different datum
+        let p = Proj::from_proj_string("+proj=utm +zone=30 +ellps=GRS80 +towgs84=1,2,3").unwrap();
+        assert!(!utm.is_equivalent_to(&p));
+
+        // Different units
+        let p = Proj::from_proj_string("+proj=utm +zone=30 +ellps=GRS80 +units=ft").unwrap();
+        assert!(!utm.is_equivalent_to(&p));
+    }
+
+    #[cfg(feature = "crs-definitions")]
+    #[test]
+    fn proj_init_epsg() {
+        let p = Proj::from_proj_string("+init=epsg:3857").unwrap();
+        assert_eq!(p.projname(), "merc");
+        assert!(p.is_equivalent_to(&Proj::from_epsg_code(3857).unwrap()));
+
+        let p = Proj::from_proj_string("+init=EPSG:4326 +over").unwrap();
+        assert!(p.is_latlong());
+        assert!(p.over());
+        assert_eq!(p.datum_name(), Some("WGS84"));
+
+        // Parameters override the definition
+        let p = Proj::from_proj_string("+init=epsg:4326 +ellps=GRS80").unwrap();
+        assert_eq!(p.ellipsoid_name(), Some("GRS80"));
+
+        assert!(Proj::from_proj_string("+init=epsg:0").is_err());
+        assert!(Proj::from_proj_string("+init=foo:4326").is_err());
+    }
+
+    #[cfg(not(feature = "crs-definitions"))]
+    #[test]
+    fn proj_init_unsupported() {
+        assert!(matches!(
+            Proj::from_proj_string("+init=epsg:4326"),
+            Err(Error::UnrecognizedFormat)
+        ));
+    }
+
+    #[test]
+    fn proj_wgs84() {
+        let (a, b) = (wgs84(), wgs84());
+        assert!(a.is_latlong());
+        assert!(a.is_equivalent_to(&b));
+        assert!(a.is_equivalent_to(&Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap()));
+        assert_eq!(a.definition(), b.definition());
+    }
+
+    #[test]
+    fn proj_wgs84_datum_shift() {
+        let src = Proj::from_proj_string("+proj=longlat +ellps=bessel +towgs84=598.1,73.7,418.2")
+            .unwrap();
+        let dst = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+
+        let (lon, lat) = (-2f64.to_radians(), 52f64.to_radians());
+        let mut pt = (lon, lat, 0.);
+        let mut expected = (lon, lat, 0.);
+        crate::transform::transform(&src, &wgs84(), &mut pt).unwrap();
+        crate::transform::transform(&src, &dst, &mut expected).unwrap();
+
+        assert_eq!(pt, expected);
+        // The datum shift is applied
+        assert!((pt.0 - lon).abs() > 1.0e-5);
+    }
+
+    #[test]
+    fn proj_natural_origin() {
+        let p = Proj::from_proj_string("+proj=utm +zone=33 +south +ellps=GRS80").unwrap();
+        let (lam0, phi0) = p.natural_origin();
+        assert!((lam0.to_degrees() - 15.).abs() < 1.0e-12);
+        assert_eq!(phi0, 0.);
+        assert_eq!(p.scale_factor(), 0.9996);
+        assert_eq!(p.false_easting(), 500000.);
+        assert_eq!(p.false_northing(), 10000000.);
+    }
+
+    #[test]
+    fn proj_prime_meridian() {
+        let pm = |s: &str| Proj::from_proj_string(s).unwrap().from_greenwich();
+
+        assert_eq!(pm("+proj=latlong +ellps=GRS80"), 0.);
+        assert_eq!(pm("+proj=latlong +ellps=GRS80 +pm=greenwich"), 0.);
+        assert_eq!(
+            pm("+proj=latlong +ellps=GRS80 +pm=lisbon"),
+            (-9.131906111111f64).to_radians()
+        );
+        // Numeric values are in degrees
+        assert_eq!(
+            pm("+proj=latlong +ellps=GRS80 +pm=-9.131906111111"),
+            pm("+proj=latlong +ellps=GRS80 +pm=lisbon")
+        );
+        assert!(Proj::from_proj_string("+proj=latlong +ellps=GRS80 +pm=foo").is_err());
+
+        // The Paris meridian is at longitude 0 in the Paris based CRS
+        let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+        let dst = Proj::from_proj_string("+proj=latlong +ellps=GRS80 +pm=paris").unwrap();
+        let mut pt = (2.337229166667f64.to_radians(), 0.8, 0.);
+        crate::transform::transform(&src, &dst, &mut pt).unwrap();
+        assert!(pt.0.abs() < 1.0e-15);
+        assert_eq!(pt.1, 0.8);
+
+        // Same as a central meridian for projections
+        let src = Proj::from_proj_string("+proj=latlong +ellps=clrk80ign").unwrap();
+        let pm = Proj::from_proj_string("+proj=merc +ellps=clrk80ign +pm=paris").unwrap();
+        let lon0 =
+            Proj::from_proj_string("+proj=merc +ellps=clrk80ign +lon_0=2.337229166667").unwrap();
+        let mut a = (5.0f64.to_radians(), 45.0f64.to_radians(), 0.);
+        let mut b = a;
+        crate::transform::transform(&src, &pm, &mut a).unwrap();
+        crate::transform::transform(&src, &lon0, &mut b).unwrap();
+        assert_abs_diff_eq!(a.0, b.0, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(a.1, b.1, epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn proj_init_strict() {
+        let strict = |s| Proj::init_strict(projstring::parse(s).unwrap());
+
+        assert!(
+            strict("+proj=tmerc +lat_0=45 +lon_0=3 +k=0.9996 +x_0=500000 +ellps=GRS80").is_ok()
+        );
+        assert!(
+            strict("+proj=utm +zone=31 +south +datum=WGS84 +units=m +no_defs +type=crs").is_ok()
+        );
+
+        // Meaningless for tmerc
+        assert!(matches!(
+            strict("+proj=tmerc +lat_1=45 +ellps=GRS80"),
+            Err(Error::UnknownParameter(name)) if name == "lat_1"
+        ));
+        assert!(matches!(
+            strict("+proj=utm +zone=31 +lat_ts=45 +ellps=GRS80"),
+            Err(Error::UnknownParameter(name)) if name == "lat_ts"
+        ));
+
+        // Relaxed mode
+        assert!(Proj::init(projstring::parse("+proj=tmerc +lat_1=45").unwrap()).is_ok());
+    }
+
+    #[test]
+    fn proj_from_proj_string_strict() {
+        // Typo in lon_0
+        let defn = "+proj=tmerc +lat_0=45 +lon_=10 +ellps=GRS80";
+        assert!(matches!(
+            Proj::from_proj_string_strict(defn),
+            Err(Error::UnknownParameter(name)) if name == "lon_"
+        ));
+        assert!(Proj::from_proj_string(defn).is_ok());
+
+        // Overridden parameters are not reported
+        assert!(Proj::from_proj_string_strict("+proj=merc +lon_0=10 +lon_0=5").is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "crs-definitions")]
+    fn proj_from_proj_string_strict_init() {
+        assert!(Proj::from_proj_string_strict("+init=epsg:2154 +x_0=0").is_ok());
+        assert!(matches!(
+            Proj::from_proj_string_strict("+init=epsg:2154 +lon_=10"),
+            Err(Error::UnknownParameter(name)) if name == "lon_"
+        ));
+    }
 }
@@ -18,7 +18,12 @@ use crate::proj::ProjData;
 // Projection stub
 super::projection! { aea, leac }
 
-const PHI_NITER: usize = 15;
+// Newton iterations converge slowly close to the poles
+const PHI_NITER: usize = 30;
+
+// Tolerance on `ec - |qs|` for falling back to the pole
+// when the iteration does not converge
+const POLE_TOL: f64 = 1.0e-5;
 
 // determine latitude angle phi1
 #[inline]
@@ -51,7 +56,7 @@ fn phi1_inv(qs: f64, e: f64, one_es: f64) -> Result<f64> {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub(crate) struct Projection {
     e: f64,
     one_es: f64,
@@ -92,11 +97,21 @@ impl Projection {
                 n = (m1 * m1 - m2 * m2) / (ml2 - ml1);
             }
 
+            let ml0 = qsfn(p.phi0.sin(), el.e, el.one_es);
+            if ml0.is_infinite() {
+                return Err(Error::ToleranceConditionError);
+            }
+
             let ec = 1. - 0.5 * el.one_es * ((1. - el.e) / (1. + el.e)).ln() / el.e;
             let c = m1 * m1 + n * ml1;
             let dd = 1. / n;
             let n2 = n + n;
-            let rho0 = dd * (c - n * qsfn(p.phi0.sin(), el.e, el.one_es)).sqrt();
+            let rho0 = dd * (c - n * ml0).sqrt();
+
+            // Degenerated ellipsoid (e ~ 1)
+            if !(ec.is_finite() && rho0.is_finite()) {
+                return Err(Error::ToleranceConditionError);
+            }
 
             Ok(Self {
                 e: el.e,
@@ -166,7 +181,11 @@ impl Projection {
             if self.is_ellipse() {
                 phi = (self.c - phi * phi) / self.n;
                 phi = if (self.ec - phi.abs()).abs() > EPS_7 {
-                    phi1_inv(phi, self.e, self.one_es)?
+                    match phi1_inv(phi, self.e, self.one_es) {
+                        Ok(phi) => phi,
+                        Err(_) if (self.ec - phi.abs()).abs() < POLE_TOL => FRAC_PI_2.copysign(phi),
+                        Err(err) => return Err(err),
+                    }
                 } else if phi < 0. {
                     -FRAC_PI_2
                 } else {
@@ -225,11 +244,13 @@ impl Projection {
 
 #[cfg(test)]
 mod tests {
+    use crate::errors::Error;
     use crate::math::consts::EPS_10;
     use crate::proj::Proj;
     use crate::tests::utils::{test_proj_forward, test_proj_inverse};
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn proj_aea_aea_ellipsoidal() {
         let p = Proj::from_proj_string("+proj=aea +ellps=GRS80 +lat_1=0 +lat_2=2").unwrap();
 
@@ -270,6 +291,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn proj_aea_leac_ellipsoidal() {
         let p = Proj::from_proj_string("+proj=leac +ellps=GRS80").unwrap();
 
@@ -309,4 +331,69 @@ mod tests {
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    fn proj_aea_near_pole() {
+        use crate::math::consts::FRAC_PI_2;
+        use approx::assert_abs_diff_eq;
+
+        // Conus Albers
+        let p = Proj::from_proj_string(
+            "+proj=aea +ellps=GRS80 +lat_1=29.5 +lat_2=45.5 +lat_0=23 +lon_0=-96",
+        )
+        .unwrap();
+
+        let inputs = [((10., 89.9, 0.), (3631192.5069999387, 8150638.716879059, 0.))];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+
+        // As in proj, points closer to the pole than the
+        // `ec` tolerance are mapped to the pole.
+        let inputs = [(
+            (10., 89.99, 0.),
+            (3631169.755717088, 8150649.8588296035, 0.),
+        )];
+        test_proj_forward(&p, &inputs, 1.0e-8);
+
+        let d = p.data();
+        let (_, phi, _) = p
+            .projection()
+            .inverse(
+                (inputs[0].1 .0 - d.x0) / d.ellps.a,
+                (inputs[0].1 .1 - d.y0) / d.ellps.a,
+                0.,
+            )
+            .unwrap();
+        assert_abs_diff_eq!(phi, FRAC_PI_2, epsilon = 0.01f64.to_radians());
+
+        // High eccentricity, close to the pole
+        let p = Proj::from_proj_string(
+            "+proj=aea +a=6378137 +es=0.5 +lat_1=29.5 +lat_2=45.5 +lat_0=23 +lon_0=-96",
+        )
+        .unwrap();
+
+        let inputs = [(
+            (10., 89.987, 0.),
+            (4237454.408262107, 8128755.042860355, 0.),
+        )];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_aea_degenerated_ellipsoid() {
+        // e rounds to 1
+        let p = Proj::from_proj_string(
+            "+proj=aea +lat_1=29.5 +lat_2=45.5 +a=6378137 +es=0.9999999999999999",
+        );
+        assert!(matches!(p, Err(Error::ToleranceConditionError)));
+
+        // Close to 1 but still valid
+        let p = Proj::from_proj_string("+proj=aea +lat_1=29.5 +lat_2=45.5 +a=6378137 +es=0.999999")
+            .unwrap();
+        let (x, y, _) = p.projection().forward(0.1, 0.5, 0.).unwrap();
+        assert!(x.is_finite() && y.is_finite());
+    }
 }
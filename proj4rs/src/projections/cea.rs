@@ -0,0 +1,209 @@
+//!
+//! From proj/cea.cpp
+//!
+//! See also <https://proj.org/operations/projections/cea.html>
+//!
+//! cea: "Equal Area Cylindrical" "\n\tCyl, Sph&Ell\n\tlat_ts="
+//!
+//! The scale factor on the equator is given either by `lat_ts`
+//! or by the `k` parameter.
+//!
+use crate::errors::{Error, Result};
+use crate::math::{
+    aasin, authlat, authset,
+    consts::{EPS_10, FRAC_PI_2},
+    qsfn,
+};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { cea }
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {
+    k0: f64,
+    e: f64,
+    one_es: f64,
+    qp: f64,
+    apa: (f64, f64, f64),
+    is_ellps: bool,
+}
+
+impl Projection {
+    pub fn cea(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let t = match params.try_angular_value("lat_ts")? {
+            Some(t) => {
+                p.k0 = t.cos();
+                if p.k0 < 0. {
+                    return Err(Error::InvalidParameterValue("lat_ts should be <= 90°"));
+                }
+                t
+            }
+            None => 0.,
+        };
+
+        let el = &p.ellps;
+        if el.is_ellipsoid() {
+            let qp = qsfn(1., el.e, el.one_es);
+            if qp.is_infinite() {
+                return Err(Error::ToleranceConditionError);
+            }
+            let t = t.sin();
+            p.k0 /= (1. - el.es * t * t).sqrt();
+            Ok(Self {
+                k0: p.k0,
+                e: el.e,
+                one_es: el.one_es,
+                qp,
+                apa: authset(el.es),
+                is_ellps: true,
+            })
+        } else {
+            Ok(Self {
+                k0: p.k0,
+                e: 0.,
+                one_es: 1.,
+                qp: 0.,
+                apa: (0., 0., 0.),
+                is_ellps: false,
+            })
+        }
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if self.is_ellps {
+            Ok((
+                self.k0 * lam,
+                0.5 * qsfn(phi.sin(), self.e, self.one_es) / self.k0,
+                z,
+            ))
+        } else {
+            Ok((self.k0 * lam, phi.sin() / self.k0, z))
+        }
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if self.is_ellps {
+            Ok((
+                x / self.k0,
+                authlat(aasin(2. * y * self.k0 / self.qp)?, self.apa),
+                z,
+            ))
+        } else {
+            let y = y * self.k0;
+            let t = y.abs();
+            if t - EPS_10 <= 1. {
+                let phi = if t >= 1. {
+                    FRAC_PI_2.copysign(y)
+                } else {
+                    y.asin()
+                };
+                Ok((x / self.k0, phi, z))
+            } else {
+                Err(Error::CoordinateOutOfRange)
+            }
+        }
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_cea_ellps() {
+        let p = Proj::from_proj_string("+proj=cea +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222638.98158654713, 110568.81239626736, 0.)),
+            ((2., -1., 0.), (222638.98158654713, -110568.81239626736, 0.)),
+            ((-2., 1., 0.), (-222638.98158654713, 110568.81239626736, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222638.98158654713, -110568.81239626736, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_cea_sph() {
+        // Lambert cylindrical equal area
+        let p = Proj::from_proj_string("+proj=cea +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223402.14425527418, 111695.40119861448, 0.)),
+            ((2., -1., 0.), (223402.14425527418, -111695.40119861448, 0.)),
+            ((-2., 1., 0.), (-223402.14425527418, 111695.40119861448, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223402.14425527418, -111695.40119861448, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_cea_lat_ts() {
+        // EASE-Grid 2.0 global (EPSG:6933)
+        let p = Proj::from_proj_string("+proj=cea +lat_ts=30 +ellps=WGS84").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (192972.56050179305, 127566.98529541331, 0.)),
+            (
+                (-120., 45., 0.),
+                (-11578353.630107582, 5180102.328839251, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_cea_k() {
+        let p = Proj::from_proj_string("+proj=cea +k=0.5 +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [((2., 1., 0.), (111319.49079327357, 221137.6247925347, 0.))];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_cea_degenerated_ellipsoid() {
+        // Eccentricity close to 1: no NaN output
+        let p = Proj::from_proj_string("+proj=cea +a=6378137 +es=0.9999999999999999").unwrap();
+        for (lam, phi) in [(0.1, 0.5), (0., 1.5), (-0.2, -0.7)] {
+            let (x, y, _) = p.projection().forward(lam, phi, 0.).unwrap();
+            assert!(x.is_finite() && y.is_finite());
+            let (lam, phi, _) = p.projection().inverse(x, y, 0.).unwrap();
+            assert!(lam.is_finite() && phi.is_finite());
+        }
+    }
+}
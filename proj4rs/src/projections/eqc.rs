@@ -14,9 +14,9 @@ use crate::parameters::ParamList;
 use crate::proj::ProjData;
 
 // Projection stub
-super::projection! { eqc }
+super::projection! { eqc, plate_carree }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Projection {
     rc: f64,
     phi0: f64,
@@ -32,6 +32,10 @@ impl Projection {
         Ok(Self { rc, phi0: p.phi0 })
     }
 
+    pub fn plate_carree(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        Self::eqc(p, params)
+    }
+
     #[inline(always)]
     pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
         Ok((lam * self.rc, phi - self.phi0, z))
@@ -58,6 +62,7 @@ mod tests {
     use crate::tests::utils::{test_proj_forward, test_proj_inverse};
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn proj_eqc_wgs84() {
         let p = Proj::from_proj_string("+proj=eqc +ellps=WGS84").unwrap();
 
@@ -70,6 +75,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn proj_eqc_lat_ts() {
         let p = Proj::from_proj_string("+proj=eqc +lat_ts=30 +lon_0=-90").unwrap();
 
@@ -83,4 +89,44 @@ mod tests {
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_eqc_plate_carree() {
+        let p = Proj::from_proj_string("+proj=plate_carree +R=6378137").unwrap();
+        assert_eq!(p.projname(), "plate_carree");
+
+        let inputs = [((2., 47., 0.), (222638.98158654713, 5232016.06728385761, 0.))];
+
+        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_eqc_lat_0() {
+        let p = Proj::from_proj_string("+proj=eqc +lat_0=45 +ellps=WGS84").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // y = 0 on the origin parallel
+        let inputs = [
+            ((2., 45., 0.), (222638.98158654713, 0., 0.)),
+            ((2., 0., 0.), (222638.98158654713, -5009377.085697311, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_eqc_lat_0_lat_ts() {
+        let p = Proj::from_proj_string("+proj=eqc +lat_0=60 +lat_ts=60 +ellps=WGS84").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [((10., 70., 0.), (556597.4539663679, 1113194.9079327357, 0.))];
+
+        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
 }
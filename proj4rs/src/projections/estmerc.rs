@@ -15,7 +15,7 @@ use crate::math::{
 use crate::parameters::ParamList;
 use crate::proj::ProjData;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Ell {
     k0: f64,
     es: f64,
@@ -24,14 +24,14 @@ pub(crate) struct Ell {
     en: Enfn,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Sph {
     phi0: f64,
     esp: f64,
     ml0: f64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum Projection {
     Ell(Ell),
     Sph(Sph),
@@ -98,8 +98,9 @@ fn clens(a: &Coeffs, arg_r: f64) -> f64 {
     arg_r.sin() * hr
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Projection {
+    es: f64,     // Eccentricity squared
     Qn: f64,     // Merid. quad., scaled to the projection
     Zb: f64,     // Radius vector in polar coord. systems
     cgb: Coeffs, // Constants for Gauss -> Geo lat
@@ -189,6 +190,7 @@ impl Projection {
         let Zb  = - Qn *(z + clens(&gtu, 2.*z));
 
         Ok(Self {
+            es: p.ellps.es,
             Qn,
             Zb,
             cgb,
@@ -259,6 +261,37 @@ impl Projection {
         }
     }
 
+    /// Analytic point scale factor and meridian convergence (radians)
+    ///
+    /// Use the derivatives of the Krüger series,
+    /// see C. F. F. Karney, "Transverse Mercator with an accuracy of
+    /// a few nanometers", J. Geodesy 85(8), 475-485 (2011), eqs (25) - (28).
+    pub fn factors(&self, lam: f64, phi: f64) -> (f64, f64) {
+        // ell. LAT -> Gaussian LAT
+        let chi = gatg(&self.cbg, phi);
+        let (sin_chi, cos_chi) = chi.sin_cos();
+        let (sin_lam, cos_lam) = lam.sin_cos();
+
+        // Gaussian LAT, LNG -> compl. sph. N, E
+        let xi = sin_chi.atan2(cos_lam*cos_chi);
+        let eta = asinh(sin_lam*cos_chi / sin_chi.hypot(cos_chi*cos_lam));
+
+        // Derivative of the series (coefficients are stored backward)
+        let (p, q) = self.gtu.iter().rev().enumerate().fold((1., 0.), |(p, q), (j, c)| {
+            let m = 2. * (j + 1) as f64;
+            (
+                p + m * c * (m*xi).cos() * (m*eta).cosh(),
+                q + m * c * (m*xi).sin() * (m*eta).sinh(),
+            )
+        });
+
+        let gamma = (sin_chi*sin_lam).atan2(cos_lam) + q.atan2(p);
+        let k = self.Qn * p.hypot(q) * cos_chi * (1. - self.es*phi.sin().powi(2)).sqrt()
+            / (phi.cos() * sin_chi.hypot(cos_chi*cos_lam));
+
+        (k, gamma)
+    }
+
     pub const fn has_inverse() -> bool {
         true
     }
@@ -287,13 +320,10 @@ impl Projection {
                 }
             }
             None => {
-                // nearest central meridian input
-                let zone = ((adjlon(p.lam0) + PI) * 30. / PI).floor().round();
-                if (1. ..=60.).contains(&zone) {
-                    Ok(zone)
-                } else {
-                    Err(Error::InvalidUtmZone)
-                }
+                // nearest central meridian input, clamped so
+                // that the antimeridian falls in zones 1 or 60
+                let zone = ((adjlon(p.lam0) + PI) * 30. / PI).floor();
+                Ok(zone.clamp(0., 59.) + 1.)
             }
         })?;
 
@@ -313,6 +343,7 @@ mod tests {
     use crate::tests::utils::{test_proj_forward, test_proj_inverse};
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn proj_etmerc_etmerc() {
         let p = Proj::from_proj_string("+proj=etmerc +ellps=GRS80").unwrap();
 
@@ -333,6 +364,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn proj_etmerc_utm() {
         let p = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
 
@@ -349,4 +381,52 @@ mod tests {
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_etmerc_utm_factors() {
+        use crate::analysis::grid_factors;
+        use approx::assert_abs_diff_eq;
+
+        let p = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=31").unwrap();
+
+        // (lon, lat, k, gamma), reference values computed with mpmath
+        // from the derivatives of the series, as proj_factors does.
+        let inputs = [
+            (5., 45., 0.99990553634675682, 1.4145037032684442),
+            (1., -30., 1.0000591803932998, 1.0003093478169582),
+            (7.5, 70., 0.99996037906244183, 4.2296354661117886),
+        ];
+
+        for (lon, lat, k, gamma) in inputs {
+            let f = grid_factors(&p, lon, lat).unwrap();
+            assert_abs_diff_eq!(f.scale_factor, k, epsilon = 1.0e-12);
+            assert_abs_diff_eq!(f.meridian_convergence_deg, gamma, epsilon = 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn proj_etmerc_utm_computed_zone() {
+        use approx::assert_abs_diff_eq;
+
+        // (lon_0, expected central meridian)
+        let inputs = [(3., 3.), (-180., -177.), (180., 177.), (179.9, 177.)];
+        for (lon_0, lam0) in inputs {
+            let p =
+                Proj::from_proj_string(&format!("+proj=utm +ellps=GRS80 +lon_0={lon_0}")).unwrap();
+            assert_abs_diff_eq!(p.data().lam0().to_degrees(), lam0, epsilon = 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn proj_etmerc_utm_invalid_zone() {
+        use crate::errors::Error;
+
+        for zone in [0, 61, -1] {
+            assert!(matches!(
+                Proj::from_proj_string(&format!("+proj=utm +ellps=GRS80 +zone={zone}")),
+                Err(Error::InvalidUtmZone)
+            ));
+        }
+    }
 }
@@ -10,7 +10,7 @@ use crate::proj::{ProjData, ProjType};
 // Projection stub
 super::projection! { geocent, cart }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Projection {}
 
 impl Projection {
@@ -39,7 +39,7 @@ use crate::proj::ProjData;
 // Projection stub
 super::projection! { geos }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum Projection {
     El(Ell),
     Sp(Sph),
@@ -89,6 +89,14 @@ impl Projection {
         }
     }
 
+    /// Check that the point is visible from the satellite
+    pub fn domain_check(&self, lam: f64, phi: f64) -> bool {
+        match self {
+            Self::El(p) => p.is_visible(p.view_vector(lam, phi)),
+            Self::Sp(p) => p.is_visible(p.view_vector(lam, phi)),
+        }
+    }
+
     #[inline(always)]
     pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
         match self {
@@ -118,7 +126,7 @@ impl Projection {
 // Ellipsoid
 //
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Ell {
     radius_p: f64,
     radius_p2: f64,
@@ -130,20 +138,31 @@ pub(crate) struct Ell {
 }
 
 impl Ell {
-    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+    /// Return the three components of the vector from satellite to
+    /// position on earth surface (long,lat).
+    #[inline]
+    fn view_vector(&self, lam: f64, phi: f64) -> (f64, f64, f64) {
         // Calculation of geocentric latitude.
         // g_phi = (self.radius_p2 * phi.tan()).atan();
-
-        // Calculation of the three components of the vector from satellite to
-        // position on earth surface (long,lat).
         let (sin_phi, cos_phi) = (self.radius_p2 * phi.tan()).atan().sin_cos();
         let r = self.radius_p / (self.radius_p * cos_phi).hypot(sin_phi);
-        let vx = r * lam.cos() * cos_phi;
-        let vy = r * lam.sin() * cos_phi;
-        let vz = r * sin_phi;
+        (
+            r * lam.cos() * cos_phi,
+            r * lam.sin() * cos_phi,
+            r * sin_phi,
+        )
+    }
+
+    #[inline]
+    fn is_visible(&self, (vx, vy, vz): (f64, f64, f64)) -> bool {
+        ((self.radius_g - vx) * vx - vy * vy - vz * vz * self.radius_p_inv2) >= 0.
+    }
+
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (vx, vy, vz) = self.view_vector(lam, phi);
 
         // Check visibility.
-        if ((self.radius_g - vx) * vx - vy * vy - vz * vz * self.radius_p_inv2) < 0. {
+        if !self.is_visible((vx, vy, vz)) {
             return Err(Error::CoordTransOutsideProjectionDomain);
         }
 
@@ -196,11 +215,7 @@ impl Ell {
 
         // Calculation of longitude and latitude.
         let lam = vy.atan2(vx);
-        Ok((
-            lam,
-            (self.radius_p_inv2 * ((vz * lam.cos() / vx).atan())),
-            z,
-        ))
+        Ok((lam, (self.radius_p_inv2 * (vz * lam.cos() / vx)).atan(), z))
     }
 }
 
@@ -208,7 +223,7 @@ impl Ell {
 // Spherical
 //
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Sph {
     radius_g: f64,
     radius_g_1: f64,
@@ -217,13 +232,28 @@ pub(crate) struct Sph {
 }
 
 impl Sph {
+    /// Return the three components of the vector from satellite to
+    /// position on earth surface (long,lat).
+    #[inline]
+    fn view_vector(&self, lam: f64, phi: f64) -> (f64, f64, f64) {
+        let tmp = phi.cos();
+        (tmp * lam.cos(), tmp * lam.sin(), phi.sin())
+    }
+
+    #[inline]
+    fn is_visible(&self, (vx, vy, vz): (f64, f64, f64)) -> bool {
+        ((self.radius_g - vx) * vx - vy * vy - vz * vz) >= 0.
+    }
+
     pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
-        let mut tmp = phi.cos();
-        let vx = tmp * lam.cos();
-        let vy = tmp * lam.sin();
-        let vz = phi.sin();
+        let (vx, vy, vz) = self.view_vector(lam, phi);
+
+        // Check visibility.
+        if !self.is_visible((vx, vy, vz)) {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
 
-        tmp = self.radius_g - vx;
+        let tmp = self.radius_g - vx;
 
         if self.flip_axis {
             Ok((
@@ -277,7 +307,6 @@ impl Sph {
 
 #[cfg(test)]
 mod tests {
-    use crate::math::consts::{EPS_10, EPS_7};
     use crate::proj::Proj;
     use crate::tests::utils::{test_proj_forward, test_proj_inverse};
 
@@ -309,7 +338,7 @@ mod tests {
         ];
 
         test_proj_forward(&p, &inputs, 1e-8);
-        test_proj_inverse(&p, &inputs, 1.0e-2);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
     }
 
     #[test]
@@ -338,6 +367,61 @@ mod tests {
         ];
 
         test_proj_forward(&p, &inputs, 1.0e-8);
-        test_proj_inverse(&p, &inputs, 1.0e-2);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_geos_sweep_x() {
+        let p = Proj::from_proj_string("+proj=geos +ellps=GRS80 +h=35785831 +sweep=x").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222526.00851468294, 110553.44079259997, 0.)),
+            ((2., -1., 0.), (222526.00851468294, -110553.44079259997, 0.)),
+            ((-2., 1., 0.), (-222526.00851468294, 110553.44079259997, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222526.00851468294, -110553.44079259997, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+
+        // Default sweep axis is y
+        let p = Proj::from_proj_string("+proj=geos +ellps=GRS80 +h=35785831").unwrap();
+        let inputs = [((2., 1., 0.), (222527.07036580026, 110551.30341332949, 0.))];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_geos_invalid_sweep() {
+        assert!(Proj::from_proj_string("+proj=geos +ellps=GRS80 +h=35785831 +sweep=z").is_err());
+    }
+
+    #[test]
+    fn proj_geos_domain_check() {
+        for defn in [
+            "+proj=geos +ellps=GRS80 +h=35785831",
+            "+proj=geos +R=6400000 +h=35785831",
+        ] {
+            let p = Proj::from_proj_string(defn).unwrap();
+            let projection = p.projection();
+            for lon in (-180..180).step_by(5) {
+                for lat in (-85..=85).step_by(5) {
+                    let (lam, phi) = ((lon as f64).to_radians(), (lat as f64).to_radians());
+                    assert_eq!(
+                        projection.domain_check(lam, phi),
+                        projection.forward(lam, phi, 0.).is_ok(),
+                        "{defn}: {lon} {lat}"
+                    );
+                }
+            }
+            assert!(p.domain_check(0., 0.));
+            assert!(!p.domain_check(90f64.to_radians(), 0.));
+        }
     }
 }
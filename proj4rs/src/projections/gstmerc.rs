@@ -0,0 +1,118 @@
+//!
+//! Gauss-Schreiber Transverse Mercator
+//!
+//! ref: <https://proj.org/operations/projections/gstmerc.html>
+//!
+//! gstmerc: "Gauss-Schreiber Transverse Mercator (aka Gauss-Laborde Reunion)"
+//!     "\n\tCyl, Sph&Ell\n\tlat_0= lon_0= k_0=";
+//!
+//! The ellipsoid is mapped on a conformal sphere (Gauss sphere) tangent
+//! at `lat_0`, which is then projected with the spherical transverse mercator.
+//!
+use crate::errors::Result;
+use crate::math::{phi2, tsfn};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { gstmerc }
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {
+    e: f64,
+    c: f64,
+    n1: f64,
+    n2: f64,
+    ys: f64,
+}
+
+impl Projection {
+    pub fn gstmerc(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        let el = &p.ellps;
+        let sinphi0 = p.phi0.sin();
+
+        let n1 = (1. + el.es * p.phi0.cos().powi(4) / el.one_es).sqrt();
+        let phic = (sinphi0 / n1).asin();
+        let c = tsfn(-phic, 0., 0.).ln() - n1 * tsfn(-p.phi0, -sinphi0, el.e).ln();
+        let n2 = p.k0 * el.one_es.sqrt() / (1. - el.es * sinphi0 * sinphi0);
+
+        Ok(Self {
+            e: el.e,
+            c,
+            n1,
+            n2,
+            ys: -n2 * phic,
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let l = self.n1 * lam;
+        let ls = self.c + self.n1 * tsfn(-phi, -phi.sin(), self.e).ln();
+        let sinls1 = l.sin() / ls.cosh();
+        let ls1 = tsfn(-sinls1.asin(), 0., 0.).ln();
+        Ok((
+            self.n2 * ls1,
+            self.ys + self.n2 * (ls.sinh() / l.cos()).atan(),
+            z,
+        ))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (u, v) = (x / self.n2, (y - self.ys) / self.n2);
+        let l = (u.sinh() / v.cos()).atan();
+        let sinc = v.sin() / u.cosh();
+        let lc = tsfn(-sinc.asin(), 0., 0.).ln();
+        Ok((
+            l / self.n1,
+            -phi2(((lc - self.c) / self.n1).exp(), self.e)?,
+            z,
+        ))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_gstmerc_reunion() {
+        // IGNF:REUN47GAUSSL
+        let p = Proj::from_proj_string(concat!(
+            "+proj=gstmerc +lat_0=-21.116666667 +lon_0=55.533333333 +k=1",
+            " +x_0=160000 +y_0=50000 +ellps=intl +units=m",
+        ))
+        .unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            (
+                (55.5, -21.0, 0.),
+                (156534.17716068078, 62916.925105821368, 0.),
+            ),
+            (
+                (55.7, -21.3, 0.),
+                (177294.26947940826, 29691.909304688246, 0.),
+            ),
+            (
+                (55.2, -20.9, 0.),
+                (125318.59476064487, 73953.115248600188, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+}
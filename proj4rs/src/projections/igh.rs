@@ -0,0 +1,176 @@
+//!
+//! Interrupted Goode Homolosine
+//!
+//! ref: <https://proj.org/operations/projections/igh.html>
+//!
+//! igh: "Interrupted Goode Homolosine" "\n\tPCyl, Sph, no inv.";
+//!
+//! The projection is made of 12 lobes: sinusoidal between
+//! 40°44'11.8" north and south, mollweide beyond.
+//!
+//! Only the forward projection is implemented.
+//!
+use crate::errors::{Error, Result};
+use crate::math::consts::PI;
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+use crate::projections::moll;
+
+// Projection stub
+super::projection! { igh }
+
+const D_TO_R: f64 = PI / 180.;
+
+// Latitude where the sinusoidal and the mollweide
+// projections have the same scale along the meridian
+const D4044118: f64 = (40. + 44. / 60. + 11.8 / 3600.) * D_TO_R;
+
+const D20: f64 = 20. * D_TO_R;
+const D30: f64 = 30. * D_TO_R;
+const D40: f64 = 40. * D_TO_R;
+const D60: f64 = 60. * D_TO_R;
+const D80: f64 = 80. * D_TO_R;
+const D100: f64 = 100. * D_TO_R;
+const D140: f64 = 140. * D_TO_R;
+const D160: f64 = 160. * D_TO_R;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {
+    moll: moll::Projection,
+    dy0: f64,
+}
+
+impl Projection {
+    pub fn igh(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        // Map from sphere
+        let moll = moll::Projection::moll(p, params)?;
+
+        // Offset of the mollweide lobes such that both
+        // projections match at 40°44'11.8"
+        let (_, y, _) = moll.forward(0., D4044118, 0.)?;
+
+        Ok(Self {
+            moll,
+            dy0: D4044118 - y,
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // Central meridian of the lobe
+        let lam0 = if phi >= 0. {
+            if lam <= -D40 {
+                -D100
+            } else {
+                D30
+            }
+        } else if lam <= -D100 {
+            -D160
+        } else if lam <= -D20 {
+            -D60
+        } else if lam <= D80 {
+            D20
+        } else {
+            D140
+        };
+
+        let (x, y) = if phi >= D4044118 {
+            let (x, y, _) = self.moll.forward(lam - lam0, phi, z)?;
+            (x, y + self.dy0)
+        } else if phi >= -D4044118 {
+            ((lam - lam0) * phi.cos(), phi)
+        } else {
+            let (x, y, _) = self.moll.forward(lam - lam0, phi, z)?;
+            (x, y - self.dy0)
+        };
+
+        Ok((x + lam0, y, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, _: f64, _: f64, _: f64) -> Result<(f64, f64, f64)> {
+        Err(Error::NoInverseProjectionDefined)
+    }
+
+    pub const fn has_inverse() -> bool {
+        false
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::test_proj_forward;
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_igh() {
+        let p = Proj::from_proj_string("+proj=igh +a=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            // Sinusoidal lobes
+            ((2., 1., 0.), (223878.49745627144, 111701.07212763709, 0.)),
+            ((2., -1., 0.), (223708.37131305814, -111701.07212763709, 0.)),
+            ((-2., 1., 0.), (-222857.7405969916, 111701.07212763709, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223027.86674020491, -111701.07212763709, 0.),
+            ),
+            (
+                (-150., 20., 0.),
+                (-16418340.873374463, 2234021.4425527419, 0.),
+            ),
+            (
+                (120., -30., 0.),
+                (13703430.776019361, -3351032.1638291128, 0.),
+            ),
+            // Mollweide lobes
+            (
+                (-120., 60., 0.),
+                (-12471681.373148907, 6562388.5963589097, 0.),
+            ),
+            (
+                (100., 60., 0.),
+                (7906541.7251773047, 6562388.5963589097, 0.),
+            ),
+            (
+                (-170., -50., 0.),
+                (-18635412.027690752, -5555662.488475655, 0.),
+            ),
+            (
+                (150., -70., 0.),
+                (16148082.833044392, -7463176.3864614431, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        assert!(!p.has_inverse());
+    }
+
+    #[test]
+    fn proj_igh_continuity() {
+        let p = Proj::from_proj_string("+proj=igh +R=1").unwrap();
+        let fwd = |lam: f64, phi: f64| p.projection().forward(lam, phi, 0.).unwrap();
+
+        // Sinusoidal and mollweide lobes match at 40°44'11.8",
+        // up to the rounding of the latitude for the abscissa
+        let phi = super::D4044118;
+        for lam in [-2.5, -1., 0.5, 2.] {
+            let (n, s) = (fwd(lam, phi), fwd(lam, phi - 1.0e-12));
+            assert!((n.0 - s.0).abs() < 1.0e-6 && (n.1 - s.1).abs() < 1.0e-9);
+            let (n, s) = (fwd(lam, -phi + 1.0e-12), fwd(lam, -phi - 1.0e-12));
+            assert!((n.0 - s.0).abs() < 1.0e-6 && (n.1 - s.1).abs() < 1.0e-9);
+        }
+
+        // Central meridians of the lobes are straight lines
+        let (x, _, _) = fwd(30f64.to_radians(), 80f64.to_radians());
+        assert!((x - 30f64.to_radians()).abs() < EPS_10);
+    }
+}
@@ -0,0 +1,394 @@
+//!
+//! Krovak
+//!
+//! ref: <https://proj.org/operations/projections/krovak.html>
+//!
+//! krovak: "Krovak" "\n\tPCyl, Ell"
+//! krovak_etrs: "Krovak (ETRS89 variant)" "\n\tPCyl, Ell"
+//!
+//! `krovak` always uses the Bessel 1841 ellipsoid, as proj4js does: another
+//! ellipsoid in the definition is an error, and the datum uses Bessel
+//! when no ellipsoid is given.
+//! `krovak_etrs` uses the ellipsoid of the definition, for use with
+//! S-JTSK/05 (ETRS89 realization).
+//!
+//! Unless `+czech` is set, the axes are oriented toward East and North,
+//! i.e. the Krovak southing and westing are negated.
+//!
+//! As in proj, `+axis=swu` (EPSG:5513) is handled by the generic axis
+//! adjustment applied to the East-North output: it returns the positive
+//! southing and westing, in that order, while `+czech` returns
+//! the westing first.
+//!
+//! The azimuth of the cone axis is fixed: `+alpha`, as set by the EPSG
+//! definitions, is accepted if it is the built-in 30°17'17.30311".
+//!
+//! With `+wgs84` and no explicit datum shift, the S-JTSK to WGS84
+//! Helmert parameters (EPSG:1623) are used.
+//!
+use crate::datum_params::DatumParams;
+use crate::ellipsoids::constants::BESSEL;
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::consts::{FRAC_PI_2, FRAC_PI_4};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { krovak, krovak_etrs }
+
+/// S-JTSK to WGS84 Helmert parameters selected by `+wgs84`
+const TOWGS84: &str = "589,76,480";
+
+// Parameters defining the ellipsoid
+const ELLPS_PARAMS: [&str; 4] = ["ellps", "datum", "R", "a"];
+
+const EPS: f64 = 1.0e-15;
+const MAX_ITER: usize = 100;
+
+// Co-latitude of the cone axis: 59°42'42.69689"
+const UQ: f64 = 1.042_168_563_804_74;
+// Latitude of the pseudo standard parallel: 78°30'N
+const S0: f64 = 1.370_083_462_815_55;
+
+// Default projection center: 49°30'N
+const PHI0: f64 = 0.863_937_979_737_193;
+// Default central meridian: 42°30' east of Ferro (17°40' west of Greenwich)
+const LAM0: f64 = 0.741_764_932_097_590_1 - 0.308_341_501_185_665;
+const K0: f64 = 0.9999;
+
+// Tolerance on `+alpha`, about 1e-6 degree
+const ALPHA_TOL: f64 = 2.0e-8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {
+    e: f64,
+    alpha: f64,
+    k: f64,
+    n: f64,
+    rho0: f64,
+    ad: f64,
+    czech: f64,
+    bessel: bool,
+    wgs84: bool,
+}
+
+impl Projection {
+    fn init(p: &mut ProjData, params: &ParamList, bessel: bool, wgs84: bool) -> Result<Self> {
+        if params.get("lat_0").is_none() {
+            p.phi0 = PHI0;
+        }
+        if params.get("lon_0").is_none() {
+            p.lam0 = LAM0;
+        }
+        if ["k", "k_0", "k0"].iter().all(|k| params.get(k).is_none()) {
+            p.k0 = K0;
+        }
+
+        // Fixed azimuth of the cone axis
+        if let Some(alpha) = params.try_angular_value("alpha")? {
+            if (alpha - (FRAC_PI_2 - UQ)).abs() > ALPHA_TOL {
+                return Err(Error::InvalidParameterValue("Unsupported value for alpha"));
+            }
+        }
+
+        let czech = if params.check_option("czech")? {
+            1.
+        } else {
+            -1.
+        };
+
+        let (e, es) = (p.ellps.e, p.ellps.es);
+        let (sinphi0, cosphi0) = p.phi0.sin_cos();
+
+        let alpha = (1. + (es * cosphi0.powi(4)) / (1. - es)).sqrt();
+        let u0 = (sinphi0 / alpha).asin();
+        let g = ((1. + e * sinphi0) / (1. - e * sinphi0)).powf(alpha * e / 2.);
+
+        let tan_half_phi0 = (p.phi0 / 2. + FRAC_PI_4).tan();
+        if tan_half_phi0 == 0. {
+            return Err(Error::InvalidParameterValue("Invalid value for lat_0"));
+        }
+
+        let k = (u0 / 2. + FRAC_PI_4).tan() / tan_half_phi0.powf(alpha) * g;
+        let n0 = (1. - es).sqrt() / (1. - es * sinphi0 * sinphi0);
+
+        Ok(Self {
+            e,
+            alpha,
+            k,
+            n: S0.sin(),
+            rho0: p.k0 * n0 / S0.tan(),
+            ad: FRAC_PI_2 - UQ,
+            czech,
+            bessel,
+            wgs84,
+        })
+    }
+
+    pub fn krovak(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        // Bessel is the fixed ellipsoid
+        if params.iter().any(|p| ELLPS_PARAMS.contains(&p.name))
+            && !BESSEL.matches(p.ellps.a, p.ellps.es)
+        {
+            return Err(Error::InvalidParameterValue(
+                "Krovak requires the Bessel ellipsoid",
+            ));
+        }
+        p.ellps = Ellipsoid::try_from_ellipsoid(&BESSEL)?;
+        let wgs84 = params.check_option("wgs84")?;
+        Self::init(p, params, true, wgs84)
+    }
+
+    pub fn krovak_etrs(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        Self::init(p, params, false, false)
+    }
+
+    /// The datum uses the projection ellipsoid
+    pub fn fixed_ellipsoid(&self) -> bool {
+        self.bessel
+    }
+
+    /// S-JTSK to WGS84 shift if `+wgs84` is set
+    pub fn datum_params(&self) -> Result<Option<DatumParams>> {
+        self.wgs84
+            .then(|| DatumParams::from_towgs84_str(TOWGS84))
+            .transpose()
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let esinphi = self.e * phi.sin();
+        let gfi = ((1. + esinphi) / (1. - esinphi)).powf(self.alpha * self.e / 2.);
+
+        let u = 2.
+            * ((self.k * (phi / 2. + FRAC_PI_4).tan().powf(self.alpha) / gfi).atan() - FRAC_PI_4);
+        let deltav = -lam * self.alpha;
+
+        let (sinu, cosu) = u.sin_cos();
+        let (sinad, cosad) = self.ad.sin_cos();
+
+        let s = (cosad * sinu + sinad * cosu * deltav.cos()).asin();
+        let cos_s = s.cos();
+        if cos_s < 1.0e-12 {
+            return Ok((0., 0., z));
+        }
+        let d = (cosu * deltav.sin() / cos_s).asin();
+
+        let eps = self.n * d;
+        let rho = self.rho0 * (S0 / 2. + FRAC_PI_4).tan().powf(self.n)
+            / (s / 2. + FRAC_PI_4).tan().powf(self.n);
+
+        Ok((
+            self.czech * rho * eps.sin(),
+            self.czech * rho * eps.cos(),
+            z,
+        ))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // Swap axes: x is the southing, y the westing
+        let (x, y) = (self.czech * y, self.czech * x);
+
+        let rho = x.hypot(y);
+        let eps = y.atan2(x);
+
+        let d = eps / S0.sin();
+        let s = if rho == 0. {
+            FRAC_PI_2
+        } else {
+            2. * (((self.rho0 / rho).powf(1. / self.n) * (S0 / 2. + FRAC_PI_4).tan()).atan()
+                - FRAC_PI_4)
+        };
+
+        let (sins, coss) = s.sin_cos();
+        let (sinad, cosad) = self.ad.sin_cos();
+
+        let u = (cosad * sins - sinad * coss * d.cos()).asin();
+        let deltav = (coss * d.sin() / u.cos()).asin();
+
+        let lam = -deltav / self.alpha;
+
+        // Iterate for phi
+        let a = self.k.powf(-1. / self.alpha) * (u / 2. + FRAC_PI_4).tan().powf(1. / self.alpha);
+        let mut fi1 = u;
+        for _ in 0..MAX_ITER {
+            let esinphi = self.e * fi1.sin();
+            let phi =
+                2. * ((a * ((1. + esinphi) / (1. - esinphi)).powf(self.e / 2.)).atan() - FRAC_PI_4);
+            if (fi1 - phi).abs() < EPS {
+                return Ok((lam, phi, z));
+            }
+            fi1 = phi;
+        }
+        Err(Error::CoordTransOutsideProjectionDomain)
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+    use crate::transform::transform;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_krovak() {
+        // EPSG:5514
+        let p = Proj::from_proj_string("+proj=krovak +ellps=bessel").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // Reference values computed with mpmath from EPSG guidance note 7-2
+        let inputs = [
+            (
+                (16.849771944444444, 50.209011666666667, 0.),
+                (-568990.99543751043, -1050538.6308469604, 0.),
+            ),
+            (
+                (14., 50., 0.),
+                (-774126.55317200545, -1048524.8298622522, 0.),
+            ),
+            (
+                (18.5, 48.8, 0.),
+                (-464652.86620485618, -1217692.0921083785, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-6);
+
+        // Bessel is always used
+        let p = Proj::from_proj_string("+proj=krovak").unwrap();
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        for defn in [
+            "+proj=krovak +ellps=GRS80",
+            "+proj=krovak +datum=WGS84",
+            "+proj=krovak +R=6370997",
+        ] {
+            assert!(matches!(
+                Proj::from_proj_string(defn),
+                Err(Error::InvalidParameterValue(_))
+            ));
+        }
+
+        // Southing and westing
+        let p = Proj::from_proj_string("+proj=krovak +czech").unwrap();
+        let inputs = [((14., 50., 0.), (774126.55317200545, 1048524.8298622522, 0.))];
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-6);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_krovak_axis_swu() {
+        // EPSG:5513, S-JTSK / Krovak
+        let p = Proj::from_proj_string(concat!(
+            "+proj=krovak +axis=swu +lat_0=49.5 +lon_0=24.8333333333333",
+            " +alpha=30.2881397527778 +k=0.9999 +x_0=0 +y_0=0 +ellps=bessel +units=m",
+        ))
+        .unwrap();
+        let czech = Proj::from_proj_string("+proj=krovak +czech").unwrap();
+        let bessel = Proj::from_proj_string("+proj=longlat +ellps=bessel").unwrap();
+
+        let mut pt = (14f64.to_radians(), 50f64.to_radians(), 0.);
+        let mut pt_czech = pt;
+        transform(&bessel, &p, &mut pt).unwrap();
+        transform(&bessel, &czech, &mut pt_czech).unwrap();
+
+        // Same values as +czech, southing first
+        assert_abs_diff_eq!(pt.0, 1048524.8298622522, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.1, 774126.55317200545, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.0, pt_czech.1, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.1, pt_czech.0, epsilon = 1.0e-6);
+
+        transform(&p, &bessel, &mut pt).unwrap();
+        assert_abs_diff_eq!(pt.0.to_degrees(), 14., epsilon = 1.0e-10);
+        assert_abs_diff_eq!(pt.1.to_degrees(), 50., epsilon = 1.0e-10);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_krovak_etrs() {
+        // S-JTSK/05 on the ETRS89 ellipsoid (EPSG:5228)
+        let p = Proj::from_proj_string("+proj=krovak_etrs +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            (
+                (16.849771944444444, 50.209011666666667, 0.),
+                (-569060.33784049467, -1050675.7070771759, 0.),
+            ),
+            (
+                (14., 50., 0.),
+                (-774220.87860336281, -1048661.4209072254, 0.),
+            ),
+            (
+                (18.5, 48.8, 0.),
+                (-464709.40486225078, -1217848.21598939, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-6);
+    }
+
+    #[test]
+    fn proj_krovak_alpha() {
+        let p = Proj::from_proj_string("+proj=krovak +alpha=30.28813972222222").unwrap();
+        let q = Proj::from_proj_string("+proj=krovak").unwrap();
+        assert_eq!(p.projection(), q.projection());
+
+        assert!(matches!(
+            Proj::from_proj_string("+proj=krovak +alpha=30"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "crs-definitions")]
+    fn proj_krovak_strict() {
+        for code in [2065, 5513, 5514] {
+            let defn = format!("+init=epsg:{code}");
+            assert!(Proj::from_proj_string_strict(&defn).is_ok(), "EPSG:{code}");
+        }
+    }
+
+    #[test]
+    fn proj_krovak_wgs84() {
+        let wgs84 = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+        let p1 = Proj::from_proj_string("+proj=krovak +ellps=bessel +wgs84").unwrap();
+        let p2 = Proj::from_proj_string("+proj=krovak +ellps=bessel +towgs84=589,76,480").unwrap();
+        let p3 = Proj::from_proj_string("+proj=krovak +ellps=bessel").unwrap();
+        // The datum uses Bessel when no ellipsoid is given
+        let p4 = Proj::from_proj_string("+proj=krovak +wgs84").unwrap();
+
+        let mut pt1 = (15f64.to_radians(), 50f64.to_radians(), 0.);
+        let mut pt2 = pt1;
+        let mut pt3 = pt1;
+        let mut pt4 = pt1;
+        transform(&wgs84, &p1, &mut pt1).unwrap();
+        transform(&wgs84, &p2, &mut pt2).unwrap();
+        transform(&wgs84, &p3, &mut pt3).unwrap();
+        transform(&wgs84, &p4, &mut pt4).unwrap();
+
+        assert_abs_diff_eq!(pt1.0, pt2.0, epsilon = 1.0e-9);
+        assert_abs_diff_eq!(pt1.1, pt2.1, epsilon = 1.0e-9);
+        assert_abs_diff_eq!(pt1.0, pt4.0, epsilon = 1.0e-9);
+        assert_abs_diff_eq!(pt1.1, pt4.1, epsilon = 1.0e-9);
+        // The shift is about 100m
+        assert!((pt1.0 - pt3.0).hypot(pt1.1 - pt3.1) > 50.);
+    }
+}
@@ -17,7 +17,7 @@ use crate::proj::ProjData;
 // Projection stub
 super::projection! { laea }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum Projection {
     El(EProj),
     Sp(SProj),
@@ -26,7 +26,7 @@ pub(crate) enum Projection {
 impl Projection {
     pub fn laea(p: &mut ProjData, _: &ParamList) -> Result<Self> {
         Ok(if p.ellps.is_ellipsoid() {
-            Self::El(EProj::new(p))
+            Self::El(EProj::new(p)?)
         } else {
             Self::Sp(SProj::new(p))
         })
@@ -63,7 +63,7 @@ impl Projection {
 
 #[allow(non_camel_case_types)]
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 enum EMode {
     N_POLE,
     S_POLE,
@@ -83,7 +83,7 @@ enum EMode {
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct EProj {
     phi0: f64,
     e: f64,
@@ -94,9 +94,12 @@ pub(crate) struct EProj {
 }
 
 impl EProj {
-    fn new(p: &mut ProjData) -> Self {
+    fn new(p: &mut ProjData) -> Result<Self> {
         let (e, one_es) = (p.ellps.e, p.ellps.one_es);
         let qp = qsfn(1., e, one_es);
+        if qp.is_infinite() {
+            return Err(Error::ToleranceConditionError);
+        }
         let apa = authset(p.ellps.es);
         let phi0 = p.phi0;
 
@@ -133,14 +136,14 @@ impl EProj {
             }
         };
 
-        Self {
+        Ok(Self {
             phi0,
             e,
             one_es,
             qp,
             apa,
             mode,
-        }
+        })
     }
 
     fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
@@ -263,7 +266,7 @@ impl EProj {
 
 #[allow(non_camel_case_types)]
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 enum SMode {
     N_POLE,
     S_POLE,
@@ -271,7 +274,7 @@ enum SMode {
     OBLIQ { sinb1: f64, cosb1: f64 },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct SProj {
     phi0: f64,
     mode: SMode,
@@ -402,6 +405,7 @@ mod tests {
     use crate::tests::utils::{test_proj_forward, test_proj_inverse};
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn proj_laea_el() {
         let p = Proj::from_proj_string("+proj=laea +ellps=GRS80").unwrap();
 
@@ -428,6 +432,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn proj_laea_sp() {
         let p = Proj::from_proj_string("+proj=laea +a=6400000").unwrap();
 
@@ -472,4 +477,21 @@ mod tests {
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, 1.0e-7);
     }
+
+    #[test]
+    fn proj_laea_degenerated_ellipsoid() {
+        // Eccentricity close to 1: no NaN output
+        for lat_0 in [0, 45, 90] {
+            let p = Proj::from_proj_string(&format!(
+                "+proj=laea +lat_0={lat_0} +a=6378137 +es=0.9999999999999999"
+            ))
+            .unwrap();
+            for (lam, phi) in [(0.1, 0.5), (0., 1.5), (-0.2, -0.7)] {
+                let (x, y, _) = p.projection().forward(lam, phi, 0.).unwrap();
+                assert!(x.is_finite() && y.is_finite(), "lat_0={lat_0}");
+                let (lam, phi, _) = p.projection().inverse(x, y, 0.).unwrap();
+                assert!(lam.is_finite() && phi.is_finite(), "lat_0={lat_0}");
+            }
+        }
+    }
 }
@@ -11,7 +11,7 @@ use crate::proj::{ProjData, ProjType};
 // Projection stub
 super::projection! { latlong, longlat }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Projection {}
 
 impl Projection {
@@ -12,6 +12,9 @@
 //! x_0: x offset in meters
 //! y_0: y offset in meters
 //!
+//! When `lat_2` is not given, the projection is the tangent (1SP) form
+//! with `n = sin(lat_1)`; `lat_0` defaults to `lat_1`.
+//!
 
 use crate::errors::{Error, Result};
 use crate::math::{
@@ -24,7 +27,7 @@ use crate::proj::ProjData;
 // Projection stub
 super::projection! { lcc }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Projection {
     n: f64,
     rho0: f64,
@@ -95,6 +98,12 @@ impl Projection {
             }
         }
 
+        // Parallels almost symmetric about the equator: the cone
+        // degenerates into a cylinder
+        if n.abs() < EPS_10 || !(c.is_finite() && rho0.is_finite()) {
+            return Err(Error::ProjErrConicLatEqual);
+        }
+
         Ok(Self {
             n,
             rho0,
@@ -172,11 +181,13 @@ impl Projection {
 #[cfg(test)]
 mod tests {
     use crate::adaptors::transform_xy;
+    use crate::errors::Error;
     use crate::math::consts::EPS_10;
     use crate::proj::Proj;
     use crate::tests::utils::{test_proj_forward, test_proj_inverse};
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn proj_lcc() {
         let p = Proj::from_proj_string("+proj=lcc   +ellps=GRS80  +lat_1=0.5 +lat_2=2").unwrap();
 
@@ -203,6 +214,48 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_lcc_bipolar() {
+        // Standard parallels on both sides of the equator
+        let p = Proj::from_proj_string("+proj=lcc +lat_1=-10 +lat_2=30 +lat_0=10 +ellps=GRS80")
+            .unwrap();
+
+        // Reference values computed with mpmath
+        let inputs = [
+            ((2., 1., 0.), (211947.73476727489, -939037.75760231739, 0.)),
+            ((2., -1., 0.), (213255.7980617049, -1150223.7859532666, 0.)),
+            (
+                (-2., 1., 0.),
+                (-211947.73476727489, -939037.75760231739, 0.),
+            ),
+            (
+                (-2., -1., 0.),
+                (-213255.7980617049, -1150223.7859532666, 0.),
+            ),
+            ((20., 40., 0.), (1857082.3033341548, 3335282.165755595, 0.)),
+            (
+                (-30., -20., 0.),
+                (-3390941.5341587545, -3113293.473404035, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+
+        // Symmetric parallels
+        assert!(matches!(
+            Proj::from_proj_string("+proj=lcc +lat_1=30 +lat_2=-30 +ellps=GRS80"),
+            Err(Error::ProjErrConicLatEqual)
+        ));
+        // Nearly symmetric parallels: |n| < 1e-10
+        assert!(matches!(
+            Proj::from_proj_string("+proj=lcc +lat_1=30 +lat_2=-29.9999999914 +ellps=GRS80"),
+            Err(Error::ProjErrConicLatEqual)
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
     fn proj_lcc_latlon_to_lcc() {
         let p_from = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
         let p_to = Proj::from_proj_string("+proj=lcc +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
@@ -212,4 +265,48 @@ mod tests {
         let out = transform_xy(&p_from, &p_to, lon_in, lat_in).unwrap();
         assert_eq!(out, (222588.439735968423, 110660.533870799671));
     }
+
+    #[test]
+    fn proj_lcc_1sp() {
+        // EPSG:27563 - NTF (Paris) / Lambert Sud France
+        let p = Proj::from_proj_string(concat!(
+            "+proj=lcc +lat_1=44.10000000000001 +lat_0=44.10000000000001 +lon_0=0",
+            " +k_0=0.999877499 +x_0=600000 +y_0=200000 +a=6378249.2 +b=6356515",
+            " +towgs84=-168,-60,320,0,0,0,0 +pm=paris +units=m",
+        ))
+        .unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // Longitudes are relative to the Paris meridian
+        let inputs = [
+            ((2., 43.5, 0.), (761733.4811413186, 135307.36572429483, 0.)),
+            ((-1., 45., 0.), (521151.47400632746, 300480.25656969972, 0.)),
+            (
+                (5.5, 42., 0.),
+                (1055605.0713343531, -18092.638176123725, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-3);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_lcc_1sp_epsg_example() {
+        // EPSG Guidance note 7-2: JAD69 / Jamaica National Grid
+        let p = Proj::from_proj_string(concat!(
+            "+proj=lcc +lat_1=18 +lon_0=-77 +k=1 +x_0=250000 +y_0=150000",
+            " +a=6378206.4 +rf=294.9786982",
+        ))
+        .unwrap();
+
+        let (lon, lat) = (
+            -(76. + 56. / 60. + 37.26 / 3600.),
+            17. + 55. / 60. + 55.80 / 3600.,
+        );
+
+        let inputs = [((lon, lat, 0.), (255966.58, 142493.51, 0.))];
+        test_proj_forward(&p, &inputs, 1.0e-2);
+    }
 }
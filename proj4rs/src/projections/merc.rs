@@ -4,6 +4,17 @@
 //! merc: "Mercator" "\n\tCyl, Sph&Ell\n\tlat_ts="
 //! webmerc: "Web Mercator / Pseudo Mercator" "\n\tCyl, Ell\n\t"
 //!
+//! For `merc`, the scale may be given with `lat_ts`, the latitude of
+//! true scale, instead of `k`: `k = cos(lat_ts) / sqrt(1 - es * sin²(lat_ts))`.
+//! When both are given, `lat_ts` takes precedence.
+//!
+//! As in proj, `webmerc` applies the spherical formulas with the
+//! semi-major axis of the ellipsoid, i.e. as `+a=<a> +b=<a>`,
+//! and ignores `k` and `lat_ts`.
+//!
+//! As in proj, `lat_0` is ignored: the origin of northings is
+//! always the equator (use `y_0` for a false northing).
+//!
 
 // Projection stub
 super::projection! { merc, webmerc }
@@ -12,12 +23,12 @@ use crate::errors::{Error, Result};
 use crate::math::{
     asinh,
     consts::{EPS_10, FRAC_PI_2},
-    msfn, phi2,
+    inverse_isometric_latitude, msfn,
 };
 use crate::parameters::ParamList;
 use crate::proj::ProjData;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Projection {
     is_ellps: bool,
     k0: f64,
@@ -28,7 +39,7 @@ impl Projection {
     pub fn merc(p: &mut ProjData, params: &ParamList) -> Result<Self> {
         let phits: Option<f64> = params.try_angular_value("lat_ts")?;
         if let Some(phits) = phits {
-            if phits >= FRAC_PI_2 {
+            if phits.abs() >= FRAC_PI_2 {
                 return Err(Error::InvalidParameterValue(
                     "lat_ts larger than 90 degrees",
                 ));
@@ -77,7 +88,11 @@ impl Projection {
 
     pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
         if self.is_ellps {
-            Ok((x / self.k0, phi2((-y / self.k0).exp(), self.e)?, z))
+            Ok((
+                x / self.k0,
+                inverse_isometric_latitude(y / self.k0, self.e),
+                z,
+            ))
         } else {
             Ok((x / self.k0, (y / self.k0).sinh().atan(), z))
         }
@@ -94,7 +109,7 @@ impl Projection {
 
 #[cfg(test)]
 mod tests {
-    use crate::math::consts::EPS_10;
+    use crate::math::consts::{EPS_10, FRAC_PI_2};
     use crate::proj::Proj;
     use crate::tests::utils::{test_proj_forward, test_proj_inverse};
 
@@ -122,6 +137,47 @@ mod tests {
         test_proj_inverse(&p, &inputs, EPS_10);
     }
 
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_merc_inverse_poles() {
+        // World Mercator, reference values computed with mpmath
+        let p = Proj::from_proj_string("+proj=merc +ellps=WGS84").unwrap();
+
+        let inputs = [
+            ((0., 84.216995701992806, 0.), (0., 19000000., 0.)),
+            ((0., 88.968441239598765, 0.), (0., 30000000., 0.)),
+            ((0., -89.784923205570849, 0.), (0., -40000000., 0.)),
+        ];
+        test_proj_inverse(&p, &inputs, EPS_10);
+
+        // Very large northings map to the poles
+        for (y, phi) in [(1.0e10, FRAC_PI_2), (-1.0e300, -FRAC_PI_2)] {
+            let (_, out, _) = p.projection().inverse(0., y, 0.).unwrap();
+            assert_eq!(out, phi);
+        }
+
+        // High eccentricity
+        let p = Proj::from_proj_string("+proj=merc +a=1 +es=0.81").unwrap();
+        for (y, phi) in [(0.001, 0.0052630942329263466), (2., 1.498077075013708686)] {
+            let (_, out, _) = p.projection().inverse(0., y, 0.).unwrap();
+            assert!((out - phi).abs() < EPS_10);
+        }
+    }
+
+    #[test]
+    fn proj_merc_lat_0() {
+        // lat_0 does not shift the northing (as proj)
+        let p = Proj::from_proj_string("+proj=merc +lat_0=30 +ellps=GRS80").unwrap();
+
+        let inputs = [
+            ((2., 1., 0.), (222638.98158654713, 110579.96521825077, 0.)),
+            ((2., 30., 0.), (222638.98158654713, 3482189.0853040293, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-6);
+    }
+
     #[test]
     fn proj_merc_merc_sph() {
         let p = Proj::from_proj_string("+proj=merc +R=6400000").unwrap();
@@ -181,4 +237,53 @@ mod tests {
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_merc_lat_ts() {
+        let p = Proj::from_proj_string("+proj=merc +lat_ts=-45 +ellps=WGS84").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        assert!((p.data().k0() - 0.7082931706937200).abs() < 1.0e-15);
+
+        let inputs = [
+            ((2., 1., 0.), (157693.67018795622, 78323.03418221815, 0.)),
+            ((-2., 60., 0.), (-157693.67018795622, 5923242.270473366, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+
+        // Equivalent scale factor
+        let p = Proj::from_proj_string("+proj=merc +k=0.70829317069372 +ellps=WGS84").unwrap();
+        test_proj_forward(&p, &inputs, 1.0e-6);
+    }
+
+    #[test]
+    fn proj_merc_invalid_lat_ts() {
+        assert!(Proj::from_proj_string("+proj=merc +lat_ts=90 +ellps=WGS84").is_err());
+        assert!(Proj::from_proj_string("+proj=merc +lat_ts=-90 +ellps=WGS84").is_err());
+    }
+
+    #[test]
+    fn proj_merc_webmerc_sphere() {
+        // EPSG:3857 use the spherical formulas with the WGS84 semi-major axis
+        let p = Proj::from_proj_string("+proj=webmerc +ellps=WGS84 +k=0.5 +lat_ts=45").unwrap();
+        let s = Proj::from_proj_string("+proj=merc +a=6378137 +b=6378137").unwrap();
+
+        assert_eq!(p.data().k0(), 1.);
+        assert_eq!(p.data().a(), s.data().a());
+
+        let (lam, phi) = (2f64.to_radians(), 1f64.to_radians());
+        let (x, y, _) = p.projection().forward(lam, phi, 0.).unwrap();
+        let (xs, ys, _) = s.projection().forward(lam, phi, 0.).unwrap();
+        assert_eq!((x, y), (xs, ys));
+
+        // The datum ellipsoid is preserved
+        assert_eq!(p.ellipsoid_name(), Some("WGS84"));
+
+        let inputs = [((2., 1., 0.), (222638.98158654713, 111325.14286638626, 0.))];
+        test_proj_forward(&p, &inputs, EPS_10);
+    }
 }
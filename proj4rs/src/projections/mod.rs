@@ -2,7 +2,7 @@
 //! Implemented projections
 //!
 
-// We definitely do not want to use trait object, se we use
+// Builtin projections do not use trait objects: we use an
 // enum for type abstraction.
 //
 // Instead of writing massive boilerplate for delegation to inner types
@@ -10,13 +10,18 @@
 // pointer to associated function. This spare us writing huge  `match`
 // for each fonction call.
 //
+// Third-party projections registered at runtime are the exception:
+// they are `ProjectionAlgorithm` trait objects held by the `Plugin`
+// variant (see `register_projection`).
+//
 // Most important projections:
 //
 // lcc, merc, tmerc, utm (etmerc) et aea
 // stere et sterea pour for polar regions.
 //
 
-use crate::errors::Result;
+use crate::datum_params::DatumParams;
+use crate::errors::{Error, Result};
 use crate::parameters::ParamList;
 use crate::proj::ProjData;
 
@@ -29,13 +34,31 @@ pub(crate) type ProjFn = fn(&ProjParams, f64, f64, f64) -> Result<(f64, f64, f64
 #[derive(Clone)]
 pub(crate) struct ProjDelegate(ProjParams, ProjFn, ProjFn, bool, bool);
 
+// Function pointers are defined by the projection
+// parameters variant
+impl PartialEq for ProjDelegate {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
 impl ProjDelegate {
+    /// Return [`Error::NoInverseProjectionDefined`] if the
+    /// projection has no inverse
     #[inline(always)]
     pub fn inverse(&self, u: f64, v: f64, w: f64) -> Result<(f64, f64, f64)> {
+        if !self.3 {
+            return Err(Error::NoInverseProjectionDefined);
+        }
         self.1(&self.0, u, v, w)
     }
+    /// Return [`Error::NoForwardProjectionDefined`] if the
+    /// projection has no forward
     #[inline(always)]
     pub fn forward(&self, u: f64, v: f64, w: f64) -> Result<(f64, f64, f64)> {
+        if !self.4 {
+            return Err(Error::NoForwardProjectionDefined);
+        }
         self.2(&self.0, u, v, w)
     }
 
@@ -46,6 +69,31 @@ impl ProjDelegate {
     pub fn has_forward(&self) -> bool {
         self.4
     }
+
+    /// Cheap check that `lam`, `phi` is in the projection domain
+    ///
+    /// Returning `true` does not guarantee that the forward
+    /// projection succeeds.
+    pub fn domain_check(&self, lam: f64, phi: f64) -> bool {
+        self.0.domain_check(lam, phi)
+    }
+
+    /// Analytic point scale factor and meridian convergence
+    /// (radians), if the projection provides them
+    pub fn factors(&self, lam: f64, phi: f64) -> Option<(f64, f64)> {
+        self.0.factors(lam, phi)
+    }
+
+    /// The datum uses the ellipsoid set by the projection
+    pub fn fixed_ellipsoid(&self) -> bool {
+        self.0.fixed_ellipsoid()
+    }
+
+    /// Datum shift of the projection, used when the definition
+    /// has no `+nadgrids` or `+towgs84`
+    pub fn datum_params(&self) -> Result<Option<DatumParams>> {
+        self.0.datum_params()
+    }
 }
 
 impl fmt::Debug for ProjDelegate {
@@ -56,8 +104,15 @@ impl fmt::Debug for ProjDelegate {
 
 pub(crate) type InitFn = fn(&mut ProjData, &ParamList) -> Result<ProjDelegate>;
 
+#[derive(Clone, Copy)]
+enum Initializer {
+    Builtin(InitFn),
+    Plugin(PluginInitFn),
+}
+
 /// Returned by projection lookup
-pub(crate) struct ProjInit(&'static str, InitFn);
+#[derive(Clone, Copy)]
+pub(crate) struct ProjInit(&'static str, Initializer);
 
 impl ProjInit {
     #[inline(always)]
@@ -68,7 +123,10 @@ impl ProjInit {
     /// Return a tuple (params, inverse, forward)
     #[inline(always)]
     pub fn init(&self, proj: &mut ProjData, params: &ParamList) -> Result<ProjDelegate> {
-        self.1(proj, params)
+        match self.1 {
+            Initializer::Builtin(init) => init(proj, params),
+            Initializer::Plugin(init) => plugin::init(init, proj, params),
+        }
     }
 }
 
@@ -134,47 +192,100 @@ macro_rules! projection {
 use downcast;
 use projection;
 
-const NUM_PROJECTIONS: usize = 22;
+const NUM_PROJECTIONS: usize = 39;
 
 macro_rules! declare_projections {
     ($(($name:ident $(,)? $($init:ident),*)),+ $(,)?) => {
         const PROJECTIONS: [ProjInit; NUM_PROJECTIONS] = [
         $(
-            ProjInit(stringify!($name), $name::stub::$name),
+            ProjInit(stringify!($name), Initializer::Builtin($name::stub::$name)),
             $(
-                ProjInit(stringify!($init), $name::stub::$init),
+                ProjInit(stringify!($init), Initializer::Builtin($name::stub::$init)),
             )*
         )+
         ];
         #[allow(non_camel_case_types)]
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, PartialEq)]
         pub(crate) enum ProjParams {
             $(
                 $name($name::Projection),
             )+
+            Plugin(plugin::Projection),
         }
     };
 }
 
+// Projections with a restricted domain override
+// the default domain check; conformal projections
+// may provide analytic factors.
+impl ProjParams {
+    fn domain_check(&self, lam: f64, phi: f64) -> bool {
+        match self {
+            Self::geos(p) => p.domain_check(lam, phi),
+            Self::nsper(p) => p.domain_check(lam, phi),
+            Self::ortho(p) => p.domain_check(lam, phi),
+            Self::stere(p) => p.domain_check(lam, phi),
+            Self::Plugin(p) => p.domain_check(lam, phi),
+            _ => true,
+        }
+    }
+
+    fn factors(&self, lam: f64, phi: f64) -> Option<(f64, f64)> {
+        match self {
+            Self::etmerc(p) => Some(p.factors(lam, phi)),
+            _ => None,
+        }
+    }
+
+    // Projections with a built-in datum: the datum uses the
+    // projection ellipsoid and may have a default shift.
+    fn fixed_ellipsoid(&self) -> bool {
+        match self {
+            Self::krovak(p) => p.fixed_ellipsoid(),
+            _ => false,
+        }
+    }
+
+    fn datum_params(&self) -> Result<Option<DatumParams>> {
+        match self {
+            Self::krovak(p) => p.datum_params(),
+            _ => Ok(None),
+        }
+    }
+}
+
 // ----------------------------
 // Projection list
 // ---------------------------
 
 pub mod aea;
+pub mod cea;
 pub mod eqc;
 pub mod estmerc;
 pub mod etmerc;
 pub mod geocent;
 pub mod geos;
+pub mod gstmerc;
+pub mod igh;
+pub mod krovak;
+pub mod labrd;
 pub mod laea;
 pub mod latlong;
 pub mod lcc;
 pub mod merc;
 pub mod moll;
+pub mod noop;
+pub mod nsper;
+pub mod ocea;
+pub mod ortho;
 pub mod somerc;
 pub mod stere;
 pub mod sterea;
 pub mod tmerc;
+pub mod topocentric;
+pub mod urm5;
+pub mod urmfps;
+pub mod wag7;
 
 #[rustfmt::skip]
 declare_projections! [
@@ -191,14 +302,373 @@ declare_projections! [
     (laea),
     (moll, wag4, wag5),
     (geos),
-    (eqc),
+    (eqc, plate_carree),
+    (urmfps, wag1),
+    (wag7),
+    (cea),
+    (gstmerc),
+    (labrd),
+    (nsper, tpers),
+    (ortho),
+    (noop),
+    (krovak, krovak_etrs),
+    (topocentric),
+    (igh),
+    (ocea),
+    (urm5),
 ];
 
 ///
 /// Return the projection definition
 ///
-pub(crate) fn find_projection(name: &str) -> Option<&ProjInit> {
+/// Builtin projections take precedence over
+/// registered plugins.
+pub(crate) fn find_projection(name: &str) -> Option<ProjInit> {
     PROJECTIONS
         .iter()
         .find(|d| d.name().eq_ignore_ascii_case(name))
+        .copied()
+        .or_else(|| plugin::registry::find(name))
+}
+
+/// Return the names of the builtin projections, aliases included
+///
+/// Registered plugins are not listed.
+pub fn projection_names() -> impl Iterator<Item = &'static str> {
+    PROJECTIONS.iter().map(ProjInit::name)
+}
+
+// ----------------------------
+// Plugin projections
+// ---------------------------
+
+/// Projection algorithm for third-party projections
+///
+/// As for builtin projections, forward input is in radians relative
+/// to the central meridian and output is in units of the semi-major axis;
+/// scaling, false easting/northing and units are handled by the caller.
+pub trait ProjectionAlgorithm: Send + Sync {
+    fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)>;
+    fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)>;
+    fn has_inverse(&self) -> bool {
+        true
+    }
+    fn has_forward(&self) -> bool {
+        true
+    }
+    /// Cheap check that the (`lam`, `phi`) input of the forward
+    /// projection is in the projection domain
+    fn domain_check(&self, _lam: f64, _phi: f64) -> bool {
+        true
+    }
+}
+
+/// Parameters of a plugin projection definition
+///
+/// Parameters read through this trait are considered as used by
+/// [`Proj::from_proj_string_strict`](crate::Proj::from_proj_string_strict).
+pub trait PluginParams {
+    /// Return the value of the parameter `name`
+    ///
+    /// Return `None` if the parameter is missing or has no value.
+    fn value(&self, name: &str) -> Option<&str>;
+    /// Return the value of the parameter `name` as a number
+    fn try_f64(&self, name: &str) -> Result<Option<f64>>;
+    /// Return the angular value of the parameter `name` in radians
+    ///
+    /// The value is given in degrees, decimal or DMS.
+    fn try_angular_value(&self, name: &str) -> Result<Option<f64>>;
+    /// Return `true` if the flag `name` is set
+    fn check_option(&self, name: &str) -> Result<bool>;
+}
+
+impl PluginParams for ParamList<'_> {
+    fn value(&self, name: &str) -> Option<&str> {
+        self.get(name).and_then(|p| p.value)
+    }
+    fn try_f64(&self, name: &str) -> Result<Option<f64>> {
+        self.get(name).map(f64::try_from).transpose()
+    }
+    fn try_angular_value(&self, name: &str) -> Result<Option<f64>> {
+        ParamList::try_angular_value(self, name)
+    }
+    fn check_option(&self, name: &str) -> Result<bool> {
+        ParamList::check_option(self, name)
+    }
+}
+
+/// Initialize a plugin projection from projection data and parameters
+pub type PluginInitFn =
+    fn(&mut ProjData, &dyn PluginParams) -> Result<Box<dyn ProjectionAlgorithm>>;
+
+/// Register a third-party projection
+///
+/// The projection is then available with `+proj=<name>`. Registering
+/// a name twice replaces the previous registration; builtin projections
+/// cannot be overridden.
+///
+/// ```rust
+/// use proj4rs::errors::Result;
+/// use proj4rs::projections::{register_projection, PluginParams, ProjectionAlgorithm};
+/// use proj4rs::proj::ProjData;
+/// use proj4rs::Proj;
+///
+/// struct Identity;
+///
+/// impl ProjectionAlgorithm for Identity {
+///     fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+///         Ok((lam, phi, z))
+///     }
+///     fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+///         Ok((x, y, z))
+///     }
+/// }
+///
+/// fn identity(_: &mut ProjData, _: &dyn PluginParams) -> Result<Box<dyn ProjectionAlgorithm>> {
+///     Ok(Box::new(Identity))
+/// }
+///
+/// register_projection("identity", identity);
+///
+/// let p = Proj::from_proj_string("+proj=identity +ellps=WGS84").unwrap();
+/// assert_eq!(p.projname(), "identity");
+/// ```
+pub fn register_projection(name: &str, init: PluginInitFn) {
+    plugin::registry::register(name, init)
+}
+
+pub(crate) mod plugin {
+    use super::*;
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    pub(crate) struct Projection(Arc<dyn ProjectionAlgorithm>);
+
+    impl fmt::Debug for Projection {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "Plugin")
+        }
+    }
+
+    // Plugin parameters are opaque: only clones of
+    // the same instance compare equal
+    impl PartialEq for Projection {
+        fn eq(&self, other: &Self) -> bool {
+            Arc::ptr_eq(&self.0, &other.0)
+        }
+    }
+
+    pub(super) fn init(
+        init: PluginInitFn,
+        p: &mut ProjData,
+        params: &ParamList,
+    ) -> Result<ProjDelegate> {
+        let algo: Arc<dyn ProjectionAlgorithm> = init(p, params)?.into();
+        let (has_inverse, has_forward) = (algo.has_inverse(), algo.has_forward());
+        Ok(ProjDelegate(
+            ProjParams::Plugin(Projection(algo)),
+            inverse_,
+            forward_,
+            has_inverse,
+            has_forward,
+        ))
+    }
+
+    fn inverse_(p: &ProjParams, u: f64, v: f64, w: f64) -> Result<(f64, f64, f64)> {
+        downcast!(Plugin, p).0.inverse(u, v, w)
+    }
+
+    fn forward_(p: &ProjParams, u: f64, v: f64, w: f64) -> Result<(f64, f64, f64)> {
+        downcast!(Plugin, p).0.forward(u, v, w)
+    }
+
+    impl Projection {
+        #[inline]
+        pub(super) fn domain_check(&self, lam: f64, phi: f64) -> bool {
+            self.0.domain_check(lam, phi)
+        }
+    }
+
+    // Registered names need a static lifetime, as for
+    // builtin projection names: they are leaked once and
+    // reused when a name is registered again.
+    fn intern(names: &mut Vec<&'static str>, name: &str) -> &'static str {
+        match names.iter().find(|n| **n == name) {
+            Some(n) => n,
+            None => {
+                let n = &*Box::leak(name.to_owned().into_boxed_str());
+                names.push(n);
+                n
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct Registry {
+        plugins: Vec<ProjInit>,
+        names: Vec<&'static str>,
+    }
+
+    impl Registry {
+        fn register(&mut self, name: &str, init: PluginInitFn) {
+            let name = intern(&mut self.names, name);
+            self.plugins
+                .retain(|p| !p.name().eq_ignore_ascii_case(name));
+            self.plugins.push(ProjInit(name, Initializer::Plugin(init)));
+        }
+
+        fn find(&self, name: &str) -> Option<ProjInit> {
+            self.plugins
+                .iter()
+                .find(|p| p.name().eq_ignore_ascii_case(name))
+                .copied()
+        }
+    }
+
+    #[cfg(feature = "multi-thread")]
+    pub(super) mod registry {
+        use super::*;
+        use lazy_static::lazy_static;
+        use std::sync::Mutex;
+
+        lazy_static! {
+            static ref PLUGINS: Mutex<Registry> = Mutex::new(Registry::default());
+        }
+
+        pub(crate) fn register(name: &str, init: PluginInitFn) {
+            PLUGINS.lock().unwrap().register(name, init)
+        }
+
+        pub(crate) fn find(name: &str) -> Option<ProjInit> {
+            PLUGINS.lock().unwrap().find(name)
+        }
+    }
+
+    #[cfg(not(feature = "multi-thread"))]
+    pub(super) mod registry {
+        use super::*;
+        use std::cell::RefCell;
+
+        thread_local! {
+            static PLUGINS: RefCell<Registry> = RefCell::new(Registry::default());
+        }
+
+        pub(crate) fn register(name: &str, init: PluginInitFn) {
+            PLUGINS.with(|plugins| plugins.borrow_mut().register(name, init))
+        }
+
+        pub(crate) fn find(name: &str) -> Option<ProjInit> {
+            PLUGINS.with(|plugins| plugins.borrow().find(name))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::Error;
+    use crate::proj::Proj;
+    use crate::transform::transform;
+    use approx::assert_abs_diff_eq;
+
+    struct Scaled(f64);
+
+    impl ProjectionAlgorithm for Scaled {
+        fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+            Ok((self.0 * lam, self.0 * phi, z))
+        }
+        fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+            Ok((x / self.0, y / self.0, z))
+        }
+        fn has_inverse(&self) -> bool {
+            false
+        }
+    }
+
+    fn scaled(_: &mut ProjData, params: &dyn PluginParams) -> Result<Box<dyn ProjectionAlgorithm>> {
+        Ok(Box::new(Scaled(params.try_f64("scale")?.unwrap_or(1.))))
+    }
+
+    #[test]
+    fn proj_plugin() {
+        register_projection("test_scaled", scaled);
+
+        let src = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+        let dst = Proj::from_proj_string("+proj=test_scaled +scale=2 +ellps=WGS84").unwrap();
+        assert_eq!(dst.projname(), "test_scaled");
+
+        let mut pt = (1.0f64.to_radians(), 2.0f64.to_radians(), 0.);
+        transform(&src, &dst, &mut pt).unwrap();
+        assert_abs_diff_eq!(pt.0, 2. * 6378137. * 1.0f64.to_radians(), epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.1, 2. * 6378137. * 2.0f64.to_radians(), epsilon = 1.0e-6);
+
+        // Inverse not available
+        assert!(matches!(
+            transform(&dst, &src, &mut pt),
+            Err(Error::NoInverseProjectionDefined)
+        ));
+    }
+
+    #[test]
+    fn proj_delegate_no_inverse() {
+        register_projection("test_scaled", scaled);
+
+        let p = Proj::from_proj_string("+proj=test_scaled +scale=2 +ellps=WGS84").unwrap();
+        assert!(p.projection().forward(1., 1., 0.).is_ok());
+        assert!(matches!(
+            p.projection().inverse(2., 2., 0.),
+            Err(Error::NoInverseProjectionDefined)
+        ));
+    }
+
+    #[test]
+    fn proj_projection_names() {
+        let names: Vec<_> = projection_names().collect();
+        assert_eq!(names.len(), NUM_PROJECTIONS);
+        assert!(names.contains(&"merc"));
+        assert!(names.contains(&"utm"));
+        assert!(names.iter().all(|n| find_projection(n).is_some()));
+    }
+
+    #[test]
+    fn proj_plugin_register_twice() {
+        register_projection("test_twice", scaled);
+        let p1 = Proj::from_proj_string("+proj=test_twice +ellps=WGS84").unwrap();
+        register_projection("test_twice", scaled);
+        let p2 = Proj::from_proj_string("+proj=test_twice +ellps=WGS84").unwrap();
+
+        // The name is not leaked again
+        assert!(std::ptr::eq(p1.projname(), p2.projname()));
+    }
+
+    #[test]
+    fn proj_plugin_params() {
+        fn check(
+            _: &mut ProjData,
+            params: &dyn PluginParams,
+        ) -> Result<Box<dyn ProjectionAlgorithm>> {
+            assert_eq!(params.value("name"), Some("foo"));
+            assert_eq!(params.value("flag"), None);
+            assert_eq!(params.value("missing"), None);
+            assert_eq!(params.try_f64("scale")?, Some(2.));
+            assert!(params.try_f64("name").is_err());
+            assert_eq!(params.try_angular_value("lat_1")?, Some(45f64.to_radians()));
+            assert!(params.check_option("flag")?);
+            assert!(!params.check_option("missing")?);
+            Ok(Box::new(Scaled(1.)))
+        }
+        register_projection("test_params", check);
+
+        Proj::from_proj_string_strict(
+            "+proj=test_params +name=foo +flag +scale=2 +lat_1=45 +ellps=WGS84",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn proj_plugin_no_override() {
+        register_projection("merc", scaled);
+        let p = Proj::from_proj_string("+proj=merc +ellps=WGS84").unwrap();
+        assert!(p.has_inverse());
+    }
 }
@@ -0,0 +1,85 @@
+//!
+//! No operation
+//!
+//! ref: <https://proj.org/operations/conversions/noop.html>
+//!
+//! noop: "No operation"
+//!
+//! The coordinates are passed through unchanged: like `latlong`, the
+//! projection steps of the transformation are skipped, and
+//! only the datum shift applies. Note that, as for `latlong`, the
+//! first coordinate is wrapped to [-pi, pi] unless `+over` is set.
+//!
+use crate::errors::Result;
+use crate::parameters::ParamList;
+use crate::proj::{ProjData, ProjType};
+
+// Projection stub
+super::projection! { noop }
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {}
+
+impl Projection {
+    pub fn noop(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        p.proj_type = ProjType::Latlong;
+        p.x0 = 0.;
+        p.y0 = 0.;
+        Ok(Self {})
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        Ok((x, y, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        Ok((x, y, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proj::Proj;
+    use crate::transform::transform;
+
+    #[test]
+    fn proj_noop() {
+        let p = Proj::from_proj_string("+proj=noop +ellps=GRS80").unwrap();
+        assert_eq!(p.projname(), "noop");
+
+        let others = [
+            Proj::from_proj_string("+proj=noop +ellps=WGS84").unwrap(),
+            Proj::from_proj_string("+proj=noop +ellps=GRS80 +x_0=1000").unwrap(),
+            Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap(),
+        ];
+
+        let input = (2.5, -1.2, 10.);
+        for other in &others {
+            let mut pt = input;
+            transform(&p, other, &mut pt).unwrap();
+            assert_eq!(pt, input);
+
+            transform(other, &p, &mut pt).unwrap();
+            assert_eq!(pt, input);
+        }
+
+        // Arbitrary coordinates
+        let p = Proj::from_proj_string("+proj=noop +ellps=GRS80 +over").unwrap();
+        let other = Proj::from_proj_string("+proj=noop +ellps=WGS84 +over").unwrap();
+
+        let input = (123456.789, -9876.54321, 10.);
+        let mut pt = input;
+        transform(&p, &other, &mut pt).unwrap();
+        assert_eq!(pt, input);
+    }
+}
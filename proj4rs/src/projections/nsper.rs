@@ -0,0 +1,268 @@
+//!
+//! Near-sided perspective
+//!
+//! ref: <https://proj.org/operations/projections/nsper.html>
+//!
+//! nsper: "Near-sided perspective" "\n\tAzi, Sph\n\th=";
+//! tpers: "Tilted perspective" "\n\tAzi, Sph\n\ttilt= azi= h=";
+//!
+//! `h` is the height of the view point above the sphere. For `tpers`,
+//! the view is tilted by `tilt` from the nadir toward the azimuth `azi`.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::consts::{EPS_10, FRAC_PI_2};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { nsper, tpers }
+
+#[allow(non_camel_case_types)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Mode {
+    S_POLE,
+    N_POLE,
+    OBLIQ,
+    EQUIT,
+}
+
+use Mode::*;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Tilt {
+    cg: f64,
+    sg: f64,
+    cw: f64,
+    sw: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {
+    mode: Mode,
+    phi0: f64,
+    sinph0: f64,
+    cosph0: f64,
+    p: f64,
+    rp: f64,
+    pn1: f64,
+    pfact: f64,
+    h: f64,
+    tilt: Option<Tilt>,
+}
+
+impl Projection {
+    fn init(p: &mut ProjData, params: &ParamList, tilt: Option<Tilt>) -> Result<Self> {
+        let height = params.try_value::<f64>("h")?.unwrap_or(0.);
+
+        let phi0 = p.phi0;
+        let mode = if (phi0.abs() - FRAC_PI_2).abs() < EPS_10 {
+            if phi0 < 0. {
+                S_POLE
+            } else {
+                N_POLE
+            }
+        } else if phi0.abs() < EPS_10 {
+            EQUIT
+        } else {
+            OBLIQ
+        };
+
+        // Normalize by radius
+        let pn1 = height / p.ellps.a;
+        if pn1 <= 0. || pn1 > 1.0e10 {
+            return Err(Error::InvalidParameterValue("Invalid value for h"));
+        }
+
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+
+        let pp = 1. + pn1;
+        let h = 1. / pn1;
+        Ok(Self {
+            mode,
+            phi0,
+            sinph0: phi0.sin(),
+            cosph0: phi0.cos(),
+            p: pp,
+            rp: 1. / pp,
+            pn1,
+            pfact: (pp + 1.) * h,
+            h,
+            tilt,
+        })
+    }
+
+    pub fn nsper(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        Self::init(p, params, None)
+    }
+
+    pub fn tpers(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let omega = params.try_angular_value("tilt")?.unwrap_or(0.);
+        let gamma = params.try_angular_value("azi")?.unwrap_or(0.);
+        let (sg, cg) = gamma.sin_cos();
+        let (sw, cw) = omega.sin_cos();
+        Self::init(p, params, Some(Tilt { cg, sg, cw, sw }))
+    }
+
+    // Cosine of the angular distance from the center
+    #[inline]
+    fn cos_c(&self, sinphi: f64, cosphi: f64, coslam: f64) -> f64 {
+        match self.mode {
+            OBLIQ => self.sinph0 * sinphi + self.cosph0 * cosphi * coslam,
+            EQUIT => cosphi * coslam,
+            S_POLE => -sinphi,
+            N_POLE => sinphi,
+        }
+    }
+
+    /// Points beyond the horizon are not visible
+    pub fn domain_check(&self, lam: f64, phi: f64) -> bool {
+        let (sinphi, cosphi) = phi.sin_cos();
+        self.cos_c(sinphi, cosphi, lam.cos()) >= self.rp
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (sinphi, cosphi) = phi.sin_cos();
+        let (sinlam, coslam) = lam.sin_cos();
+
+        let cosc = self.cos_c(sinphi, cosphi, coslam);
+        if cosc < self.rp {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+
+        let k = self.pn1 / (self.p - cosc);
+        let x = k * cosphi * sinlam;
+        let y = k * match self.mode {
+            OBLIQ => self.cosph0 * sinphi - self.sinph0 * cosphi * coslam,
+            EQUIT => sinphi,
+            N_POLE => -cosphi * coslam,
+            S_POLE => cosphi * coslam,
+        };
+
+        match &self.tilt {
+            Some(t) => {
+                let yt = y * t.cg + x * t.sg;
+                let ba = 1. / (yt * t.sw * self.h + t.cw);
+                Ok(((x * t.cg - y * t.sg) * t.cw * ba, yt * ba, z))
+            }
+            None => Ok((x, y, z)),
+        }
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (x, y) = match &self.tilt {
+            Some(t) => {
+                let yt = 1. / (self.pn1 - y * t.sw);
+                let bm = self.pn1 * x * yt;
+                let bq = self.pn1 * y * t.cw * yt;
+                (bm * t.cg + bq * t.sg, bq * t.cg - bm * t.sg)
+            }
+            None => (x, y),
+        };
+
+        let rh = x.hypot(y);
+        if rh <= EPS_10 {
+            return Ok((0., self.phi0, z));
+        }
+
+        let sinz = 1. - rh * rh * self.pfact;
+        if sinz < 0. {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        let sinz = (self.p - sinz.sqrt()) / (self.pn1 / rh + rh / self.pn1);
+        let cosz = (1. - sinz * sinz).sqrt();
+
+        let (phi, x, y) = match self.mode {
+            OBLIQ => {
+                let phi = (cosz * self.sinph0 + y * sinz * self.cosph0 / rh).asin();
+                (
+                    phi,
+                    x * sinz * self.cosph0,
+                    (cosz - self.sinph0 * phi.sin()) * rh,
+                )
+            }
+            EQUIT => ((y * sinz / rh).asin(), x * sinz, cosz * rh),
+            N_POLE => (cosz.asin(), x, -y),
+            S_POLE => (-cosz.asin(), x, y),
+        };
+
+        Ok((x.atan2(y), phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_nsper() {
+        let p = Proj::from_proj_string("+proj=nsper +h=3000000 +ellps=WGS84").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222200.18490175337, 111133.94234834668, 0.)),
+            (
+                (-20., -30., 0.),
+                (-1353411.1340855007, -2284638.1940857363, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+
+        // Beyond the horizon
+        assert!(!p.domain_check(0., 60f64.to_radians()));
+        assert!(p.domain_check(0., 30f64.to_radians()));
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_tpers() {
+        let p = Proj::from_proj_string(
+            "+proj=tpers +h=5500000 +lat_0=40 +lon_0=-60 +tilt=30 +azi=20 +ellps=WGS84",
+        )
+        .unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // Reference values from Snyder's tilted perspective formulas
+        let inputs = [
+            ((-60., 40., 0.), (0., 0., 0.)),
+            (
+                (-55., 45., 0.),
+                (162949.44440071666, 715413.45802055202, 0.),
+            ),
+            (
+                (-65., 35., 0.),
+                (-258351.88593486179, -820633.13113103687, 0.),
+            ),
+            (
+                (-60., 50., 0.),
+                (-336157.08457936051, 1066462.9411972525, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_nsper_invalid_height() {
+        assert!(Proj::from_proj_string("+proj=nsper +ellps=WGS84").is_err());
+        assert!(Proj::from_proj_string("+proj=tpers +h=-1 +ellps=WGS84").is_err());
+    }
+}
@@ -0,0 +1,211 @@
+//!
+//! Oblique Cylindrical Equal Area
+//!
+//! ref: <https://proj.org/operations/projections/ocea.html>
+//!
+//! ocea: "Oblique Cylindrical Equal Area" "\n\tCyl, Sph\n\tlonc= alpha= or\n\tlat_1= lat_2= lon_1= lon_2=";
+//!
+//! The central line is defined either by a point (`lonc`, `lat_0`)
+//! and an azimuth `alpha`, or by two points (`lon_1`, `lat_1`) and
+//! (`lon_2`, `lat_2`).
+//!
+//! With an azimuth, the longitude of the pole is computed with `atan2`
+//! where proj uses `atan`: when `-sin(lat_0) * sin(alpha)` is negative,
+//! e.g. for `lat_0 < 0` and `0 < alpha < 180°`, the pole is on the
+//! opposite meridian and the results differ from proj. For `+lat_0=-20
+//! +alpha=45 +lonc=30` the pole longitude is 138.9° where proj has -41.1°.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::Result;
+use crate::math::consts::{FRAC_PI_2, PI};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { ocea }
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {
+    rok: f64,
+    rtk: f64,
+    sinphi: f64,
+    cosphi: f64,
+}
+
+impl Projection {
+    pub fn ocea(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        // Pole of the oblique transformation
+        let (lam_p, phi_p) = match params.try_angular_value("alpha")? {
+            Some(alpha) => {
+                // Point and azimuth: alpha is the azimuth of the central line,
+                // clockwise from north, as for omerc
+                let alpha = PI + alpha;
+                let lonz = params.try_angular_value("lonc")?.unwrap_or(0.);
+                (
+                    (-alpha.cos()).atan2(-p.phi0.sin() * alpha.sin()) + lonz,
+                    (p.phi0.cos() * alpha.sin()).asin(),
+                )
+            }
+            None => {
+                // Two points
+                let phi_1 = params.try_angular_value("lat_1")?.unwrap_or(0.);
+                let phi_2 = params.try_angular_value("lat_2")?.unwrap_or(0.);
+                let lam_1 = params.try_angular_value("lon_1")?.unwrap_or(0.);
+                let lam_2 = params.try_angular_value("lon_2")?.unwrap_or(0.);
+
+                let mut lam_p = (phi_1.cos() * phi_2.sin() * lam_1.cos()
+                    - phi_1.sin() * phi_2.cos() * lam_2.cos())
+                .atan2(
+                    phi_1.sin() * phi_2.cos() * lam_2.sin()
+                        - phi_1.cos() * phi_2.sin() * lam_1.sin(),
+                );
+
+                // Take care of the wrap-around of lam0 when lon_1 = -90°
+                if lam_1 == -FRAC_PI_2 {
+                    lam_p = -lam_p;
+                }
+
+                let cos_lamp_m_lam_1 = (lam_p - lam_1).cos();
+                let tan_phi_1 = phi_1.tan();
+                let phi_p = if tan_phi_1 == 0. {
+                    if cos_lamp_m_lam_1 >= 0. {
+                        -FRAC_PI_2
+                    } else {
+                        FRAC_PI_2
+                    }
+                } else {
+                    (-cos_lamp_m_lam_1 / tan_phi_1).atan()
+                };
+                (lam_p, phi_p)
+            }
+        };
+
+        p.lam0 = lam_p + FRAC_PI_2;
+
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+
+        Ok(Self {
+            rok: 1. / p.k0,
+            rtk: p.k0,
+            sinphi: phi_p.sin(),
+            cosphi: phi_p.cos(),
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (sinlam, coslam) = lam.sin_cos();
+        let mut x = ((phi.tan() * self.cosphi + self.sinphi * sinlam) / coslam).atan();
+        if coslam < 0. {
+            x += PI;
+        }
+        Ok((
+            self.rtk * x,
+            self.rok * (self.sinphi * phi.sin() - self.cosphi * phi.cos() * sinlam),
+            z,
+        ))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let y = y / self.rok;
+        let x = x / self.rtk;
+        let t = (1. - y * y).sqrt();
+        let s = x.sin();
+        Ok((
+            (t * self.sinphi * s - y * self.cosphi).atan2(t * x.cos()),
+            (y * self.sinphi + t * self.cosphi * s).asin(),
+            z,
+        ))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_ocea_two_points() {
+        let p = Proj::from_proj_string("+proj=ocea +a=6400000 +lat_1=0.5 +lat_2=2").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (19994423.837934088, 223322.760576728, 0.)),
+            ((2., -1., 0.), (20217962.128015257, 223322.760576729, 0.)),
+            ((-2., 1., 0.), (19994423.837934092, -223322.760576727, 0.)),
+            ((-2., -1., 0.), (20217962.128015260, -223322.760576730, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-7);
+        test_proj_inverse(&p, &inputs, 1.0e-7);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_ocea_azimuth() {
+        let p =
+            Proj::from_proj_string("+proj=ocea +a=6400000 +lonc=30 +alpha=45 +lat_0=20").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (20702450.164305437, -832055.78146757248, 0.)),
+            ((2., -1., 0.), (20869646.304668141, -683620.85328609692, 0.)),
+            ((-2., 1., 0.), (21005536.849562714, -1159578.6271745005, 0.)),
+            (
+                (-2., -1., 0.),
+                (21172957.508458625, -1011143.6989930249, 0.),
+            ),
+            ((30., 40., 0.), (15451713.35490517, -1547806.4809470417, 0.)),
+            (
+                (-60., -20., 0.),
+                (27951760.646102666, -2798101.0275369783, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-7);
+        test_proj_inverse(&p, &inputs, 1.0e-7);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_ocea_azimuth_south() {
+        // The pole longitude differs by 180° from proj, see module docs
+        let p =
+            Proj::from_proj_string("+proj=ocea +a=6400000 +lonc=30 +alpha=45 +lat_0=-20").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // lam0 is 90° east of the pole
+        assert_abs_diff_eq!(p.data().lam0(), 3.9947396331111335, epsilon = 1.0e-12);
+
+        let inputs = [
+            ((2., 1., 0.), (23973946.953378737, -3564903.5052467857, 0.)),
+            (
+                (-2., -1., 0.),
+                (24569216.924534794, -3635972.5427210997, 0.),
+            ),
+            ((30., 40., 0.), (17477624.806682928, -3919183.588453085, 0.)),
+            (
+                (-60., -20., 0.),
+                (-7845567.6631279893, -2798101.0275369783, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-7);
+        test_proj_inverse(&p, &inputs, 1.0e-7);
+    }
+}
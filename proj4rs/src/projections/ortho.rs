@@ -0,0 +1,405 @@
+//!
+//! Orthographic
+//!
+//! ref: <https://proj.org/operations/projections/ortho.html>
+//!
+//! ortho: "Orthographic" "\n\tAzi, Sph&Ell";
+//!
+//! The ellipsoidal formulas are from the EPSG guidance note 7-2, §3.3.5.
+//! Since there is no closed form for the oblique inverse, the geodetic
+//! coordinates are computed with Newton iterations, starting from
+//! the spherical solution.
+//!
+use crate::errors::{Error, Result};
+use crate::math::consts::{EPS_10, FRAC_PI_2};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { ortho }
+
+const MAX_ITER: usize = 20;
+const TOL: f64 = 1.0e-12;
+
+#[allow(non_camel_case_types)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Mode {
+    N_POLE,
+    S_POLE,
+    EQUIT,
+    OBLIQ,
+}
+
+use Mode::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {
+    mode: Mode,
+    phi0: f64,
+    sinph0: f64,
+    cosph0: f64,
+    nu0: f64,
+    es: f64,
+    one_es: f64,
+}
+
+impl Projection {
+    pub fn ortho(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        let phi0 = p.phi0;
+        let mode = if (phi0.abs() - FRAC_PI_2).abs() <= EPS_10 {
+            if phi0 < 0. {
+                S_POLE
+            } else {
+                N_POLE
+            }
+        } else if phi0.abs() > EPS_10 {
+            OBLIQ
+        } else {
+            EQUIT
+        };
+
+        let (sinph0, cosph0) = phi0.sin_cos();
+        let el = &p.ellps;
+        Ok(Self {
+            mode,
+            phi0,
+            sinph0,
+            cosph0,
+            nu0: 1. / (1. - el.es * sinph0 * sinph0).sqrt(),
+            es: el.es,
+            one_es: el.one_es,
+        })
+    }
+
+    #[inline]
+    fn is_ellipsoid(&self) -> bool {
+        self.es != 0.
+    }
+
+    /// Points on the far side of the globe are not visible
+    pub fn domain_check(&self, lam: f64, phi: f64) -> bool {
+        let (sinphi, cosphi) = phi.sin_cos();
+        self.sinph0 * sinphi + self.cosph0 * cosphi * lam.cos() >= -EPS_10
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if self.is_ellipsoid() {
+            self.e_forward(lam, phi, z)
+        } else {
+            self.s_forward(lam, phi, z)
+        }
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if self.is_ellipsoid() {
+            self.e_inverse(x, y, z)
+        } else {
+            self.s_inverse(x, y, z)
+        }
+    }
+
+    //------------------
+    // Spherical
+    //------------------
+
+    #[inline(always)]
+    pub fn s_forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (sinphi, cosphi) = phi.sin_cos();
+        let (sinlam, coslam) = lam.sin_cos();
+
+        let y = match self.mode {
+            EQUIT => {
+                if cosphi * coslam < -EPS_10 {
+                    return Err(Error::CoordTransOutsideProjectionDomain);
+                }
+                sinphi
+            }
+            OBLIQ => {
+                if self.sinph0 * sinphi + self.cosph0 * cosphi * coslam < -EPS_10 {
+                    return Err(Error::CoordTransOutsideProjectionDomain);
+                }
+                self.cosph0 * sinphi - self.sinph0 * cosphi * coslam
+            }
+            N_POLE | S_POLE => {
+                if (phi - self.phi0).abs() - EPS_10 > FRAC_PI_2 {
+                    return Err(Error::CoordTransOutsideProjectionDomain);
+                }
+                if self.mode == N_POLE {
+                    -cosphi * coslam
+                } else {
+                    cosphi * coslam
+                }
+            }
+        };
+
+        Ok((cosphi * sinlam, y, z))
+    }
+
+    #[inline(always)]
+    pub fn s_inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let rh = x.hypot(y);
+        let sinc = if rh > 1. {
+            if rh - 1. > EPS_10 {
+                return Err(Error::CoordTransOutsideProjectionDomain);
+            }
+            1.
+        } else {
+            rh
+        };
+        let cosc = (1. - sinc * sinc).sqrt();
+
+        if rh <= EPS_10 {
+            return Ok((0., self.phi0, z));
+        }
+
+        let (phi, x, y) = match self.mode {
+            N_POLE => (sinc.acos(), x, -y),
+            S_POLE => (-sinc.acos(), x, y),
+            EQUIT => (asin_clamped(y * sinc / rh), x * sinc, cosc * rh),
+            OBLIQ => {
+                let sinphi = cosc * self.sinph0 + y * sinc * self.cosph0 / rh;
+                (
+                    asin_clamped(sinphi),
+                    x * sinc * self.cosph0,
+                    (cosc - self.sinph0 * sinphi) * rh,
+                )
+            }
+        };
+
+        let lam = if y == 0. && matches!(self.mode, OBLIQ | EQUIT) {
+            if x == 0. {
+                0.
+            } else {
+                FRAC_PI_2.copysign(x)
+            }
+        } else {
+            x.atan2(y)
+        };
+
+        Ok((lam, phi, z))
+    }
+
+    //------------------
+    // Ellipsoidal
+    //------------------
+
+    // Projected coordinates and the jacobian of the forward projection
+    #[inline]
+    fn e_project(&self, lam: f64, phi: f64) -> ((f64, f64), [f64; 4]) {
+        let (sinphi, cosphi) = phi.sin_cos();
+        let (sinlam, coslam) = lam.sin_cos();
+        let w = 1. - self.es * sinphi * sinphi;
+        let nu = 1. / w.sqrt();
+        // Radius of curvature in the meridian
+        let rho = self.one_es * nu / w;
+
+        let x = nu * cosphi * sinlam;
+        let y = nu * (sinphi * self.cosph0 - cosphi * self.sinph0 * coslam)
+            + self.es * (self.nu0 * self.sinph0 - nu * sinphi) * self.cosph0;
+
+        (
+            (x, y),
+            [
+                -rho * sinphi * sinlam,
+                nu * cosphi * coslam,
+                rho * (cosphi * self.cosph0 + sinphi * self.sinph0 * coslam),
+                nu * self.sinph0 * cosphi * sinlam,
+            ],
+        )
+    }
+
+    #[inline(always)]
+    pub fn e_forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // The visibility condition is the same as for the sphere
+        if !self.domain_check(lam, phi) {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        let ((x, y), _) = self.e_project(lam, phi);
+        Ok((x, y, z))
+    }
+
+    #[inline(always)]
+    pub fn e_inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        match self.mode {
+            N_POLE | S_POLE => {
+                // rh^2 = cosphi^2 / (1 - es * sinphi^2)
+                let rh2 = x * x + y * y;
+                let phi = if rh2 >= 1. - 1.0e-15 {
+                    if rh2 - 1. > EPS_10 {
+                        return Err(Error::CoordTransOutsideProjectionDomain);
+                    }
+                    0.
+                } else {
+                    (rh2 * self.one_es / (1. - self.es * rh2)).sqrt().acos()
+                };
+                if self.mode == N_POLE {
+                    Ok((x.atan2(-y), phi, z))
+                } else {
+                    Ok((x.atan2(y), -phi, z))
+                }
+            }
+            EQUIT => {
+                // Equation of the limb ellipse
+                if x * x + y * y / self.one_es > 1. + 1.0e-11 {
+                    return Err(Error::CoordTransOutsideProjectionDomain);
+                }
+                let sinphi2 = if y == 0. {
+                    0.
+                } else {
+                    1. / ((self.one_es / y).powi(2) + self.es)
+                };
+                if sinphi2 > 1. - 1.0e-11 {
+                    return Ok((0., FRAC_PI_2.copysign(y), z));
+                }
+                let phi = sinphi2.sqrt().asin().copysign(y);
+                let sinlam = x * ((1. - self.es * sinphi2) / (1. - sinphi2)).sqrt();
+                let lam = if sinlam.abs() - 1. > -1.0e-15 {
+                    FRAC_PI_2.copysign(x)
+                } else {
+                    sinlam.asin()
+                };
+                Ok((lam, phi, z))
+            }
+            OBLIQ => {
+                // Start from the spherical solution, clamped
+                // to the limb of the sphere
+                let rh = x.hypot(y);
+                let (x0, y0) = if rh > 1. { (x / rh, y / rh) } else { (x, y) };
+                let (mut lam, mut phi, _) = self.s_inverse(x0, y0, z)?;
+
+                for _ in 0..MAX_ITER {
+                    let ((xp, yp), [j11, j12, j21, j22]) = self.e_project(lam, phi);
+                    let det = j11 * j22 - j12 * j21;
+                    let (dx, dy) = (x - xp, y - yp);
+                    let dphi = (j22 * dx - j12 * dy) / det;
+                    let dlam = (-j21 * dx + j11 * dy) / det;
+                    phi = (phi + dphi).clamp(-FRAC_PI_2, FRAC_PI_2);
+                    lam += dlam;
+                    if dphi.abs() < TOL && dlam.abs() < TOL {
+                        return Ok((lam, phi, z));
+                    }
+                }
+                Err(Error::InverseProjectionFailure)
+            }
+        }
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[inline]
+fn asin_clamped(v: f64) -> f64 {
+    if v.abs() >= 1. {
+        FRAC_PI_2.copysign(v)
+    } else {
+        v.asin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_ortho_sphere() {
+        let p = Proj::from_proj_string("+proj=ortho +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223322.760576727, 111695.401198614, 0.)),
+            ((2., -1., 0.), (223322.760576727, -111695.401198614, 0.)),
+            ((-2., 1., 0.), (-223322.760576727, 111695.401198614, 0.)),
+            ((-2., -1., 0.), (-223322.760576727, -111695.401198614, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-9);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_ortho_ellipsoid_oblique() {
+        let p = Proj::from_proj_string("+proj=ortho +lat_0=55 +lon_0=5 +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // Reference values from the EPSG guidance note formulas
+        let inputs = [
+            ((5., 55., 0.), (0., 0., 0.)),
+            ((9., 50., 0.), (286550.11362546626, -547480.6206312478, 0.)),
+            (
+                (-3., 60., 0.),
+                (-444950.95946134855, 581622.96702648282, 0.),
+            ),
+            (
+                (20., 40., 0.),
+                (1266325.9090252542, -1512163.5391977465, 0.),
+            ),
+            ((5., 80., 0.), (0., 2700219.9234262073, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-9);
+    }
+
+    #[test]
+    fn proj_ortho_ellipsoid_polar() {
+        let p = Proj::from_proj_string("+proj=ortho +lat_0=90 +ellps=GRS80").unwrap();
+
+        let inputs = [
+            (
+                (10., 80., 0.),
+                (192951.75490675396, -1094283.7796660059, 0.),
+            ),
+            (
+                (-100., 70., 0.),
+                (-2154688.112071105, 379929.64916992054, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-9);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_ortho_ellipsoid_equatorial() {
+        let p = Proj::from_proj_string("+proj=ortho +ellps=GRS80").unwrap();
+
+        let inputs = [
+            ((30., 20., 0.), (2997918.1919539215, 2167696.787761423, 0.)),
+            (
+                (-60., -45., 0.),
+                (-3912348.4650201918, -4487348.4087548001, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-9);
+    }
+
+    #[test]
+    fn proj_ortho_domain() {
+        for s in [
+            "+proj=ortho +R=6400000",
+            "+proj=ortho +lat_0=30 +ellps=GRS80",
+        ] {
+            let p = Proj::from_proj_string(s).unwrap();
+            assert!(p.projection().forward(3., 0., 0.).is_err());
+            assert!(!p.domain_check(3., 0.));
+            assert!(p.projection().inverse(1.1, 0., 0.).is_err());
+        }
+    }
+}
@@ -16,7 +16,7 @@ use crate::proj::ProjData;
 // Projection stub
 super::projection! { somerc }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Projection {
     e: f64,
     rone_es: f64,
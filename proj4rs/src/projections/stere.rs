@@ -18,6 +18,17 @@
 //!
 //! See also <https://proj.org/operations/projections/ups.html>
 //!
+//! ### Scale factor
+//!
+//! For the polar aspects, the scale may be given either by the latitude
+//! of true scale `lat_ts` or by the scale factor at the pole `k`.
+//! When `lat_ts` is given and differs from the pole, it defines the scale
+//! and `k` must be left to its default value of `1`: giving both
+//! is an error.
+//!
+//! For the oblique and equatorial aspects `lat_ts` is ignored and the
+//! scale is given by `k`.
+//!
 //! stere: "Stereographic" "\n\tAzi, Sph&Ell\n\tlat_ts=";
 //! ups: "Universal Polar Stereographic") "\n\tAzi, Sph&Ell\n\tsouth";
 //!
@@ -50,7 +61,7 @@ use Mode::*;
 // Projection stub
 super::projection! { stere, ups }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Projection {
     mode: Mode,
     e: f64,
@@ -66,11 +77,27 @@ impl Projection {
         self.e != 0.
     }
 
+    /// Polar aspects are undefined at the opposite pole
+    pub fn domain_check(&self, _lam: f64, phi: f64) -> bool {
+        match self.mode {
+            N_POLE => phi + FRAC_PI_2 > EPS_10,
+            S_POLE => FRAC_PI_2 - phi > EPS_10,
+            _ => true,
+        }
+    }
+
     // -----------
     // stere
     // -----------
     pub fn stere(p: &mut ProjData, params: &ParamList) -> Result<Self> {
-        Self::init(p, params.try_angular_value("lat_ts")?.unwrap_or(FRAC_PI_2))
+        let phits = params.try_angular_value("lat_ts")?.unwrap_or(FRAC_PI_2);
+        let is_polar = (p.phi0.abs() - FRAC_PI_2).abs() < EPS_10;
+        if is_polar && (phits.abs() - FRAC_PI_2).abs() >= EPS_10 && p.k0 != 1. {
+            return Err(Error::InvalidParameterValue(
+                "lat_ts and k cannot be both defined",
+            ));
+        }
+        Self::init(p, phits)
     }
 
     // -----------
@@ -464,6 +491,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn proj_stere_ups_ellipsoidal() {
         let p = Proj::from_proj_string("+proj=ups +ellps=GRS80").unwrap();
 
@@ -504,4 +532,86 @@ mod tests {
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_stere_lat_ts_spherical() {
+        let p = Proj::from_proj_string("+proj=stere +lat_0=90 +lat_ts=70 +R=6378137").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // Reference values computed with mpmath: true scale at 70°N,
+        // rho = R * cos(70°) * tan(45° - phi/2) / tan(10°)
+        let inputs = [
+            ((0., 70., 0.), (0., -2181451.3308907508, 0.)),
+            (
+                (30., 80., 0.),
+                (541188.48040059962, -937365.94452483209, 0.),
+            ),
+            (
+                (-120., 60., 0.),
+                (-2870845.6355998799, 1657483.5005154529, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-6);
+
+        // Scale along the parallel is 1 at lat_ts
+        let t = crate::analysis::tissot_at(&p, 0., 70.).unwrap();
+        assert!((t.scale_k - 1.).abs() < 1.0e-8);
+    }
+
+    #[test]
+    fn proj_stere_ups_domain_check() {
+        let p = Proj::from_proj_string("+proj=ups +ellps=WGS84").unwrap();
+        assert!(p.domain_check(0., FRAC_PI_2));
+        assert!(p.domain_check(0., -1.5));
+        assert!(!p.domain_check(0., -FRAC_PI_2));
+
+        let p = Proj::from_proj_string("+proj=ups +south +ellps=WGS84").unwrap();
+        assert!(p.domain_check(0., -FRAC_PI_2));
+        assert!(!p.domain_check(0., FRAC_PI_2));
+    }
+
+    #[test]
+    fn proj_stere_antarctic() {
+        // EPSG:3031
+        let p = Proj::from_proj_string(
+            "+proj=stere +lat_0=-90 +lat_ts=-71 +lon_0=0 +x_0=0 +y_0=0 +datum=WGS84 +units=m",
+        )
+        .unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((0., -71., 0.), (0., 2082760.108542913, 0.)),
+            ((45., -80., 0.), (770166.1790023467, 770166.1790023468, 0.)),
+            (
+                (-120., -65., 0.),
+                (-2388932.766882399, -1379250.97603547, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_stere_lat_ts_and_k() {
+        // k = 1 is accepted as found in EPSG definitions
+        assert!(
+            Proj::from_proj_string("+proj=stere +lat_0=-90 +lat_ts=-71 +k=1 +datum=WGS84").is_ok()
+        );
+        assert!(Proj::from_proj_string("+proj=stere +lat_0=-90 +k=0.994 +datum=WGS84").is_ok());
+        assert!(matches!(
+            Proj::from_proj_string("+proj=stere +lat_0=-90 +lat_ts=-71 +k=0.994 +datum=WGS84"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+        // lat_ts is ignored for oblique aspect
+        assert!(
+            Proj::from_proj_string("+proj=stere +lat_0=45 +lat_ts=30 +k=0.9999 +datum=WGS84")
+                .is_ok()
+        );
+    }
 }
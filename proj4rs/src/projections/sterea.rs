@@ -13,7 +13,7 @@ use crate::proj::ProjData;
 // Projection stub
 super::projection! { sterea }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Projection {
     k0: f64,
     phic0: f64,
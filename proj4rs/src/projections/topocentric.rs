@@ -0,0 +1,162 @@
+//!
+//! Topocentric (local East-North-Up)
+//!
+//! ref: <https://proj.org/operations/conversions/topocentric.html>
+//!
+//! topocentric: "Geocentric/Topocentric conversion" "\n\tlat_0= lon_0= h_0= or X_0= Y_0= Z_0="
+//!
+//! Unlike proj, where the conversion applies to geocentric coordinates,
+//! the input is geographic: points are converted to geocentric coordinates
+//! then rotated to the local tangent plane at the origin. Geocentric input
+//! is handled by transforming from a `cart` definition.
+//!
+//! The origin is given either as geographic coordinates (`lat_0`, `lon_0`,
+//! `h_0`) or as geocentric coordinates (`X_0`, `Y_0`, `Z_0`).
+//!
+//! The conversion is the one of [`EnuFrame`](crate::frames::EnuFrame).
+//!
+use crate::errors::{Error, Result};
+use crate::frames::EnuFrame;
+use crate::geocent::geocentric_to_geodetic;
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { topocentric }
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {
+    a: f64,
+    // Local frame at the origin, in the meridian plane of lon_0
+    frame: EnuFrame,
+}
+
+impl Projection {
+    pub fn topocentric(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let (a, es, b) = (p.ellps.a, p.ellps.es, p.ellps.b);
+
+        let geocentric_origin = [
+            params.try_value::<f64>("X_0")?,
+            params.try_value::<f64>("Y_0")?,
+            params.try_value::<f64>("Z_0")?,
+        ];
+
+        let h0 = match geocentric_origin {
+            [Some(x), Some(y), Some(z)] => {
+                if ["lat_0", "lon_0", "h_0"]
+                    .iter()
+                    .any(|name| params.get(name).is_some())
+                {
+                    return Err(Error::InvalidParameterValue(
+                        "Mixing X_0/Y_0/Z_0 and lat_0/lon_0/h_0",
+                    ));
+                }
+                let (lam0, phi0, h0) = geocentric_to_geodetic(x, y, z, a, es, b)?;
+                p.lam0 = lam0;
+                p.phi0 = phi0;
+                h0
+            }
+            [None, None, None] => params.try_value::<f64>("h_0")?.unwrap_or(0.),
+            _ => {
+                return Err(Error::InvalidParameterValue(
+                    "X_0, Y_0 and Z_0 must be all defined",
+                ))
+            }
+        };
+
+        p.x0 = 0.;
+        p.y0 = 0.;
+
+        // Longitudes are relative to lon_0
+        let frame = EnuFrame::from_geodetic_origin(&p.ellps, p.phi0, 0., h0)?;
+
+        Ok(Self { a, frame })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (e, n, u) = self.frame.geodetic_to_enu(lam, phi, z)?;
+        Ok((e / self.a, n / self.a, u))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        self.frame.enu_to_geodetic(x * self.a, y * self.a, z)
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+    use crate::transform::transform;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_topocentric() {
+        let p = Proj::from_proj_string(
+            "+proj=topocentric +lat_0=46.5 +lon_0=6.5 +h_0=500 +ellps=GRS80",
+        )
+        .unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // Reference values computed with mpmath
+        let inputs = [
+            ((6.5, 46.5, 500.), (0., 0., 0.)),
+            (
+                (6.51, 46.51, 600.),
+                (767.55725533422042, 1111.7651148675674, 99.856877031768195),
+            ),
+            (
+                (6.4, 46.4, 400.),
+                (-7690.798256849144, -11111.834895951391, -114.32101209893687),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-6);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_topocentric_geocentric_origin() {
+        let cart = Proj::from_proj_string("+proj=cart +ellps=GRS80").unwrap();
+        let p = Proj::from_proj_string(
+            "+proj=topocentric +X_0=4383970.1839 +Y_0=499504.6346 +Z_0=4600941.0539 +ellps=GRS80",
+        )
+        .unwrap();
+
+        // ECEF point near the origin
+        let mut pt = (4383980.1839, 499514.6346, 4600951.0539);
+        transform(&cart, &p, &mut pt).unwrap();
+
+        // Reference values computed with mpmath
+        assert_abs_diff_eq!(pt.0, 8.8036507178736881, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.1, -1.1163783941728672, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.2, 14.87445573184801, epsilon = 1.0e-6);
+
+        transform(&p, &cart, &mut pt).unwrap();
+        assert_abs_diff_eq!(pt.0, 4383980.1839, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.1, 499514.6346, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.2, 4600951.0539, epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn proj_topocentric_invalid_origin() {
+        assert!(Proj::from_proj_string("+proj=topocentric +X_0=1 +Y_0=2 +ellps=GRS80").is_err());
+        assert!(Proj::from_proj_string(
+            "+proj=topocentric +X_0=4383970 +Y_0=499504 +Z_0=4600941 +lat_0=46 +ellps=GRS80"
+        )
+        .is_err());
+    }
+}
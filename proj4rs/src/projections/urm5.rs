@@ -0,0 +1,116 @@
+//!
+//! Urmaev V
+//!
+//! ref: <https://proj.org/operations/projections/urm5.html>
+//!
+//! urm5: "Urmaev V" "\n\tPCyl, Sph, no inv\n\tn= q= alpha=";
+//!
+//! Only the forward projection is implemented.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::aasin;
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { urm5 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {
+    m: f64,
+    rmn: f64,
+    q3: f64,
+    n: f64,
+}
+
+impl Projection {
+    pub fn urm5(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let n: f64 = params
+            .try_value("n")?
+            .ok_or(Error::InputStringError("Missing parameter 'n'"))?;
+        if n <= 0. || n > 1. {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for n: it should be in ]0,1] range.",
+            ));
+        }
+
+        let q3 = params.try_value::<f64>("q")?.unwrap_or(0.) / 3.;
+        let alpha = params.try_angular_value("alpha")?.unwrap_or(0.);
+
+        let t = n * alpha.sin();
+        let denom = (1. - t * t).sqrt();
+        if denom == 0. {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for n / alpha: n * sin(|alpha|) should be < 1.",
+            ));
+        }
+        let m = alpha.cos() / denom;
+
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+
+        Ok(Self {
+            m,
+            rmn: 1. / (m * n),
+            q3,
+            n,
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let phi = aasin(self.n * phi.sin())?;
+        let t = phi * phi;
+        Ok((
+            self.m * lam * phi.cos(),
+            phi * (1. + t * self.q3) * self.rmn,
+            z,
+        ))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, _: f64, _: f64, _: f64) -> Result<(f64, f64, f64)> {
+        Err(Error::NoInverseProjectionDefined)
+    }
+
+    pub const fn has_inverse() -> bool {
+        false
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proj::Proj;
+    use crate::tests::utils::test_proj_forward;
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_urm5() {
+        let p = Proj::from_proj_string("+proj=urm5 +a=6400000 +n=.3 +q=.3 +alpha=10").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (220304.28567066534, 113265.30687103971, 0.)),
+            ((2., -1., 0.), (220304.28567066534, -113265.30687103971, 0.)),
+            ((-2., 1., 0.), (-220304.28567066534, 113265.30687103971, 0.)),
+            (
+                (-2., -1., 0.),
+                (-220304.28567066534, -113265.30687103971, 0.),
+            ),
+            ((60., 45., 0.), (6458800.0348536608, 4645319.4620750785, 0.)),
+            (
+                (-150., -75., 0.),
+                (-15814109.418080967, -6414962.664898619, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        assert!(!p.has_inverse());
+    }
+}
@@ -0,0 +1,139 @@
+//!
+//! Urmaev Flat-Polar Sinusoidal
+//!
+//! ref: <https://proj.org/operations/projections/urmfps.html>
+//!
+//! urmfps: "Urmaev Flat-Polar Sinusoidal" "\n\tPCyl, Sph\n\tn=";
+//! wag1: "Wagner I (Kavraiskiy VI)" "\n\tPCyl, Sph.";
+//!
+//! Wagner I is the Urmaev flat-polar sinusoidal projection with
+//! `n = sqrt(3)/2`.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::aasin;
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { urmfps, wag1 }
+
+const C_X: f64 = 0.877_382_675_3;
+const C_Y: f64 = 1.139_753_528_477;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {
+    n: f64,
+    c_y: f64,
+}
+
+impl Projection {
+    pub fn urmfps(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let n: f64 = params
+            .try_value("n")?
+            .ok_or(Error::InputStringError("Missing parameter 'n'"))?;
+        if n <= 0. || n > 1. {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for n: it should be in ]0,1] range.",
+            ));
+        }
+        Self::new(p, n)
+    }
+
+    pub fn wag1(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        Self::new(p, 0.866_025_403_784_438_6)
+    }
+
+    fn new(p: &mut ProjData, n: f64) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+
+        Ok(Self { n, c_y: C_Y / n })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let phi = aasin(self.n * phi.sin())?;
+        Ok((C_X * lam * phi.cos(), self.c_y * phi, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let y = y / self.c_y;
+        let phi = aasin(y.sin() / self.n)?;
+        Ok((x / (C_X * y.cos()), phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn proj_urmfps() {
+        let p = Proj::from_proj_string("+proj=urmfps +a=6400000 +n=0.5").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (196001.708134192, 127306.843329993, 0.)),
+            ((2., -1., 0.), (196001.708134192, -127306.843329993, 0.)),
+            ((-2., 1., 0.), (-196001.708134192, 127306.843329993, 0.)),
+            ((-2., -1., 0.), (-196001.708134192, -127306.843329993, 0.)),
+            ((60., 45., 0.), (5500493.7189511248, 5271929.0182176702, 0.)),
+            (
+                (-150., -75., 0.),
+                (-12872522.163968071, -7353292.5931351215, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_urmfps_n() {
+        assert!(matches!(
+            Proj::from_proj_string("+proj=urmfps +a=6400000"),
+            Err(Error::InputStringError(_))
+        ));
+        for n in ["0", "1.5"] {
+            assert!(matches!(
+                Proj::from_proj_string(&format!("+proj=urmfps +a=6400000 +n={n}")),
+                Err(Error::InvalidParameterValue(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn proj_wag1() {
+        let p = Proj::from_proj_string("+proj=wag1 +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (195317.27234158386, 126875.17190893309, 0.)),
+            ((2., -1., 0.), (195317.27234158386, -126875.17190893309, 0.)),
+            ((-2., 1., 0.), (-195317.27234158386, 126875.17190893309, 0.)),
+            (
+                (-2., -1., 0.),
+                (-195317.27234158386, -126875.17190893309, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+}
@@ -0,0 +1,93 @@
+//!
+//! Wagner VII
+//!
+//! ref: <https://proj.org/operations/projections/wag7.html>
+//!
+//! wag7: "Wagner VII" "\n\tMisc Sph, no inv.";
+//!
+//! The inverse is not part of proj: it is derived from the
+//! Hammer-Aitoff construction of the projection, i.e an azimuthal
+//! equal area projection of the transformed coordinates.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::Result;
+use crate::math::aasin;
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { wag7 }
+
+// sin(65deg)
+const SIN_65: f64 = 0.906_307_787_036_65;
+const C_X: f64 = 2.66723;
+const C_Y: f64 = 1.24104;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Projection {}
+
+impl Projection {
+    pub fn wag7(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self {})
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let s = SIN_65 * phi.sin();
+        let ct = (1. - s * s).sqrt();
+        let (sl, cl) = (lam / 3.).sin_cos();
+        let d = 1. / (0.5 * (1. + ct * cl)).sqrt();
+        Ok((C_X * ct * sl * d, C_Y * s * d, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (x, y) = (x / C_X, y / C_Y);
+        let rho = x.hypot(y);
+        if rho < 1.0e-15 {
+            return Ok((0., 0., z));
+        }
+        let c = 2. * aasin(0.5 * rho)?;
+        let (sc, cc) = c.sin_cos();
+        let lam = 3. * (x * sc).atan2(rho * cc);
+        let phi = aasin(y * sc / (rho * SIN_65))?;
+        Ok((lam, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_wag7() {
+        let p = Proj::from_proj_string("+proj=wag7 +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (197923.4343266996, 125207.85778753877, 0.)),
+            ((2., -1., 0.), (197923.4343266996, -125207.85778753877, 0.)),
+            ((-2., 1., 0.), (-197923.4343266996, 125207.85778753877, 0.)),
+            (
+                (-2., -1., 0.),
+                (-197923.4343266996, -125207.85778753877, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+}
@@ -0,0 +1,996 @@
+//!
+//! PROJJSON support
+//!
+//! Only the subset of PROJJSON needed for describing basic geographic,
+//! geocentric and projected CRS is supported.
+//!
+//! A datum shift to WGS84 defined by `towgs84` is described by
+//! a `BoundCRS`.
+//!
+//! See <https://proj.org/specifications/projjson.html>
+//!
+use crate::datum_params::DatumParams;
+use crate::errors::{Error, Result};
+use crate::math::consts::{EPS_10, FRAC_PI_2, SEC_TO_RAD};
+use crate::parameters::ParamList;
+use crate::proj::{Proj, ProjType};
+use crate::projstring;
+use serde_json::{json, Map, Value};
+
+const PROJJSON_SCHEMA: &str = "https://proj.org/schemas/v0.7/projjson.schema.json";
+
+// EPSG datum codes mapped to proj datum identifiers
+#[rustfmt::skip]
+const EPSG_DATUMS: [(u64, &str); 13] = [
+    (6326, "WGS84"),
+    (6121, "GGRS87"),
+    (6269, "NAD83"),
+    (6267, "NAD27"),
+    (6314, "potsdam"),
+    (6223, "carthage"),
+    (6312, "hermannskogel"),
+    (6299, "ire65"),
+    (6272, "nzgd49"),
+    (6277, "OSGB36"),
+    (6149, "ch1903"),
+    (6156, "s_jtsk"),
+    (6313, "rnb72"),
+];
+
+/// Conversion method
+struct Method {
+    name: &'static str,
+    proj: &'static str,
+    // EPSG parameters mapped to proj parameters
+    params: &'static [(&'static str, &'static str)],
+}
+
+const LAT_NAT: (&str, &str) = ("Latitude of natural origin", "lat_0");
+const LON_NAT: (&str, &str) = ("Longitude of natural origin", "lon_0");
+const K_NAT: (&str, &str) = ("Scale factor at natural origin", "k");
+const FE: (&str, &str) = ("False easting", "x_0");
+const FN: (&str, &str) = ("False northing", "y_0");
+const LAT_FO: (&str, &str) = ("Latitude of false origin", "lat_0");
+const LON_FO: (&str, &str) = ("Longitude of false origin", "lon_0");
+const E_FO: (&str, &str) = ("Easting at false origin", "x_0");
+const N_FO: (&str, &str) = ("Northing at false origin", "y_0");
+const LAT_1: (&str, &str) = ("Latitude of 1st standard parallel", "lat_1");
+const LAT_2: (&str, &str) = ("Latitude of 2nd standard parallel", "lat_2");
+const LAT_1_TS: (&str, &str) = ("Latitude of 1st standard parallel", "lat_ts");
+
+const TRANSVERSE_MERCATOR: &str = "Transverse Mercator";
+const LCC_2SP: &str = "Lambert Conic Conformal (2SP)";
+const LCC_1SP: &str = "Lambert Conic Conformal (1SP)";
+const MERCATOR_A: &str = "Mercator (variant A)";
+const MERCATOR_B: &str = "Mercator (variant B)";
+const PSEUDO_MERCATOR: &str = "Popular Visualisation Pseudo Mercator";
+const LAEA: &str = "Lambert Azimuthal Equal Area";
+const AEA: &str = "Albers Equal Area";
+const POLAR_STEREO_A: &str = "Polar Stereographic (variant A)";
+const POLAR_STEREO_B: &str = "Polar Stereographic (variant B)";
+const OBLIQUE_STEREO: &str = "Oblique Stereographic";
+const EQC: &str = "Equidistant Cylindrical";
+
+// Supported conversion methods
+#[rustfmt::skip]
+const METHODS: [Method; 12] = [
+    Method { name: TRANSVERSE_MERCATOR, proj: "+proj=tmerc", params: &[LAT_NAT, LON_NAT, K_NAT, FE, FN] },
+    Method { name: LCC_2SP, proj: "+proj=lcc", params: &[LAT_FO, LON_FO, LAT_1, LAT_2, E_FO, N_FO] },
+    // The standard parallel is the latitude of origin
+    Method { name: LCC_1SP, proj: "+proj=lcc", params: &[LAT_NAT, LON_NAT, K_NAT, FE, FN] },
+    Method { name: MERCATOR_A, proj: "+proj=merc", params: &[LAT_NAT, LON_NAT, K_NAT, FE, FN] },
+    Method { name: MERCATOR_B, proj: "+proj=merc", params: &[LAT_1_TS, LON_NAT, FE, FN] },
+    // The method defines its own sphere
+    Method {
+        name: PSEUDO_MERCATOR,
+        proj: "+proj=merc +a=6378137 +b=6378137 +nadgrids=@null",
+        params: &[LAT_NAT, LON_NAT, FE, FN],
+    },
+    Method { name: LAEA, proj: "+proj=laea", params: &[LAT_NAT, LON_NAT, FE, FN] },
+    Method { name: AEA, proj: "+proj=aea", params: &[LAT_FO, LON_FO, LAT_1, LAT_2, E_FO, N_FO] },
+    Method { name: POLAR_STEREO_A, proj: "+proj=stere", params: &[LAT_NAT, LON_NAT, K_NAT, FE, FN] },
+    // The pole is given by the sign of the standard parallel
+    Method {
+        name: POLAR_STEREO_B,
+        proj: "+proj=stere",
+        params: &[("Latitude of standard parallel", "lat_ts"), ("Longitude of origin", "lon_0"), FE, FN],
+    },
+    Method { name: OBLIQUE_STEREO, proj: "+proj=sterea", params: &[LAT_NAT, LON_NAT, K_NAT, FE, FN] },
+    Method { name: EQC, proj: "+proj=eqc", params: &[LAT_1_TS, LAT_NAT, LON_NAT, FE, FN] },
+];
+
+fn find_method(name: &str) -> Option<&'static Method> {
+    METHODS.iter().find(|m| m.name.eq_ignore_ascii_case(name))
+}
+
+// Datum shift parameters, with the SI factor of their unit
+#[rustfmt::skip]
+const TOWGS84_PARAMS: [(&str, f64); 7] = [
+    ("X-axis translation", 1.),
+    ("Y-axis translation", 1.),
+    ("Z-axis translation", 1.),
+    ("X-axis rotation", SEC_TO_RAD),
+    ("Y-axis rotation", SEC_TO_RAD),
+    ("Z-axis rotation", SEC_TO_RAD),
+    ("Scale difference", 1.0e-6),
+];
+
+const GEOCENTRIC_TRANSLATIONS: &str = "Geocentric translations (geog2D domain)";
+const POSITION_VECTOR: &str = "Position Vector transformation (geog2D domain)";
+
+#[inline]
+fn invalid(msg: &'static str) -> Error {
+    Error::InvalidProjJson(msg)
+}
+
+/// Return a numerical value, either as plain number or
+/// as a `{ "value": .., "unit": .. }` object.
+///
+/// Angular values are returned in degrees and linear values
+/// in meters.
+fn value_of(v: &Value) -> Option<f64> {
+    match v {
+        Value::Number(n) => n.as_f64(),
+        Value::Object(o) => {
+            o.get("value").and_then(Value::as_f64).map(|x| {
+                unit_factor(o.get("unit")).map_or(x, |(f, angular)| {
+                    if angular {
+                        (x * f).to_degrees()
+                    } else {
+                        x * f
+                    }
+                })
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Return the conversion factor to SI units and a flag telling if
+/// the unit is angular.
+///
+/// `None` means that the value is already in degrees or meters.
+fn unit_factor(unit: Option<&Value>) -> Option<(f64, bool)> {
+    match unit? {
+        Value::String(s) if s == "radian" => Some((1., true)),
+        Value::Object(o) => {
+            let factor = o.get("conversion_factor").and_then(Value::as_f64)?;
+            match o.get("type").and_then(Value::as_str) {
+                Some("AngularUnit") => Some((factor, true)),
+                _ => Some((factor, false)),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Return the EPSG code of a datum
+fn epsg_code(datum: &Value) -> Option<u64> {
+    datum
+        .get("id")
+        .filter(|id| id.get("authority").and_then(Value::as_str) == Some("EPSG"))
+        .and_then(|id| id.get("code"))
+        .and_then(Value::as_u64)
+}
+
+/// Parse datum, ellipsoid and prime meridian of a geodetic CRS
+fn parse_geodetic(crs: &Value, defn: &mut Vec<String>) -> Result<()> {
+    let datum = crs
+        .get("datum")
+        .or_else(|| crs.get("datum_ensemble"))
+        .ok_or(invalid("Missing datum"))?;
+
+    let epsg_datum = epsg_code(datum).and_then(|code| EPSG_DATUMS.iter().find(|(c, _)| *c == code));
+
+    if let Some((_, name)) = epsg_datum {
+        defn.push(format!("+datum={name}"));
+    } else {
+        let ellps = datum.get("ellipsoid").ok_or(invalid("Missing ellipsoid"))?;
+        if let Some(r) = ellps.get("radius").and_then(value_of) {
+            defn.push(format!("+R={r}"));
+        } else {
+            let a = ellps
+                .get("semi_major_axis")
+                .and_then(value_of)
+                .ok_or(invalid("Missing semi major axis"))?;
+            defn.push(format!("+a={a}"));
+            if let Some(rf) = ellps.get("inverse_flattening").and_then(value_of) {
+                defn.push(format!("+rf={rf}"));
+            } else if let Some(b) = ellps.get("semi_minor_axis").and_then(value_of) {
+                defn.push(format!("+b={b}"));
+            } else {
+                return Err(invalid("Missing ellipsoid shape parameter"));
+            }
+        }
+    }
+
+    if let Some(pm) = datum
+        .get("prime_meridian")
+        .or_else(|| crs.get("prime_meridian"))
+        .and_then(|pm| pm.get("longitude"))
+        .and_then(value_of)
+        .filter(|pm| *pm != 0.)
+    {
+        defn.push(format!("+pm={pm}"));
+    }
+    Ok(())
+}
+
+/// Parse a projected CRS conversion
+fn parse_conversion(conversion: &Value, defn: &mut Vec<String>) -> Result<&'static Method> {
+    let method = conversion
+        .get("method")
+        .and_then(|m| m.get("name"))
+        .and_then(Value::as_str)
+        .ok_or(invalid("Missing conversion method"))?;
+
+    let method = find_method(method).ok_or(Error::ProjectionNotFound)?;
+    defn.push(method.proj.into());
+
+    let mut lat_0 = None;
+    let mut lat_ts = None;
+    if let Some(params) = conversion.get("parameters").and_then(Value::as_array) {
+        params.iter().try_for_each(|p| {
+            let name = p.get("name").and_then(Value::as_str).unwrap_or("");
+            let value = value_of(p).ok_or(invalid("Invalid parameter value"))?;
+            let (_, tok) = method
+                .params
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .ok_or(invalid("Unsupported conversion parameter"))?;
+            match *tok {
+                "lat_0" => lat_0 = Some(value),
+                "lat_ts" => lat_ts = Some(value),
+                _ => (),
+            }
+            defn.push(format!("+{tok}={value}"));
+            Ok::<_, Error>(())
+        })?;
+    }
+    match method.name {
+        LCC_1SP => {
+            if let Some(lat_0) = lat_0 {
+                defn.push(format!("+lat_1={lat_0}"));
+            }
+        }
+        POLAR_STEREO_B => {
+            let lat_ts = lat_ts.ok_or(invalid("Missing latitude of standard parallel"))?;
+            defn.push(
+                if lat_ts < 0. {
+                    "+lat_0=-90"
+                } else {
+                    "+lat_0=90"
+                }
+                .into(),
+            );
+        }
+        _ => (),
+    }
+    Ok(method)
+}
+
+/// Parse a transformation to WGS84 as a `towgs84` parameter
+fn parse_towgs84(transformation: &Value, defn: &mut Vec<String>) -> Result<()> {
+    let method = transformation
+        .get("method")
+        .and_then(|m| m.get("name"))
+        .and_then(Value::as_str)
+        .ok_or(invalid("Missing transformation method"))?;
+
+    // Coordinate frame rotations have the opposite sign
+    // of position vector rotations
+    let (count, sign) = if method.starts_with("Geocentric translations") {
+        (3, 1.)
+    } else if method.starts_with("Position Vector transformation") {
+        (7, 1.)
+    } else if method.starts_with("Coordinate Frame rotation") {
+        (7, -1.)
+    } else {
+        return Err(invalid("Unsupported transformation method"));
+    };
+
+    let params = transformation
+        .get("parameters")
+        .and_then(Value::as_array)
+        .ok_or(invalid("Missing transformation parameters"))?;
+
+    let values = TOWGS84_PARAMS[..count]
+        .iter()
+        .enumerate()
+        .map(|(i, (name, factor))| {
+            let p = params
+                .iter()
+                .find(|p| p.get("name").and_then(Value::as_str) == Some(name))
+                .ok_or(invalid("Missing transformation parameter"))?;
+            let x = p
+                .get("value")
+                .and_then(Value::as_f64)
+                .ok_or(invalid("Invalid parameter value"))?;
+            // Values without unit conversion factor are in
+            // metre, arc-second and parts per million
+            let x = match p.get("unit") {
+                Some(Value::Object(o)) => {
+                    let f = o
+                        .get("conversion_factor")
+                        .and_then(Value::as_f64)
+                        .ok_or(invalid("Invalid unit"))?;
+                    x * f / factor
+                }
+                _ => x,
+            };
+            Ok(if (3..6).contains(&i) { sign * x } else { x }.to_string())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    defn.push(format!("+towgs84={}", values.join(",")));
+    Ok(())
+}
+
+/// Parse linear units from a cartesian coordinate system
+fn parse_units(crs: &Value, defn: &mut Vec<String>) {
+    let unit = crs
+        .get("coordinate_system")
+        .and_then(|cs| cs.get("axis"))
+        .and_then(Value::as_array)
+        .and_then(|axis| axis.first())
+        .and_then(|axis| axis.get("unit"));
+    match unit {
+        Some(Value::String(s)) if s == "US survey foot" => defn.push("+units=us-ft".into()),
+        Some(Value::String(s)) if s == "foot" => defn.push("+units=ft".into()),
+        Some(u) => {
+            if let Some((f, false)) = unit_factor(Some(u)) {
+                defn.push(format!("+to_meter={f}"));
+            }
+        }
+        None => (),
+    }
+}
+
+/// Build a projstring from a PROJJSON value
+fn to_proj_string(crs: &Value) -> Result<String> {
+    // Use the proj4 definition if available
+    if let Some(s) = crs.get("proj4").and_then(Value::as_str) {
+        return Ok(s.into());
+    }
+
+    let mut defn = Vec::<String>::new();
+    match crs.get("type").and_then(Value::as_str) {
+        Some("GeographicCRS") => {
+            defn.push("+proj=longlat".into());
+            parse_geodetic(crs, &mut defn)?;
+        }
+        Some("GeodeticCRS") => {
+            defn.push("+proj=geocent".into());
+            parse_geodetic(crs, &mut defn)?;
+        }
+        Some("ProjectedCRS") => {
+            let conversion = crs.get("conversion").ok_or(invalid("Missing conversion"))?;
+            let method = parse_conversion(conversion, &mut defn)?;
+            if method.name != PSEUDO_MERCATOR {
+                let base_crs = crs.get("base_crs").ok_or(invalid("Missing base crs"))?;
+                parse_geodetic(base_crs, &mut defn)?;
+            }
+            parse_units(crs, &mut defn);
+        }
+        Some("BoundCRS") => {
+            let target_crs = crs.get("target_crs").ok_or(invalid("Missing target crs"))?;
+            if target_crs
+                .get("datum")
+                .or_else(|| target_crs.get("datum_ensemble"))
+                .and_then(epsg_code)
+                != Some(6326)
+            {
+                return Err(invalid("Target crs is not WGS84"));
+            }
+            let source_crs = crs.get("source_crs").ok_or(invalid("Missing source crs"))?;
+            defn.push(to_proj_string(source_crs)?);
+            parse_towgs84(
+                crs.get("transformation")
+                    .ok_or(invalid("Missing transformation"))?,
+                &mut defn,
+            )?;
+        }
+        _ => return Err(invalid("Unsupported CRS type")),
+    }
+    Ok(defn.join(" "))
+}
+
+// Parameters that are not part of the CRS definition
+const INFORMATIVE: [&str; 4] = ["no_defs", "type", "wktext", "title"];
+
+// Parameters described by the datum, the ellipsoid, the prime meridian
+// or the units
+#[rustfmt::skip]
+const GEODETIC: [&str; 21] = [
+    "ellps", "datum", "a", "b", "rf", "f", "es", "e", "R",
+    "R_A", "R_V", "R_a", "R_g", "R_h", "R_lat_a", "R_lat_g",
+    "towgs84", "nadgrids", "pm", "units", "to_meter",
+];
+
+/// Return true if the projection is the pseudo mercator
+/// used by web maps
+fn is_pseudo_mercator(p: &Proj, params: &ParamList) -> bool {
+    let ellps = p.ellipsoid();
+    ellps.is_sphere()
+        && ellps.a == 6378137.
+        && p.data().k0 == 1.
+        && params.get("nadgrids").and_then(|p| p.value) == Some("@null")
+}
+
+/// Return the conversion method of a projected CRS
+///
+/// Parameters read from `params` are those described by the method.
+fn method_of(p: &Proj, params: &ParamList) -> Result<&'static Method> {
+    let d = p.data();
+    let name = match p.projname() {
+        "tmerc" | "etmerc" => TRANSVERSE_MERCATOR,
+        "utm" => {
+            params.get("zone");
+            params.get("south");
+            TRANSVERSE_MERCATOR
+        }
+        "lcc" => {
+            let lat_1 = params.try_angular_value("lat_1")?.unwrap_or(0.);
+            let lat_2 = params.try_angular_value("lat_2")?.unwrap_or(lat_1);
+            if lat_1 == lat_2 && lat_1 == d.phi0 {
+                LCC_1SP
+            } else if d.k0 == 1. {
+                LCC_2SP
+            } else {
+                return Err(Error::ProjectionNotFound);
+            }
+        }
+        "merc" if is_pseudo_mercator(p, params) => {
+            params.get("lat_ts");
+            params.get("k");
+            PSEUDO_MERCATOR
+        }
+        "merc" if params.get("lat_ts").is_some() => MERCATOR_B,
+        "merc" => MERCATOR_A,
+        "laea" => LAEA,
+        "aea" => AEA,
+        "stere" if (d.phi0.abs() - FRAC_PI_2).abs() < EPS_10 => {
+            match params.try_angular_value("lat_ts")? {
+                None => POLAR_STEREO_A,
+                Some(lat_ts) if (lat_ts.abs() - FRAC_PI_2).abs() < EPS_10 => POLAR_STEREO_A,
+                Some(lat_ts) if lat_ts.signum() == d.phi0.signum() => {
+                    // The pole is given by the standard parallel
+                    params.get("lat_0");
+                    POLAR_STEREO_B
+                }
+                _ => return Err(Error::ProjectionNotFound),
+            }
+        }
+        "sterea" => OBLIQUE_STEREO,
+        "eqc" => EQC,
+        _ => return Err(Error::ProjectionNotFound),
+    };
+    Ok(find_method(name).unwrap())
+}
+
+/// Return the conversion of a projected CRS
+fn conversion(p: &Proj, params: &ParamList) -> Result<Value> {
+    let method = method_of(p, params)?;
+    let d = p.data();
+
+    let parameters = method
+        .params
+        .iter()
+        .map(|(name, tok)| {
+            let (value, unit) = match *tok {
+                "lat_0" => (d.phi0.to_degrees(), "degree"),
+                "lon_0" => (d.lam0.to_degrees(), "degree"),
+                "k" => {
+                    params.get("k_0").or_else(|| params.get("k0"));
+                    (d.k0, "unity")
+                }
+                "x_0" => (d.x0, "metre"),
+                "y_0" => (d.y0, "metre"),
+                // Defaults to lat_1
+                "lat_2" => match params.try_angular_value("lat_2")? {
+                    Some(lat_2) => (lat_2.to_degrees(), "degree"),
+                    None => (
+                        params
+                            .try_angular_value("lat_1")?
+                            .unwrap_or(0.)
+                            .to_degrees(),
+                        "degree",
+                    ),
+                },
+                tok => (
+                    params.try_angular_value(tok)?.unwrap_or(0.).to_degrees(),
+                    "degree",
+                ),
+            };
+            params.get(tok);
+            Ok(json!({ "name": name, "value": value, "unit": unit }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(json!({
+        "name": "unknown",
+        "method": { "name": method.name },
+        "parameters": parameters,
+    }))
+}
+
+/// WGS84 datum, as referenced by its EPSG code
+fn wgs84_datum() -> Value {
+    json!({
+        "type": "GeodeticReferenceFrame",
+        "name": "World Geodetic System 1984",
+        "ellipsoid": {
+            "name": "WGS 84",
+            "semi_major_axis": 6378137.,
+            "inverse_flattening": 298.257223563,
+        },
+        "id": { "authority": "EPSG", "code": 6326 },
+    })
+}
+
+/// Return the transformation to WGS84 of a datum shift
+fn towgs84_transformation(params: &DatumParams) -> Value {
+    let (method, values) = match *params {
+        DatumParams::ToWGS84_3(dx, dy, dz) => (GEOCENTRIC_TRANSLATIONS, vec![dx, dy, dz]),
+        DatumParams::ToWGS84_7(dx, dy, dz, rx, ry, rz, s) => (
+            POSITION_VECTOR,
+            vec![
+                dx,
+                dy,
+                dz,
+                rx / SEC_TO_RAD,
+                ry / SEC_TO_RAD,
+                rz / SEC_TO_RAD,
+                (s - 1.) * 1.0e6,
+            ],
+        ),
+        _ => (GEOCENTRIC_TRANSLATIONS, vec![0., 0., 0.]),
+    };
+
+    let parameters = TOWGS84_PARAMS
+        .iter()
+        .zip(values)
+        .map(|((name, factor), value)| {
+            let unit = match *factor {
+                1. => json!("metre"),
+                SEC_TO_RAD => json!({
+                    "type": "AngularUnit",
+                    "name": "arc-second",
+                    "conversion_factor": SEC_TO_RAD,
+                }),
+                _ => json!({
+                    "type": "ScaleUnit",
+                    "name": "parts per million",
+                    "conversion_factor": factor,
+                }),
+            };
+            json!({ "name": name, "value": value, "unit": unit })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "name": "Transformation to WGS84",
+        "method": { "name": method },
+        "parameters": parameters,
+    })
+}
+
+impl Proj {
+    /// Create projection from PROJJSON definition
+    ///
+    /// If the root object holds a `proj4` member, then it is used as
+    /// the projection definition. Otherwise the definition is built from
+    /// the `GeographicCRS`, `GeodeticCRS`, `ProjectedCRS` or `BoundCRS`
+    /// description.
+    ///
+    /// Requires feature *projjson*
+    pub fn from_projjson(s: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(s).map_err(|_| invalid("Invalid json document"))?;
+        Self::from_proj_string(&to_proj_string(&value)?)
+    }
+
+    /// Serialize projection as PROJJSON
+    ///
+    /// Conversions are described with their EPSG method and parameter
+    /// names, and `towgs84` datum shifts with a `BoundCRS`. The
+    /// projstring definition is also stored in the `proj4` member.
+    ///
+    /// Return [`Error::ProjectionNotFound`] if the projection has no
+    /// supported EPSG method, and [`Error::UnknownParameter`] for
+    /// parameters that cannot be described, as grid based datum shifts.
+    ///
+    /// Requires feature *projjson*
+    pub fn to_projjson(&self) -> Result<String> {
+        let d = self.data();
+        let ellps = self.ellipsoid();
+        let params = projstring::parse(self.definition())?;
+        params.get("proj");
+        GEODETIC.iter().for_each(|name| {
+            params.get(name);
+        });
+
+        // Datums with an EPSG code are described by reference,
+        // other datum shifts by a bound CRS
+        let epsg_datum = self
+            .datum_name()
+            .and_then(|name| EPSG_DATUMS.iter().find(|(_, n)| *n == name));
+
+        let ellipsoid = if ellps.is_sphere() {
+            json!({ "name": "unknown", "radius": ellps.a })
+        } else {
+            json!({
+                "name": "unknown",
+                "semi_major_axis": ellps.a,
+                "inverse_flattening": ellps.a / (ellps.a - ellps.b),
+            })
+        };
+
+        let mut datum = json!({
+            "type": "GeodeticReferenceFrame",
+            "name": "unknown",
+            "ellipsoid": ellipsoid,
+            "prime_meridian": {
+                "name": "unknown",
+                "longitude": d.from_greenwich.to_degrees(),
+            },
+        });
+        if let Some((code, name)) = epsg_datum {
+            datum["name"] = json!(name);
+            datum["id"] = json!({ "authority": "EPSG", "code": code });
+        }
+
+        let geographic = |datum: &Value| {
+            json!({
+                "type": "GeographicCRS",
+                "name": "unknown",
+                "datum": datum,
+                "coordinate_system": {
+                    "subtype": "ellipsoidal",
+                    "axis": [
+                        { "name": "Longitude", "abbreviation": "lon", "direction": "east", "unit": "degree" },
+                        { "name": "Latitude", "abbreviation": "lat", "direction": "north", "unit": "degree" },
+                    ],
+                },
+            })
+        };
+
+        let mut pseudo_mercator = false;
+        let mut crs = match self.projection_type() {
+            ProjType::Latlong => geographic(&datum),
+            ProjType::Geocentric => json!({
+                "type": "GeodeticCRS",
+                "name": "unknown",
+                "datum": datum,
+                "coordinate_system": {
+                    "subtype": "Cartesian",
+                    "axis": [
+                        { "name": "Geocentric X", "abbreviation": "X", "direction": "geocentricX", "unit": "metre" },
+                        { "name": "Geocentric Y", "abbreviation": "Y", "direction": "geocentricY", "unit": "metre" },
+                        { "name": "Geocentric Z", "abbreviation": "Z", "direction": "geocentricZ", "unit": "metre" },
+                    ],
+                },
+            }),
+            ProjType::Other => {
+                let conversion = conversion(self, &params)?;
+                pseudo_mercator = conversion["method"]["name"] == PSEUDO_MERCATOR;
+                let unit = json!({
+                    "type": "LinearUnit",
+                    "name": self.units(),
+                    "conversion_factor": d.to_meter,
+                });
+                json!({
+                    "type": "ProjectedCRS",
+                    "name": "unknown",
+                    // The pseudo mercator sphere is defined by the method
+                    "base_crs": if pseudo_mercator {
+                        geographic(&wgs84_datum())
+                    } else {
+                        geographic(&datum)
+                    },
+                    "conversion": conversion,
+                    "coordinate_system": {
+                        "subtype": "Cartesian",
+                        "axis": [
+                            { "name": "Easting", "abbreviation": "E", "direction": "east", "unit": unit },
+                            { "name": "Northing", "abbreviation": "N", "direction": "north", "unit": unit },
+                        ],
+                    },
+                })
+            }
+        };
+
+        if let Some(p) = params.unused().find(|p| !INFORMATIVE.contains(&p.name)) {
+            return Err(Error::UnknownParameter(p.name.into()));
+        }
+
+        match self.datum().params() {
+            _ if pseudo_mercator || epsg_datum.is_some() => (),
+            DatumParams::NoDatum => (),
+            DatumParams::NadGrids(_) => return Err(Error::UnknownParameter("nadgrids".into())),
+            params => {
+                crs = json!({
+                    "type": "BoundCRS",
+                    "source_crs": crs,
+                    "target_crs": geographic(&wgs84_datum()),
+                    "transformation": towgs84_transformation(params),
+                });
+            }
+        }
+
+        if let Value::Object(map) = &mut crs {
+            let mut root = Map::new();
+            root.insert("$schema".into(), PROJJSON_SCHEMA.into());
+            root.append(map);
+            root.insert("proj4".into(), self.definition().into());
+            crs = Value::Object(root);
+        }
+
+        serde_json::to_string(&crs).map_err(|_| invalid("Serialization failed"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::consts::FRAC_PI_2;
+    use crate::transform::transform;
+    use approx::assert_abs_diff_eq;
+
+    // Output of `projinfo -o PROJJSON EPSG:4326`
+    const EPSG_4326: &str = r#"{
+      "$schema": "https://proj.org/schemas/v0.7/projjson.schema.json",
+      "type": "GeographicCRS",
+      "name": "WGS 84",
+      "datum_ensemble": {
+        "name": "World Geodetic System 1984 ensemble",
+        "members": [
+          { "name": "World Geodetic System 1984 (Transit)", "id": { "authority": "EPSG", "code": 1166 } },
+          { "name": "World Geodetic System 1984 (G730)", "id": { "authority": "EPSG", "code": 1152 } }
+        ],
+        "ellipsoid": {
+          "name": "WGS 84",
+          "semi_major_axis": 6378137,
+          "inverse_flattening": 298.257223563
+        },
+        "accuracy": "2.0",
+        "id": { "authority": "EPSG", "code": 6326 }
+      },
+      "coordinate_system": {
+        "subtype": "ellipsoidal",
+        "axis": [
+          { "name": "Geodetic latitude", "abbreviation": "Lat", "direction": "north", "unit": "degree" },
+          { "name": "Geodetic longitude", "abbreviation": "Lon", "direction": "east", "unit": "degree" }
+        ]
+      },
+      "scope": "Horizontal component of 3D system.",
+      "area": "World.",
+      "bbox": { "south_latitude": -90, "west_longitude": -180, "north_latitude": 90, "east_longitude": 180 },
+      "id": { "authority": "EPSG", "code": 4326 }
+    }"#;
+
+    const UTM_31N: &str = r#"{
+      "type": "ProjectedCRS",
+      "name": "WGS 84 / UTM zone 31N",
+      "base_crs": {
+        "name": "WGS 84",
+        "datum": {
+          "type": "GeodeticReferenceFrame",
+          "name": "World Geodetic System 1984",
+          "ellipsoid": { "name": "WGS 84", "semi_major_axis": 6378137, "inverse_flattening": 298.257223563 }
+        }
+      },
+      "conversion": {
+        "name": "UTM zone 31N",
+        "method": { "name": "Transverse Mercator" },
+        "parameters": [
+          { "name": "Latitude of natural origin", "value": 0, "unit": "degree" },
+          { "name": "Longitude of natural origin", "value": 3, "unit": "degree" },
+          { "name": "Scale factor at natural origin", "value": 0.9996, "unit": "unity" },
+          { "name": "False easting", "value": 500000, "unit": "metre" },
+          { "name": "False northing", "value": 0, "unit": "metre" }
+        ]
+      },
+      "coordinate_system": {
+        "subtype": "Cartesian",
+        "axis": [
+          { "name": "Easting", "abbreviation": "E", "direction": "east", "unit": "metre" },
+          { "name": "Northing", "abbreviation": "N", "direction": "north", "unit": "metre" }
+        ]
+      }
+    }"#;
+
+    #[test]
+    fn projjson_epsg_4326() {
+        let p = Proj::from_projjson(EPSG_4326).unwrap();
+        assert!(p.is_latlong());
+        assert_eq!(p.definition(), "+proj=longlat +datum=WGS84");
+
+        let wgs84 = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+        assert!(p.datum().is_identical_to(wgs84.datum()));
+    }
+
+    #[test]
+    fn projjson_projected() {
+        let src = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+        let dst = Proj::from_projjson(UTM_31N).unwrap();
+        let utm = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+
+        let mut p1 = (2.0f64.to_radians(), 45.0f64.to_radians(), 0.);
+        let mut p2 = p1;
+        transform(&src, &dst, &mut p1).unwrap();
+        transform(&src, &utm, &mut p2).unwrap();
+        assert_abs_diff_eq!(p1.0, p2.0, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(p1.1, p2.1, epsilon = 1.0e-6);
+    }
+
+    // Read back the PROJJSON output without the `proj4` member
+    fn roundtrip(p: &Proj) -> (Value, Proj) {
+        let mut value: Value = serde_json::from_str(&p.to_projjson().unwrap()).unwrap();
+        value.as_object_mut().unwrap().remove("proj4");
+        let p2 = Proj::from_projjson(&value.to_string()).unwrap();
+        (value, p2)
+    }
+
+    #[test]
+    fn projjson_roundtrip() {
+        let p = Proj::from_proj_string("+proj=lcc +lat_1=49 +lat_2=44 +lat_0=46.5 +lon_0=3 +x_0=700000 +y_0=6600000 +ellps=GRS80 +units=m").unwrap();
+        let s = p.to_projjson().unwrap();
+        let value: Value = serde_json::from_str(&s).unwrap();
+        assert_eq!(value["type"], "ProjectedCRS");
+        assert_eq!(
+            value["conversion"]["method"]["name"],
+            "Lambert Conic Conformal (2SP)"
+        );
+
+        let p2 = Proj::from_projjson(&s).unwrap();
+        assert_eq!(p2.definition(), p.definition());
+
+        let value: Value = serde_json::from_str(
+            &Proj::from_projjson(EPSG_4326)
+                .unwrap()
+                .to_projjson()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(value["type"], "GeographicCRS");
+        assert_eq!(value["datum"]["ellipsoid"]["semi_major_axis"], 6378137.0);
+        assert_eq!(value["datum"]["id"]["code"], 6326);
+    }
+
+    #[test]
+    fn projjson_methods_roundtrip() {
+        #[rustfmt::skip]
+        let inputs = [
+            ("Transverse Mercator", "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000 +ellps=airy", (-1., 51.)),
+            ("Transverse Mercator", "+proj=utm +zone=33 +south +ellps=GRS80", (14., -30.)),
+            ("Lambert Conic Conformal (2SP)", "+proj=lcc +lat_1=49 +lat_2=44 +lat_0=46.5 +lon_0=3 +x_0=700000 +y_0=6600000 +ellps=GRS80", (2., 47.)),
+            ("Lambert Conic Conformal (1SP)", "+proj=lcc +lat_1=18 +lat_0=18 +lon_0=-77 +k=0.9995 +x_0=250000 +y_0=150000 +ellps=clrk66", (-76., 17.)),
+            ("Mercator (variant A)", "+proj=merc +lon_0=110 +k=0.997 +x_0=3900000 +y_0=900000 +ellps=bessel", (120., -3.)),
+            ("Mercator (variant B)", "+proj=merc +lat_ts=41 +lon_0=51 +x_0=0 +y_0=0 +ellps=krass", (53., 38.)),
+            ("Popular Visualisation Pseudo Mercator", "+proj=merc +a=6378137 +b=6378137 +lat_ts=0 +lon_0=0 +x_0=0 +y_0=0 +k=1 +units=m +nadgrids=@null +wktext +no_defs", (2., 45.)),
+            ("Lambert Azimuthal Equal Area", "+proj=laea +lat_0=52 +lon_0=10 +x_0=4321000 +y_0=3210000 +ellps=GRS80 +units=m", (5., 50.)),
+            ("Albers Equal Area", "+proj=aea +lat_1=29.5 +lat_2=45.5 +lat_0=23 +lon_0=-96 +x_0=0 +y_0=0 +ellps=clrk66", (-90., 35.)),
+            ("Polar Stereographic (variant A)", "+proj=stere +lat_0=90 +lon_0=0 +k=0.994 +x_0=2000000 +y_0=2000000 +ellps=WGS84", (10., 80.)),
+            ("Polar Stereographic (variant B)", "+proj=stere +lat_0=-90 +lat_ts=-71 +lon_0=0 +x_0=0 +y_0=0 +ellps=WGS84", (10., -80.)),
+            ("Oblique Stereographic", "+proj=sterea +lat_0=52.15616055555555 +lon_0=5.38763888888889 +k=0.9999079 +x_0=155000 +y_0=463000 +ellps=bessel", (6., 52.)),
+            ("Equidistant Cylindrical", "+proj=eqc +lat_ts=30 +lat_0=10 +lon_0=-90 +x_0=100 +y_0=200 +ellps=WGS84", (-80., 40.)),
+        ];
+
+        // Every method is checked
+        assert!(METHODS
+            .iter()
+            .all(|m| inputs.iter().any(|(name, _, _)| *name == m.name)));
+
+        let latlong = Proj::from_proj_string("+proj=longlat +ellps=WGS84").unwrap();
+        for (method, defn, (lon, lat)) in inputs {
+            let p = Proj::from_proj_string(defn).unwrap();
+            let (value, p2) = roundtrip(&p);
+            assert_eq!(value["conversion"]["method"]["name"], method, "{defn}");
+
+            let mut pt = (f64::to_radians(lon), f64::to_radians(lat), 0.);
+            let mut pt2 = pt;
+            transform(&latlong, &p, &mut pt).unwrap();
+            transform(&latlong, &p2, &mut pt2).unwrap();
+            assert!((pt.0 - pt2.0).abs() < 1.0e-6, "{defn}");
+            assert!((pt.1 - pt2.1).abs() < 1.0e-6, "{defn}");
+        }
+    }
+
+    #[test]
+    fn projjson_datum_shift_roundtrip() {
+        let wgs84 = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+        for defn in [
+            "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000 +ellps=airy +towgs84=446.448,-125.157,542.06,0.15,0.247,0.842,-20.489",
+            "+proj=longlat +ellps=clrk66 +towgs84=-9,161,175",
+            "+proj=longlat +ellps=GRS80 +towgs84=0,0,0",
+        ] {
+            let p = Proj::from_proj_string(defn).unwrap();
+            let (value, p2) = roundtrip(&p);
+            assert_eq!(value["type"], "BoundCRS", "{defn}");
+            assert!(p2.datum().is_identical_to(p.datum()), "{defn}");
+
+            let mut pt = (f64::to_radians(-1.), f64::to_radians(51.), 0.);
+            let mut pt2 = pt;
+            transform(&wgs84, &p, &mut pt).unwrap();
+            transform(&wgs84, &p2, &mut pt2).unwrap();
+            assert_abs_diff_eq!(pt.0, pt2.0, epsilon = 1.0e-6);
+            assert_abs_diff_eq!(pt.1, pt2.1, epsilon = 1.0e-6);
+        }
+
+        // Named datums are referenced by their EPSG code
+        let p = Proj::from_proj_string("+proj=longlat +datum=OSGB36").unwrap();
+        let (value, p2) = roundtrip(&p);
+        assert_eq!(value["type"], "GeographicCRS");
+        assert_eq!(value["datum"]["id"]["code"], 6277);
+        assert!(p2.datum().is_identical_to(p.datum()));
+    }
+
+    #[test]
+    fn projjson_conversion_parameters() {
+        let conversion = |method: &str, params: &str| {
+            format!(
+                r#"{{
+                  "type": "ProjectedCRS",
+                  "base_crs": {{ "datum": {{ "id": {{ "authority": "EPSG", "code": 6326 }} }} }},
+                  "conversion": {{ "method": {{ "name": "{method}" }}, "parameters": [{params}] }}
+                }}"#
+            )
+        };
+
+        // Standard parallel of the Mercator (variant B)
+        let p = Proj::from_projjson(&conversion(
+            "Mercator (variant B)",
+            r#"{ "name": "Latitude of 1st standard parallel", "value": 41, "unit": "degree" }"#,
+        ))
+        .unwrap();
+        assert_eq!(p.definition(), "+proj=merc +lat_ts=41 +datum=WGS84");
+
+        // Pole of the Polar Stereographic (variant B)
+        let p = Proj::from_projjson(&conversion(
+            "Polar Stereographic (variant B)",
+            r#"{ "name": "Latitude of standard parallel", "value": -71, "unit": "degree" }"#,
+        ))
+        .unwrap();
+        assert_abs_diff_eq!(p.data().phi0, -FRAC_PI_2);
+
+        // Unsupported methods and parameters
+        assert!(matches!(
+            Proj::from_projjson(&conversion("Hotine Oblique Mercator (variant B)", "")),
+            Err(Error::ProjectionNotFound)
+        ));
+        assert!(matches!(
+            Proj::from_projjson(&conversion(
+                "Mercator (variant B)",
+                r#"{ "name": "Scale factor at natural origin", "value": 0.99 }"#
+            )),
+            Err(Error::InvalidProjJson(_))
+        ));
+    }
+
+    #[test]
+    fn projjson_unsupported_definition() {
+        for (defn, err) in [
+            ("+proj=ocea +lat_1=0.5 +lat_2=2", "projection"),
+            ("+proj=lcc +lat_1=49 +lat_2=44 +k=0.99", "projection"),
+            ("+proj=tmerc +lat_ts=10", "lat_ts"),
+            ("+proj=longlat +ellps=GRS80 +nadgrids=@null", "nadgrids"),
+            ("+proj=longlat +ellps=GRS80 +axis=neu", "axis"),
+        ] {
+            let p = Proj::from_proj_string(defn).unwrap();
+            match p.to_projjson() {
+                Err(Error::ProjectionNotFound) => assert_eq!(err, "projection", "{defn}"),
+                Err(Error::UnknownParameter(name)) => assert_eq!(name, err, "{defn}"),
+                res => panic!("{defn}: {res:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn projjson_invalid() {
+        assert!(Proj::from_projjson("{").is_err());
+        assert!(matches!(
+            Proj::from_projjson(r#"{ "type": "VerticalCRS" }"#),
+            Err(Error::InvalidProjJson(_))
+        ));
+    }
+}
@@ -10,7 +10,7 @@
 //!
 //! <ident> ::= [_0-9a_Z]+
 //!
-//! <value> ::= " { <word> }... " | <word>
+//! <value> ::= " { <word> }... " | ' { <word> }... ' | <word>
 //!
 //! <word> ::= [^\s]+
 //! ```
@@ -55,20 +55,22 @@ mod tokenizer {
     }
 
     /// Get the next quoted or unquoted token from the input string
+    ///
+    /// Values may be quoted with either double or single quotes.
     pub(super) fn unquote_next(s: &str) -> Result<(&str, &str)> {
         let s = s.trim_start();
-        if s.starts_with('\"') {
+        if let Some(q) = s.chars().next().filter(|c| *c == '\"' || *c == '\'') {
             // Check if string part is terminated by a quote,
             // if not, continue with the next part.
             // Inner quotes not separated by a whitespace is left
             // as part of the token.
-            let s = unsafe { s.split_once('\"').unwrap_unchecked().1 };
+            let s = &s[q.len_utf8()..];
             match s
                 .split_inclusive(|c: char| c.is_whitespace())
                 .try_fold(0usize, |len, s| {
                     let offset = s.len();
                     let s = s.trim_end();
-                    if s.ends_with('\"') {
+                    if s.ends_with(q) {
                         ControlFlow::Break(len + s.len() - 1)
                     } else {
                         ControlFlow::Continue(len + offset)
@@ -157,6 +159,33 @@ mod tests {
         assert!(tokenizer::unquote_next(r).is_err());
     }
 
+    #[test]
+    fn projstring_unquote_single() {
+        let s = r#"'foo bar' 'it"s'  'baz "#;
+        let (s, r) = tokenizer::unquote_next(s).unwrap();
+        assert_eq!((s, r), ("foo bar", r#" 'it"s'  'baz "#));
+        let (s, r) = tokenizer::unquote_next(r).unwrap();
+        assert_eq!((s, r), (r#"it"s"#, "  'baz "));
+
+        assert!(tokenizer::unquote_next(r).is_err());
+    }
+
+    #[test]
+    fn projstring_quoted_values() {
+        let params = parse(r#"+proj=longlat +title="My CRS" +ellps=WGS84"#).unwrap();
+        assert_eq!(params.get("title").unwrap().value, Some("My CRS"));
+        assert_eq!(params.get("ellps").unwrap().value, Some("WGS84"));
+
+        let params = parse("+proj=longlat +title='My other CRS' +ellps=WGS84").unwrap();
+        assert_eq!(params.get("title").unwrap().value, Some("My other CRS"));
+        assert_eq!(params.get("ellps").unwrap().value, Some("WGS84"));
+
+        assert!(matches!(
+            parse("+proj=longlat +title='My CRS +ellps=WGS84"),
+            Err(Error::InputStringError("Unterminated quoted string"))
+        ));
+    }
+
     #[test]
     fn projstring_invalid_parameter_name() {
         let s = "+pro@j=geocent";
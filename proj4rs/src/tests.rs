@@ -1,7 +1,6 @@
 //!
 //! Unit tests
 //!
-use env_logger;
 use std::sync::Once;
 
 static INIT: Once = Once::new();
@@ -14,9 +13,12 @@ pub fn setup() {
 }
 
 pub(crate) mod utils {
+    use crate::math::adjlon;
     use crate::proj::{Proj, ProjData};
     use approx::assert_abs_diff_eq;
 
+    type TestInputs = [((f64, f64, f64), (f64, f64, f64))];
+
     pub(crate) fn scale(d: &ProjData, xyz: (f64, f64, f64)) -> (f64, f64, f64) {
         (xyz.0 * d.ellps.a + d.x0, xyz.1 * d.ellps.a + d.y0, xyz.2)
     }
@@ -37,11 +39,7 @@ pub(crate) mod utils {
         (lpz.0.to_radians(), lpz.1.to_radians(), lpz.2)
     }
 
-    pub(crate) fn test_proj_forward(
-        p: &Proj,
-        inputs: &[((f64, f64, f64), (f64, f64, f64))],
-        prec: f64,
-    ) {
+    pub(crate) fn test_proj_forward(p: &Proj, inputs: &TestInputs, prec: f64) {
         let d = p.data();
         inputs.iter().for_each(|(input, expect)| {
             let (lam, phi, z) = to_rad(*input);
@@ -53,16 +51,12 @@ pub(crate) mod utils {
         })
     }
 
-    pub(crate) fn test_proj_inverse(
-        p: &Proj,
-        inputs: &[((f64, f64, f64), (f64, f64, f64))],
-        prec: f64,
-    ) {
+    pub(crate) fn test_proj_inverse(p: &Proj, inputs: &TestInputs, prec: f64) {
         let d = p.data();
         inputs.iter().for_each(|(expect, input)| {
             let (x, y, z) = descale(d, *input);
             let (lam, phi, z) = p.projection().inverse(x, y, z).unwrap();
-            let out = to_deg(lam + d.lam0, phi, z);
+            let out = to_deg(adjlon(lam + d.lam0), phi, z);
             assert_abs_diff_eq!(out.0, expect.0, epsilon = prec);
             assert_abs_diff_eq!(out.1, expect.1, epsilon = prec);
             assert_abs_diff_eq!(out.2, expect.2, epsilon = prec);
@@ -75,6 +69,7 @@ use crate::transform::transform;
 use approx::assert_abs_diff_eq;
 
 #[test]
+#[allow(clippy::excessive_precision)]
 fn test_transform_array() {
     let mut data: Vec<(f64, f64, f64)> = (1..=1_000)
         .map(|_| (2.0f64.to_radians(), 1.0f64.to_radians(), 0.0f64))
@@ -92,6 +87,57 @@ fn test_transform_array() {
     });
 }
 
+#[test]
+#[allow(clippy::excessive_precision)]
+fn test_transform_2d_tuples() {
+    let from = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+    let to = Proj::from_proj_string("+proj=etmerc +ellps=GRS80").unwrap();
+
+    // z is implicitly 0
+    let mut point = (2.0f64.to_radians(), 1.0f64.to_radians());
+    transform(&from, &to, &mut point).unwrap();
+    assert_abs_diff_eq!(point.0, 222650.79679758527, epsilon = 1.0e-10);
+    assert_abs_diff_eq!(point.1, 110642.22941193319, epsilon = 1.0e-10);
+
+    let mut points = vec![(2.0f64.to_radians(), 1.0f64.to_radians()); 10];
+    transform(&from, &to, &mut points).unwrap();
+    assert!(points.iter().all(|p| *p == point));
+}
+
+#[test]
+#[allow(clippy::excessive_precision)]
+fn test_transform_inplace_interleaved() {
+    use crate::errors::Error;
+    use crate::transform::{transform_inplace_2d, transform_inplace_3d};
+
+    let from = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+    let to = Proj::from_proj_string("+proj=etmerc +ellps=GRS80").unwrap();
+
+    let (lon, lat) = (2.0f64.to_radians(), 1.0f64.to_radians());
+
+    let mut xy = [lon, lat, lon, lat];
+    transform_inplace_2d(&from, &to, &mut xy).unwrap();
+    xy.chunks_exact(2).for_each(|c| {
+        assert_abs_diff_eq!(c[0], 222650.79679758527, epsilon = 1.0e-10);
+        assert_abs_diff_eq!(c[1], 110642.22941193319, epsilon = 1.0e-10);
+    });
+
+    let mut xyz = [lon, lat, 10., lon, lat, 20.];
+    transform_inplace_3d(&from, &to, &mut xyz).unwrap();
+    assert_abs_diff_eq!(xyz[3], 222650.79679758527, epsilon = 1.0e-10);
+    assert_abs_diff_eq!(xyz[4], 110642.22941193319, epsilon = 1.0e-10);
+    assert_eq!((xyz[2], xyz[5]), (10., 20.));
+
+    assert!(matches!(
+        transform_inplace_2d(&from, &to, &mut [lon, lat, lon]),
+        Err(Error::InvalidNumberOfCoordinates)
+    ));
+    assert!(matches!(
+        transform_inplace_3d(&from, &to, &mut [lon, lat]),
+        Err(Error::InvalidNumberOfCoordinates)
+    ));
+}
+
 #[test]
 fn test_utm33_grs80() {
     let from = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
@@ -108,3 +154,670 @@ fn test_utm33_grs80() {
     assert_abs_diff_eq!(v1[0].0, 391027.67777461524, epsilon = 1.0e-10);
     assert_abs_diff_eq!(v1[0].1, 5820089.724404063, epsilon = 1.0e-10);
 }
+
+#[test]
+fn test_geoc_tmerc_high_latitude() {
+    // With +geoc, geodetic latitudes are converted to geocentric
+    // latitudes before the forward projection (and back after the
+    // inverse projection).
+    let from = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+    let geoc = Proj::from_proj_string("+proj=tmerc +geoc +ellps=GRS80").unwrap();
+    let tmerc = Proj::from_proj_string("+proj=tmerc +ellps=GRS80").unwrap();
+
+    let (lam, phi) = (10.0f64.to_radians(), 75.0f64.to_radians());
+
+    // Reference values computed with mpmath from the Krüger series,
+    // the geocentric latitude of 75° on GRS80 being 74.9035...°
+    let mut pt = (lam, phi, 0.);
+    transform(&from, &geoc, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, 289558.6244724118, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.1, 8340634.532069834, epsilon = 1.0e-6);
+
+    let mut plain = (lam, phi, 0.);
+    transform(&from, &tmerc, &mut plain).unwrap();
+    assert_abs_diff_eq!(plain.0, 287748.8369060231, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(plain.1, 8351262.808828361, epsilon = 1.0e-6);
+
+    // Round trip
+    transform(&geoc, &from, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, lam, epsilon = 1.0e-12);
+    assert_abs_diff_eq!(pt.1, phi, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_datum_shift_skip() {
+    let pt = (2.0f64.to_radians(), 45.0f64.to_radians(), 0.);
+    let shifted = |from: &str, to: &str| {
+        let from = Proj::from_proj_string(from).unwrap();
+        let to = Proj::from_proj_string(to).unwrap();
+        let mut out = pt;
+        transform(&from, &to, &mut out).unwrap();
+        (out.0 - pt.0).abs() > 1.0e-10 || (out.1 - pt.1).abs() > 1.0e-10
+    };
+
+    // No datum on both sides with the same ellipsoid
+    assert!(!shifted(
+        "+proj=latlong +ellps=airy",
+        "+proj=latlong +ellps=airy"
+    ));
+    // No datum on one side: ellipsoids are not compared
+    assert!(!shifted(
+        "+proj=latlong +ellps=airy",
+        "+proj=latlong +datum=WGS84"
+    ));
+    assert!(!shifted(
+        "+proj=latlong +ellps=airy",
+        "+proj=latlong +ellps=GRS80"
+    ));
+    // Identical datums
+    assert!(!shifted(
+        "+proj=latlong +ellps=airy +towgs84=446.448,-125.157,542.06",
+        "+proj=latlong +ellps=airy +towgs84=446.448,-125.157,542.06"
+    ));
+    // GRS80 and WGS84 are considered identical
+    assert!(!shifted(
+        "+proj=latlong +ellps=GRS80 +towgs84=0,0,0",
+        "+proj=latlong +datum=WGS84"
+    ));
+    // Only towgs84 differs
+    assert!(shifted(
+        "+proj=latlong +ellps=airy +towgs84=446.448,-125.157,542.06",
+        "+proj=latlong +ellps=airy +towgs84=0,0,0"
+    ));
+    assert!(shifted(
+        "+proj=latlong +ellps=airy +towgs84=446.448,-125.157,542.06",
+        "+proj=latlong +datum=WGS84"
+    ));
+}
+
+#[test]
+fn test_over_antimeridian() {
+    let line = || -> Vec<(f64, f64)> {
+        [179.0f64, 180.0, 181.0]
+            .iter()
+            .map(|lon| (lon.to_radians(), 10.0f64.to_radians()))
+            .collect()
+    };
+
+    let src = Proj::from_proj_string("+proj=latlong +ellps=WGS84 +over").unwrap();
+
+    // Over ranging: longitudes accumulate past 180
+    let dst = Proj::from_proj_string("+proj=latlong +ellps=WGS84 +over").unwrap();
+    let mut pts = line();
+    transform(&src, &dst, pts.as_mut_slice()).unwrap();
+    assert_abs_diff_eq!(pts[2].0.to_degrees(), 181.0, epsilon = 1.0e-10);
+
+    // Longitudes are wrapped to [-180, 180]
+    let dst = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+    let mut pts = line();
+    transform(&src, &dst, pts.as_mut_slice()).unwrap();
+    assert_abs_diff_eq!(pts[0].0.to_degrees(), 179.0, epsilon = 1.0e-10);
+    assert_abs_diff_eq!(pts[1].0.to_degrees().abs(), 180.0, epsilon = 1.0e-10);
+    assert_abs_diff_eq!(pts[2].0.to_degrees(), -179.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn test_australian_datums() {
+    let wgs84 = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+    // Sydney
+    let sydney = (151.2093f64.to_radians(), (-33.8688f64).to_radians(), 0.);
+
+    // No shift from GDA2020
+    let src = Proj::from_proj_string("+proj=latlong +datum=GDA2020").unwrap();
+    let mut pt = sydney;
+    transform(&src, &wgs84, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, sydney.0, epsilon = 1.0e-12);
+    assert_abs_diff_eq!(pt.1, sydney.1, epsilon = 1.0e-12);
+    assert_abs_diff_eq!(pt.2, 0., epsilon = 1.0e-6);
+
+    let src = Proj::from_proj_string("+proj=latlong +datum=AGD66").unwrap();
+    let mut pt = sydney;
+    transform(&src, &wgs84, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0.to_degrees(), 151.21045691764771, epsilon = 1.0e-9);
+    assert_abs_diff_eq!(pt.1.to_degrees(), -33.86722015750654, epsilon = 1.0e-9);
+    assert_abs_diff_eq!(pt.2, 9.250999049171152, epsilon = 1.0e-4);
+
+    for datum in ["GDA94", "AGD84"] {
+        assert!(Proj::from_proj_string(&format!("+proj=latlong +datum={datum}")).is_ok());
+    }
+}
+
+#[test]
+fn test_same_crs() {
+    let src = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+    let dst = Proj::from_proj_string("+datum=WGS84 +zone=31 +proj=utm").unwrap();
+    assert!(src.is_same_crs(&dst));
+
+    // Points are left untouched, even out of the projection domain
+    let mut pt = (1.0e9, -1.0e9, 0.);
+    transform(&src, &dst, &mut pt).unwrap();
+    assert_eq!(pt, (1.0e9, -1.0e9, 0.));
+
+    // A projection is always the same CRS as itself
+    let p = Proj::from_proj_string("+proj=latlong +ellps=clrk66 +nadgrids=@null").unwrap();
+    assert!(p.is_same_crs(&p));
+
+    let other = Proj::from_proj_string("+proj=utm +zone=32 +datum=WGS84").unwrap();
+    assert!(!src.is_same_crs(&other));
+}
+
+#[test]
+fn test_same_crs_checks() {
+    use crate::errors::Error;
+    use crate::math::consts::PI;
+
+    // Geographic longitudes are wrapped
+    let wgs84 = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+    let equiv = Proj::from_proj_string("+ellps=WGS84 +proj=longlat +towgs84=0,0,0").unwrap();
+    assert!(wgs84.is_same_crs(&equiv));
+    let mut pt = (4., 0.5, 0.);
+    transform(&wgs84, &equiv, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, 4. - 2. * PI, epsilon = 1.0e-15);
+    assert_eq!(pt.1, 0.5);
+
+    // as for any geographic destination
+    let mut pt = (100., 1., 0.);
+    transform(&wgs84, &wgs84, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, 100. - 32. * PI, epsilon = 1.0e-13);
+
+    // unless over-ranging is allowed
+    let over = Proj::from_proj_string("+proj=latlong +datum=WGS84 +over").unwrap();
+    let mut pt = (4., 0.5, 0.);
+    transform(&over, &over, &mut pt).unwrap();
+    assert_eq!(pt, (4., 0.5, 0.));
+
+    // Projections without inverse
+    let igh = Proj::from_proj_string("+proj=igh +ellps=WGS84").unwrap();
+    let mut pt = (0., 0., 0.);
+    assert!(matches!(
+        transform(&igh, &igh, &mut pt),
+        Err(Error::NoInverseProjectionDefined)
+    ));
+}
+
+#[test]
+fn test_trivial_datum_transform() {
+    use crate::transform::is_trivial_datum_transform;
+
+    let utm31 = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+    let utm32 = Proj::from_proj_string("+proj=utm +zone=32 +datum=WGS84").unwrap();
+    assert!(is_trivial_datum_transform(&utm31, &utm32));
+
+    // GRS80 and WGS84 are considered identical
+    let grs80 = Proj::from_proj_string("+proj=utm +zone=32 +ellps=GRS80 +towgs84=0,0,0").unwrap();
+    assert!(is_trivial_datum_transform(&utm31, &grs80));
+
+    // No datum on either side
+    let nodatum = Proj::from_proj_string("+proj=utm +zone=32 +ellps=intl").unwrap();
+    assert!(is_trivial_datum_transform(&utm31, &nodatum));
+
+    let shifted =
+        Proj::from_proj_string("+proj=utm +zone=32 +ellps=intl +towgs84=-87,-98,-121").unwrap();
+    assert!(!is_trivial_datum_transform(&utm31, &shifted));
+
+    // Same results with a trivial datum transform
+    let mut pt = (500_000., 4_500_000., 0.);
+    transform(&utm31, &utm32, &mut pt).unwrap();
+    let mut expected = (500_000., 4_500_000., 0.);
+    let latlong = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+    transform(&utm31, &latlong, &mut expected).unwrap();
+    transform(&latlong, &utm32, &mut expected).unwrap();
+    assert_abs_diff_eq!(pt.0, expected.0, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.1, expected.1, epsilon = 1.0e-6);
+}
+
+#[cfg(feature = "debug-trace")]
+#[test]
+fn test_transform_debug() {
+    use crate::transform::transform_debug;
+
+    let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+    let dst =
+        Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80 +pm=paris +units=km").unwrap();
+
+    let (lon, lat) = (3f64.to_radians(), 45f64.to_radians());
+    let mut pt = (lon, lat, 0.);
+    let trace = transform_debug(&src, &dst, &mut pt).unwrap();
+
+    let names: Vec<_> = trace.steps.iter().map(|(name, _)| *name).collect();
+    assert_eq!(
+        names,
+        [
+            "input",
+            "adjust_axes",
+            "height_unit",
+            "projected_to_geographic",
+            "prime_meridian",
+            "datum_transform",
+            "prime_meridian_dst",
+            "geographic_to_projected",
+            "long_wrap",
+            "height_unit_dst",
+            "adjust_axes_dst",
+        ]
+    );
+
+    assert_eq!(trace.get("input").unwrap(), [(lon, lat, 0.)]);
+    assert_eq!(
+        trace.get("projected_to_geographic").unwrap(),
+        [(lon, lat, 0.)]
+    );
+    // Longitude from Paris
+    let (lam, _, _) = trace.get("prime_meridian_dst").unwrap()[0];
+    assert_abs_diff_eq!(lam.to_degrees(), 3. - 2.337229166667, epsilon = 1.0e-9);
+
+    // Units are applied at the projection step
+    let (x, _, _) = trace.get("geographic_to_projected").unwrap()[0];
+    assert_abs_diff_eq!(x, pt.0, epsilon = 1.0e-12);
+    assert!((300. ..500.).contains(&x));
+
+    assert_eq!(trace.get("adjust_axes_dst").unwrap(), [pt]);
+    assert!(trace.get("foo").is_none());
+}
+
+#[test]
+fn test_prime_meridian_antimeridian() {
+    let paris = Proj::from_proj_string("+proj=longlat +datum=WGS84 +pm=paris").unwrap();
+    let wgs84 = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+
+    let pm = paris.from_greenwich().to_degrees();
+
+    // Crossing the antimeridian through the prime meridian shift
+    let mut pt = (179f64.to_radians(), 45f64.to_radians(), 0.);
+    transform(&paris, &wgs84, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0.to_degrees(), 179. + pm - 360., epsilon = 1.0e-10);
+    assert_abs_diff_eq!(pt.1.to_degrees(), 45., epsilon = 1.0e-10);
+
+    // Back to the Paris meridian without jumping to the other side
+    transform(&wgs84, &paris, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0.to_degrees(), 179., epsilon = 1.0e-10);
+    assert_abs_diff_eq!(pt.1.to_degrees(), 45., epsilon = 1.0e-10);
+
+    // No wrapping when staying in range
+    let mut pt = (177.5f64.to_radians(), 45f64.to_radians(), 0.);
+    transform(&paris, &wgs84, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0.to_degrees(), 177.5 + pm, epsilon = 1.0e-10);
+
+    // Projected destination with a prime meridian
+    let utm = Proj::from_proj_string("+proj=utm +zone=60 +datum=WGS84").unwrap();
+    let mut expected = (179f64.to_radians(), 45f64.to_radians(), 0.);
+    let mut pt = ((179. - pm).to_radians(), 45f64.to_radians(), 0.);
+    transform(&wgs84, &utm, &mut expected).unwrap();
+    transform(&paris, &utm, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, expected.0, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.1, expected.1, epsilon = 1.0e-6);
+}
+
+#[test]
+fn test_transform_with_status() {
+    use crate::adaptors::transform_with_status;
+    use crate::errors::Error;
+
+    let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+    let dst = Proj::from_proj_string("+proj=ortho +lat_0=45 +lon_0=0 +R=6400000").unwrap();
+
+    let inputs = [
+        (0., 45., 0.),
+        // Not visible from the view point
+        (180., -45., 0.),
+        (10., 40., 0.),
+        // Invalid latitude
+        (0., 95., 0.),
+    ];
+
+    let mut points = inputs.map(|(x, y, z)| (f64::to_radians(x), f64::to_radians(y), z));
+    let status = transform_with_status(&src, &dst, &mut points);
+
+    assert_eq!(status.len(), 4);
+    assert!(status[0].is_ok());
+    assert!(matches!(
+        status[1],
+        Err(Error::CoordTransOutsideProjectionDomain)
+    ));
+    assert!(status[2].is_ok());
+    assert!(status[3].is_err());
+
+    assert_abs_diff_eq!(points[0].0, 0., epsilon = 1.0e-6);
+    assert_abs_diff_eq!(points[0].1, 0., epsilon = 1.0e-6);
+    // Failed points are left unchanged
+    assert_eq!(points[1], (180f64.to_radians(), -45f64.to_radians(), 0.));
+    assert_eq!(points[3], (0., 95f64.to_radians(), 0.));
+}
+
+#[test]
+#[allow(clippy::excessive_precision)]
+fn test_transform_array_to_degrees() {
+    use crate::adaptors::transform_array_to_degrees;
+
+    let utm = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+    let utm_south = Proj::from_proj_string("+proj=utm +zone=31 +south +datum=WGS84").unwrap();
+    let wgs84 = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+
+    // Points on the central meridian, northings from the meridian arc length
+    let mut points = [
+        (500000., 0., 0.),
+        (500000., 4982950.4002265524, 0.),
+        (500000., 6651411.1903627155, 0.),
+    ];
+    transform_array_to_degrees(&utm, &wgs84, points.as_mut_slice()).unwrap();
+    for (pt, lat) in points.iter().zip([0., 45., 60.]) {
+        assert_abs_diff_eq!(pt.0, 3., epsilon = 1.0e-9);
+        assert_abs_diff_eq!(pt.1, lat, epsilon = 1.0e-9);
+    }
+
+    let mut pt = (500000., 6681214.6474187932, 0.);
+    transform_array_to_degrees(&utm_south, &wgs84, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, 3., epsilon = 1.0e-9);
+    assert_abs_diff_eq!(pt.1, -30., epsilon = 1.0e-9);
+
+    // Projected output is left as is
+    let mut pt = (3f64.to_radians(), 45f64.to_radians(), 0.);
+    transform_array_to_degrees(&wgs84, &utm, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, 500000., epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.1, 4982950.4002265524, epsilon = 1.0e-6);
+}
+
+#[test]
+fn test_transform_buffers() {
+    use crate::adaptors::{transform_buffers, transform_raw_buffers, transform_xy};
+
+    let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+    let dst = Proj::from_proj_string("+proj=ortho +lat_0=45 +lon_0=0 +R=6400000").unwrap();
+
+    let lonlat = [
+        (0., 45.),
+        (180., -45.),
+        (10., 40.),
+        (-5., 50.),
+        (0., 0.),
+        (170., 0.),
+        (1., 44.),
+        (2., 43.),
+        (3., 42.),
+    ];
+    let mut x: Vec<_> = lonlat.iter().map(|p| f64::to_radians(p.0)).collect();
+    let mut y: Vec<_> = lonlat.iter().map(|p| f64::to_radians(p.1)).collect();
+    let (x0, y0) = (x.clone(), y.clone());
+
+    // Point 3 is null
+    let mut validity = [0b1111_0111u8, 0b1];
+    transform_buffers(&src, &dst, &mut x, &mut y, Some(&mut validity)).unwrap();
+
+    // Points 1 and 5 are not visible
+    assert_eq!(validity, [0b1101_0101, 0b1]);
+    for i in [0, 2, 4, 6, 7, 8] {
+        let expected = transform_xy(&src, &dst, x0[i], y0[i]).unwrap();
+        assert_abs_diff_eq!(x[i], expected.0, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(y[i], expected.1, epsilon = 1.0e-6);
+    }
+    assert_eq!((x[3], y[3]), (x0[3], y0[3]));
+
+    // No bitmap
+    let (mut x, mut y) = (x0.clone(), y0.clone());
+    unsafe {
+        transform_raw_buffers(
+            &src,
+            &dst,
+            x.as_mut_ptr(),
+            y.as_mut_ptr(),
+            std::ptr::null_mut(),
+            x.len(),
+        )
+        .unwrap();
+    }
+    assert!(x[1].is_nan() && y[1].is_nan());
+    assert!(x[3].is_finite() && y[3].is_finite());
+
+    // Size mismatch
+    assert!(transform_buffers(&src, &dst, &mut x, &mut y[..2], None).is_err());
+    assert!(transform_buffers(&src, &dst, &mut x, &mut y, Some(&mut [0xff])).is_err());
+}
+
+#[test]
+#[allow(clippy::excessive_precision)]
+fn test_cart_helmert_transform() {
+    // 7 parameters Helmert (position vector) applied in ECEF space
+    let src = Proj::from_proj_string(
+        "+proj=cart +ellps=bessel +towgs84=570.8,85.7,462.8,4.998,1.587,5.261,3.56",
+    )
+    .unwrap();
+    let dst = Proj::from_proj_string("+proj=cart +datum=WGS84").unwrap();
+
+    // (50N, 15E, 300m) on Bessel; expected values computed with mpmath
+    let mut pt = (3967594.6358797318, 1063113.7785780285, 4862524.0630960839);
+    transform(&src, &dst, &mut pt).unwrap();
+
+    assert_abs_diff_eq!(pt.0, 3968189.8569491601, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.1, 1063186.6371010171, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.2, 4862999.4073159315, epsilon = 1.0e-6);
+
+    // And back: the inverse uses the transposed rotation matrix (small
+    // angles approximation, as proj4)
+    transform(&dst, &src, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, 3967594.6358797318, epsilon = 1.0e-3);
+    assert_abs_diff_eq!(pt.1, 1063113.7785780285, epsilon = 1.0e-3);
+    assert_abs_diff_eq!(pt.2, 4862524.0630960839, epsilon = 1.0e-3);
+
+    // No datum shift when the destination datum is unknown (as proj4)
+    let dst = Proj::from_proj_string("+proj=cart +ellps=WGS84").unwrap();
+    let mut pt = (3967594.6358797318, 1063113.7785780285, 4862524.0630960839);
+    transform(&src, &dst, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, 3968078.2821280085, epsilon = 1.0e-6);
+}
+
+#[test]
+fn test_axis_orientation() {
+    let src = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+    let enu = Proj::from_proj_string("+proj=utm +zone=31 +ellps=WGS84").unwrap();
+    let swu = Proj::from_proj_string("+proj=utm +zone=31 +ellps=WGS84 +axis=swu").unwrap();
+    let neu = Proj::from_proj_string("+proj=utm +zone=31 +ellps=WGS84 +axis=neu").unwrap();
+
+    let input = (3f64.to_radians(), 45f64.to_radians(), 10.);
+
+    let mut expected = input;
+    transform(&src, &enu, &mut expected).unwrap();
+
+    // Southing, westing
+    let mut pt = input;
+    transform(&src, &swu, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, -expected.1, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.1, -expected.0, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.2, expected.2, epsilon = 1.0e-6);
+
+    transform(&swu, &src, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, input.0, epsilon = 1.0e-12);
+    assert_abs_diff_eq!(pt.1, input.1, epsilon = 1.0e-12);
+
+    // Northing, easting
+    let mut pt = input;
+    transform(&src, &neu, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, expected.1, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.1, expected.0, epsilon = 1.0e-6);
+
+    // Both directions for the same axis
+    assert!(Proj::from_proj_string("+proj=utm +zone=31 +ellps=WGS84 +axis=snu").is_err());
+}
+
+#[cfg(feature = "debug-trace")]
+#[test]
+fn test_transform_traced() {
+    use crate::adaptors::transform_traced;
+
+    let src = Proj::from_proj_string(
+        "+proj=latlong +ellps=bessel +towgs84=446.448,-125.157,542.06,0.15,0.247,0.842,-20.489",
+    )
+    .unwrap();
+    let dst = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+
+    let mut pt = (-2f64.to_radians(), 52f64.to_radians(), 0.);
+    let (result, trace) = transform_traced(&src, &dst, &mut pt);
+    assert!(result.is_ok());
+
+    let names: Vec<_> = trace.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "input",
+            "adjust_axes",
+            "height_unit",
+            "projected_to_geographic",
+            "prime_meridian",
+            "datum_transform",
+            "prime_meridian_dst",
+            "geographic_to_projected",
+            "long_wrap",
+            "height_unit_dst",
+            "adjust_axes_dst",
+        ]
+    );
+    // The datum shift is about 100m
+    let (before, after) = (trace[4].1, trace[5].1);
+    assert!((before.0 - after.0).abs() > 1.0e-6);
+    assert_eq!(trace.last().unwrap().1, pt);
+
+    // Steps up to the failure are kept
+    let dst = Proj::from_proj_string("+proj=geos +h=35785831 +ellps=GRS80").unwrap();
+    let mut pt = (180f64.to_radians(), 0., 0.);
+    let (result, trace) = transform_traced(&src, &dst, &mut pt);
+    assert!(result.is_err());
+    assert_eq!(trace.last().unwrap().0, "prime_meridian_dst");
+}
+
+#[test]
+fn test_vertical_units() {
+    let src = Proj::from_proj_string("+proj=tmerc +ellps=GRS80 +vunits=m").unwrap();
+    let dst = Proj::from_proj_string("+proj=tmerc +ellps=GRS80 +vunits=ft").unwrap();
+    assert_eq!(dst.vunits(), "ft");
+    assert_abs_diff_eq!(dst.vto_meter(), 0.3048, epsilon = 1.0e-12);
+
+    let mut pt = (1000., 2000., 30.48);
+    transform(&src, &dst, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, 1000., epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.1, 2000., epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.2, 100., epsilon = 1.0e-9);
+}
+
+#[test]
+fn test_vertical_units_latlong() {
+    let src = Proj::from_proj_string("+proj=latlong +datum=WGS84 +vunits=ft").unwrap();
+    let dst = Proj::from_proj_string("+proj=latlong +datum=WGS84 +vunits=m").unwrap();
+
+    let mut pt = (2f64.to_radians(), 45f64.to_radians(), 100.);
+    transform(&src, &dst, &mut pt).unwrap();
+    assert_eq!((pt.0, pt.1), (2f64.to_radians(), 45f64.to_radians()));
+    assert_abs_diff_eq!(pt.2, 30.48, epsilon = 1.0e-12);
+
+    transform(&dst, &src, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.2, 100., epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_to_meter_units() {
+    let p = Proj::from_proj_string("+proj=tmerc +ellps=GRS80 +to_meter=0.3048").unwrap();
+    assert_eq!(p.to_meter(), 0.3048);
+    assert_eq!(p.units(), "ft");
+
+    let p =
+        Proj::from_proj_string("+proj=tmerc +ellps=GRS80 +to_meter=0.3048006096012192").unwrap();
+    assert_eq!(p.to_meter(), 0.3048006096012192);
+    assert_eq!(p.units(), "us-ft");
+
+    let p = Proj::from_proj_string("+proj=tmerc +ellps=GRS80 +to_meter=0.5").unwrap();
+    assert_eq!(p.to_meter(), 0.5);
+    assert_eq!(p.units(), "unknown");
+
+    let p = Proj::from_proj_string("+proj=tmerc +ellps=GRS80 +vto_meter=0.3048").unwrap();
+    assert_eq!(p.vunits(), "ft");
+}
+
+#[test]
+fn test_lv95_datum() {
+    // EPSG:2056
+    let lv95 = Proj::from_proj_string(concat!(
+        "+proj=somerc +lat_0=46.9524055555556 +lon_0=7.43958333333333 +k_0=1",
+        " +x_0=2600000 +y_0=1200000 +datum=LV95 +units=m",
+    ))
+    .unwrap();
+    let wgs84 = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+
+    // Bern old observatory, origin of the projection: its
+    // WGS84 coordinates are 7°26'19.1"E 46°57'03.9"N
+    let mut pt = (7.438639f64.to_radians(), 46.951082f64.to_radians(), 0.);
+    transform(&wgs84, &lv95, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0, 2600000., epsilon = 1.);
+    assert_abs_diff_eq!(pt.1, 1200000., epsilon = 1.);
+
+    transform(&lv95, &wgs84, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0.to_degrees(), 7.438639, epsilon = 1.0e-9);
+    assert_abs_diff_eq!(pt.1.to_degrees(), 46.951082, epsilon = 1.0e-9);
+
+    // LV95 is an alias of CH1903+
+    let ch1903_plus = Proj::from_proj_string("+proj=longlat +datum=ch1903+").unwrap();
+    let lv95 = Proj::from_proj_string("+proj=longlat +datum=LV95").unwrap();
+    assert!(ch1903_plus.datum().is_identical_to(lv95.datum()));
+    assert_eq!(lv95.datum_name(), Some("ch1903+"));
+}
+
+#[test]
+#[allow(clippy::excessive_precision)]
+fn test_kkj_zone0() {
+    // EPSG:3386, KKJ / Finland zone 0: this is a transverse mercator
+    const TOWGS84: &str = "+towgs84=-96.062,-82.428,-121.753,4.801,0.345,-1.376,1.496";
+    let kkj0 = Proj::from_proj_string(&format!(
+        "+proj=tmerc +lat_0=0 +lon_0=18 +k=1 +x_0=500000 +y_0=0 +ellps=intl {TOWGS84} +units=m"
+    ))
+    .unwrap();
+    let kkj = Proj::from_proj_string(&format!("+proj=longlat +ellps=intl {TOWGS84}")).unwrap();
+
+    // On the central meridian, northings are the meridian arc
+    // lengths, computed with mpmath
+    for (phi, y) in [(60., 6654228.3963126116), (70., 7769187.4259626748)] {
+        let mut pt = (18f64.to_radians(), f64::to_radians(phi), 0.);
+        transform(&kkj, &kkj0, &mut pt).unwrap();
+        assert_abs_diff_eq!(pt.0, 500000., epsilon = 1.0e-3);
+        assert_abs_diff_eq!(pt.1, y, epsilon = 1.0e-3);
+
+        transform(&kkj0, &kkj, &mut pt).unwrap();
+        assert_abs_diff_eq!(pt.0.to_degrees(), 18., epsilon = 1.0e-10);
+        assert_abs_diff_eq!(pt.1.to_degrees(), phi, epsilon = 1.0e-10);
+    }
+
+    // Western Finland
+    let mut pt = (21.5f64.to_radians(), 61.5f64.to_radians(), 0.);
+    transform(&kkj, &kkj0, &mut pt).unwrap();
+    transform(&kkj0, &kkj, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0.to_degrees(), 21.5, epsilon = 1.0e-10);
+    assert_abs_diff_eq!(pt.1.to_degrees(), 61.5, epsilon = 1.0e-10);
+}
+
+#[test]
+fn test_coordinates_in_degrees() {
+    use crate::errors::Error;
+
+    let src = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+    let dst = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+
+    // Degrees instead of radians
+    let mut pt = (3., 45., 0.);
+    assert!(matches!(
+        transform(&src, &dst, &mut pt),
+        Err(Error::CoordinateLikelyInDegrees)
+    ));
+    let mut pt = (120., 1., 0.);
+    assert!(matches!(
+        transform(&src, &dst, &mut pt),
+        Err(Error::CoordinateLikelyInDegrees)
+    ));
+
+    // Out of range in any unit
+    let mut pt = (0., 2., 0.);
+    assert!(matches!(
+        transform(&src, &dst, &mut pt),
+        Err(Error::CoordinateOutOfRange)
+    ));
+    let mut pt = (500000., 0., 0.);
+    assert!(matches!(
+        transform(&src, &dst, &mut pt),
+        Err(Error::CoordinateOutOfRange)
+    ));
+}
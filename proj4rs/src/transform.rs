@@ -72,11 +72,58 @@ use Direction::*;
 /// The transformation function
 ///
 /// Transform coordinates from `src` to `dst` CRS.
-/// `points` must implement [`Transform`]
+/// `points` must implement [`Transform`], as do `(f64, f64, f64)`
+/// and `(f64, f64)` tuples (with an implicit `z = 0`) and
+/// slices or vectors of them.
+///
+/// If `src` and `dst` are the same projected or geocentric CRS
+/// (see [`Proj::is_same_crs`]), points are left untouched.
+/// Geographic coordinates always go through the whole transformation,
+/// so that their longitude is wrapped as for any geographic destination.
 pub fn transform<P>(src: &Proj, dst: &Proj, points: &mut P) -> Result<()>
 where
     P: Transform + ?Sized,
 {
+    Transformation::new(src, dst).apply(points)
+}
+
+/// Transformation between two CRS
+///
+/// Whether `src` and `dst` are the same CRS is checked once at
+/// creation, for transforming points one at a time.
+pub(crate) struct Transformation<'a> {
+    src: &'a Proj,
+    dst: &'a Proj,
+    identity: bool,
+}
+
+impl<'a> Transformation<'a> {
+    pub(crate) fn new(src: &'a Proj, dst: &'a Proj) -> Self {
+        Self {
+            src,
+            dst,
+            identity: is_identity(src, dst),
+        }
+    }
+
+    pub(crate) fn apply<P>(&self, points: &mut P) -> Result<()>
+    where
+        P: Transform + ?Sized,
+    {
+        if self.identity {
+            check_directions(self.src, self.dst)
+        } else {
+            pipeline(self.src, self.dst, points, |_, _| Ok(()))
+        }
+    }
+}
+
+// Transformation steps may be skipped
+fn is_identity(src: &Proj, dst: &Proj) -> bool {
+    !dst.is_latlong() && src.is_same_crs(dst)
+}
+
+fn check_directions(src: &Proj, dst: &Proj) -> Result<()> {
     if !src.has_inverse() {
         return Err(Error::NoInverseProjectionDefined);
     }
@@ -85,39 +132,218 @@ where
         return Err(Error::NoForwardProjectionDefined);
     }
 
+    Ok(())
+}
+
+/// Run the transformation steps, calling `step`
+/// after each of them
+#[inline(always)]
+fn pipeline<P, F>(src: &Proj, dst: &Proj, points: &mut P, mut step: F) -> Result<()>
+where
+    P: Transform + ?Sized,
+    F: FnMut(&'static str, &mut P) -> Result<()>,
+{
+    check_directions(src, dst)?;
+
     adjust_axes(src, Inverse, points)?;
+    step("adjust_axes", points)?;
     height_unit(src, Inverse, points)?;
+    step("height_unit", points)?;
     projected_to_geographic(src, points)?;
+    step("projected_to_geographic", points)?;
     prime_meridian(src, Inverse, points)?;
+    step("prime_meridian", points)?;
 
-    datum_transform(src, dst, points)?;
+    if !is_trivial_datum_transform(src, dst) {
+        datum_transform(src, dst, points)?;
+    }
+    step("datum_transform", points)?;
 
     prime_meridian(dst, Forward, points)?;
+    step("prime_meridian_dst", points)?;
     geographic_to_projected(dst, points)?;
-    //long_wrap(dst)?;
+    step("geographic_to_projected", points)?;
+    long_wrap(dst, points)?;
+    step("long_wrap", points)?;
     height_unit(dst, Forward, points)?;
+    step("height_unit_dst", points)?;
     adjust_axes(dst, Forward, points)?;
+    step("adjust_axes_dst", points)?;
 
     Ok(())
 }
+
+/// Step name with the coordinates after the step
+#[cfg(feature = "debug-trace")]
+pub type TraceStep = (&'static str, Vec<(f64, f64, f64)>);
+
+/// Coordinates recorded after each step of a transformation
+#[cfg(feature = "debug-trace")]
+#[derive(Debug, Default, Clone)]
+pub struct TransformTrace {
+    /// Recorded steps, starting with the `input` coordinates
+    pub steps: Vec<TraceStep>,
+}
+
+#[cfg(feature = "debug-trace")]
+impl TransformTrace {
+    /// Return the coordinates after the step `name`
+    pub fn get(&self, name: &str) -> Option<&[(f64, f64, f64)]> {
+        self.steps
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, pts)| pts.as_slice())
+    }
+
+    fn record<P: Transform + ?Sized>(&mut self, name: &'static str, points: &mut P) -> Result<()> {
+        let mut pts = vec![];
+        points.transform_coordinates(&mut |x, y, z| {
+            pts.push((x, y, z));
+            Ok((x, y, z))
+        })?;
+        self.steps.push((name, pts));
+        Ok(())
+    }
+}
+
+/// Same as [`transform`], recording the coordinates after each step
+///
+/// The steps are, in order: `adjust_axes`, `height_unit`,
+/// `projected_to_geographic`, `prime_meridian`, `datum_transform`,
+/// `prime_meridian_dst`, `geographic_to_projected`, `long_wrap`,
+/// `height_unit_dst` and `adjust_axes_dst`. No step is recorded
+/// when the points are left untouched (see [`transform`]).
+///
+/// All the points are recorded at each step: this is intended
+/// for debugging transformations of a few points.
+#[cfg(feature = "debug-trace")]
+pub fn transform_debug<P>(src: &Proj, dst: &Proj, points: &mut P) -> Result<TransformTrace>
+where
+    P: Transform + ?Sized,
+{
+    let mut trace = TransformTrace::default();
+    transform_trace_into(src, dst, points, &mut trace)?;
+    Ok(trace)
+}
+
+// Record the steps into `trace`: steps recorded before
+// a failure are kept.
+#[cfg(feature = "debug-trace")]
+pub(crate) fn transform_trace_into<P>(
+    src: &Proj,
+    dst: &Proj,
+    points: &mut P,
+    trace: &mut TransformTrace,
+) -> Result<()>
+where
+    P: Transform + ?Sized,
+{
+    trace.record("input", points)?;
+    if is_identity(src, dst) {
+        check_directions(src, dst)
+    } else {
+        pipeline(src, dst, points, |name, points| trace.record(name, points))
+    }
+}
+
+/// Interleaved 2D coordinates `[x0, y0, x1, y1, ...]`
+pub struct InterleavedCoords2d<'a>(pub &'a mut [f64]);
+
+impl Transform for InterleavedCoords2d<'_> {
+    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
+        self.0.chunks_exact_mut(2).try_for_each(|xy| {
+            (xy[0], xy[1]) = f(xy[0], xy[1], 0.).map(|(x, y, _)| (x, y))?;
+            Ok(())
+        })
+    }
+}
+
+/// Interleaved 3D coordinates `[x0, y0, z0, x1, y1, z1, ...]`
+pub struct InterleavedCoords3d<'a>(pub &'a mut [f64]);
+
+impl Transform for InterleavedCoords3d<'_> {
+    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
+        self.0.chunks_exact_mut(3).try_for_each(|xyz| {
+            (xyz[0], xyz[1], xyz[2]) = f(xyz[0], xyz[1], xyz[2])?;
+            Ok(())
+        })
+    }
+}
+
+/// Transform interleaved `[x0, y0, x1, y1, ...]` coordinates in place
+///
+/// Return [`Error::InvalidNumberOfCoordinates`] if the length of
+/// `coords` is not a multiple of 2.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::transform::transform_inplace_2d;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+/// let dst = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
+///
+/// let mut coords = [2.0f64.to_radians(), 1.0f64.to_radians(), -3.0f64.to_radians(), 45.0f64.to_radians()];
+/// transform_inplace_2d(&src, &dst, &mut coords).unwrap();
+/// ```
+pub fn transform_inplace_2d(src: &Proj, dst: &Proj, coords: &mut [f64]) -> Result<()> {
+    if !coords.len().is_multiple_of(2) {
+        return Err(Error::InvalidNumberOfCoordinates);
+    }
+    transform(src, dst, &mut InterleavedCoords2d(coords))
+}
+
+/// Transform interleaved `[x0, y0, z0, x1, y1, z1, ...]` coordinates in place
+///
+/// Return [`Error::InvalidNumberOfCoordinates`] if the length of
+/// `coords` is not a multiple of 3.
+pub fn transform_inplace_3d(src: &Proj, dst: &Proj, coords: &mut [f64]) -> Result<()> {
+    if !coords.len().is_multiple_of(3) {
+        return Err(Error::InvalidNumberOfCoordinates);
+    }
+    transform(src, dst, &mut InterleavedCoords3d(coords))
+}
+
 // ---------------------------------
 // Datum transformation
 // ---------------------------------
+
+/// Return true if no datum shift is required between `src` and `dst`
+///
+/// This is the case, in that order, when:
+///
+/// 1. Either the source or the destination has no datum
+///    (no `+datum`, `+towgs84` or `+nadgrids` parameter). This follows
+///    PROJ 4 behavior: the ellipsoids are not compared, so two
+///    CRS without datum and different ellipsoids are left unshifted.
+/// 2. Both datums are identical, i.e. they have the same datum
+///    parameters, the same semi-major axis and eccentricities equal
+///    within 5e-11 (so that GRS80 and WGS84 are considered identical).
+///
+/// Otherwise the shift is required, even if only the `+towgs84`
+/// parameters differ.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::transform::is_trivial_datum_transform;
+///
+/// let src = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+/// let dst = Proj::from_proj_string("+proj=utm +zone=32 +datum=WGS84").unwrap();
+/// assert!(is_trivial_datum_transform(&src, &dst));
+/// ```
+pub fn is_trivial_datum_transform(src: &Proj, dst: &Proj) -> bool {
+    let src_datum = src.datum();
+    let dst_datum = dst.datum();
+    src_datum.no_datum() || dst_datum.no_datum() || src_datum.is_identical_to(dst_datum)
+}
+
+/// Apply datum shift between `src` and `dst`
+///
+/// No check is done: see [`is_trivial_datum_transform`]
 fn datum_transform<P>(src: &Proj, dst: &Proj, points: &mut P) -> Result<()>
 where
     P: Transform + ?Sized,
 {
-    let src_datum = src.datum();
-    let dst_datum = dst.datum();
-
-    // Return true if the datums are identical with respect
-    // to datum transformation.
-    // As of PROJ 4 behavior, we prevent datum transformation
-    // if either the source or destination are of an unknown datum type.
-    if src_datum.no_datum() || dst_datum.no_datum() || src_datum.is_identical_to(dst_datum) {
-        return Ok(());
-    }
-
+    let (src_datum, dst_datum) = (src.datum(), dst.datum());
     points.transform_coordinates(&mut |x, y, z| Datum::transform(src_datum, dst_datum, x, y, z))
 }
 // ---------------------------------
@@ -145,15 +371,17 @@ where
             let d = &p.data();
             let (lam0, x0, y0) = (d.lam0, d.x0, d.y0);
             let (ra, to_meter) = (d.ellps.ra, d.to_meter);
+            let rone_es = d.ellps.rone_es;
 
             let over = p.over();
+            let geoc = p.geoc();
             let proj = p.projection();
 
             // Input points are cartesians
             // proj4 source: pj_inv.c
             points.transform_coordinates(&mut |x, y, z| {
                 // Inverse project
-                let (mut lam, phi, z) = proj.inverse(
+                let (mut lam, mut phi, z) = proj.inverse(
                     // descale and de-offset
                     // z is not scaled since that
                     // is handled by vto_meter before we get here
@@ -165,6 +393,10 @@ where
                 if !over {
                     lam = adjlon(lam);
                 }
+                // Geocentric latitude => geodetic latitude
+                if geoc && (phi.abs() - FRAC_PI_2).abs() > EPS_12 {
+                    phi = (rone_es * phi.tan()).atan();
+                }
                 Ok((lam, phi, z))
             })
         }
@@ -173,6 +405,17 @@ where
 // ---------------------------------
 // Geographic to projected
 // ---------------------------------
+// Geographic coordinates out of range that would be valid
+// in degrees are most likely not converted to radians
+fn out_of_range_error(lam: f64, phi: f64) -> Error {
+    let (lam, phi) = (lam.abs(), phi.abs());
+    if lam <= 360. && phi <= 90. && (lam > 10. || phi > 10.) {
+        Error::CoordinateLikelyInDegrees
+    } else {
+        Error::CoordinateOutOfRange
+    }
+}
+
 fn geographic_to_projected<P>(p: &Proj, points: &mut P) -> Result<()>
 where
     P: Transform + ?Sized,
@@ -198,10 +441,11 @@ where
             let d = p.data();
 
             let (lam0, x0, y0) = (d.lam0, d.x0, d.y0);
-            let a = d.ellps.a;
+            let (a, one_es) = (d.ellps.a, d.ellps.one_es);
 
             let proj = p.projection();
             let over = p.over();
+            let geoc = p.geoc();
 
             let fr_meter = 1. / p.to_meter();
 
@@ -211,7 +455,7 @@ where
                 // Over range check
                 let t = phi.abs() - FRAC_PI_2;
                 if t > EPS_12 || lam.abs() > 10. {
-                    Err(Error::CoordinateOutOfRange)
+                    Err(out_of_range_error(lam, phi))
                 } else {
                     let (x, y, z) = proj.forward(
                         // ----
@@ -231,6 +475,10 @@ where
                             } else {
                                 FRAC_PI_2
                             }
+                        } else if geoc {
+                            // Geodetic latitude to geocentric latitude
+                            // before projecting
+                            (one_es * phi.tan()).atan()
                         } else {
                             phi
                         },
@@ -247,6 +495,21 @@ where
     }
 }
 // ---------------------------------
+// Wrap output longitudes to [-pi, pi]
+// for geographic destination unless
+// over-ranging is allowed.
+// ---------------------------------
+fn long_wrap<P>(p: &Proj, points: &mut P) -> Result<()>
+where
+    P: Transform + ?Sized,
+{
+    if p.is_latlong() && !p.over() {
+        points.transform_coordinates(&mut |lam, phi, z| Ok((adjlon(lam), phi, z)))
+    } else {
+        Ok(())
+    }
+}
+// ---------------------------------
 // Transform cartesian ("geocentric")
 // source coordinates to lat/long,
 // ---------------------------------
@@ -285,19 +548,27 @@ where
 }
 // --------------------------
 // Prime meridian adjustment
+//
+// The shifted longitude is wrapped back
+// to [-pi, pi] unless over-ranging is
+// allowed.
 // -------------------------
 fn prime_meridian<P>(p: &Proj, dir: Direction, points: &mut P) -> Result<()>
 where
     P: Transform + ?Sized,
 {
     let mut pm = p.from_greenwich();
-    if pm == 0. || p.is_geocent() || p.is_latlong() {
+    if pm == 0. || p.is_geocent() {
         Ok(())
     } else {
         if dir == Forward {
             pm = -pm;
         }
-        points.transform_coordinates(&mut |x, y, z| Ok((x + pm, y, z)))
+        if p.over() {
+            points.transform_coordinates(&mut |x, y, z| Ok((x + pm, y, z)))
+        } else {
+            points.transform_coordinates(&mut |x, y, z| Ok((adjlon(x + pm), y, z)))
+        }
     }
 }
 // ---------------------
@@ -323,8 +594,8 @@ fn normalize_axis<P: Transform + ?Sized>(axis: &Axis, points: &mut P) -> Result<
         let (mut x_out, mut y_out, mut z_out) = (x, y, z);
         axis.iter().enumerate().for_each(|(i, axe)| {
             let value = match i {
-                1 => x,
-                2 => y,
+                0 => x,
+                1 => y,
                 _ => z,
             };
             match axe {
@@ -359,8 +630,8 @@ fn denormalize_axis<P: Transform + ?Sized>(axis: &Axis, points: &mut P) -> Resul
                 _ => unreachable!(),
             };
             match i {
-                1 => x_out = value,
-                2 => y_out = value,
+                0 => x_out = value,
+                1 => y_out = value,
                 _ => z_out = value,
             }
         });
@@ -375,10 +646,6 @@ fn height_unit<P>(p: &Proj, dir: Direction, points: &mut P) -> Result<()>
 where
     P: Transform + ?Sized,
 {
-    //if p.is_latlong {
-    //    return Ok(());
-    //}
-
     let fac = if dir == Forward {
         1. / p.vto_meter()
     } else {
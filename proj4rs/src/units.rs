@@ -51,8 +51,18 @@ mod constants {
     ];
 }
 
+pub const UNKNOWN: &str = "unknown";
+
+/// Return the unit definition for a `to_meter` value
+///
+/// The name is the one of the predefined unit with the same
+/// factor, if any, or "unknown". The factor is kept as is.
 pub fn from_value(to_meter: f64) -> UnitDefn {
-    UnitDefn { name: "", to_meter }
+    let name = constants::UNITS
+        .iter()
+        .find(|d| (d.to_meter - to_meter).abs() <= 1.0e-12 * d.to_meter)
+        .map_or(UNKNOWN, |d| d.name);
+    UnitDefn { name, to_meter }
 }
 
 /// Return the unit definition
@@ -2,6 +2,7 @@
 //! Wasm bindgen entry point
 //!
 mod nadgrids;
+mod stream;
 
 use crate::{errors, proj, transform, transform::TransformClosure};
 use wasm_bindgen::prelude::*;
@@ -50,6 +51,22 @@ impl Projection {
         self.inner.is_geocent()
     }
 
+    #[wasm_bindgen(getter, js_name = isProjected)]
+    pub fn is_projected(&self) -> bool {
+        self.inner.is_projected()
+    }
+
+    /// One of "geocentric", "latlong" or "projected"
+    #[wasm_bindgen(getter, js_name = projType)]
+    pub fn proj_type(&self) -> String {
+        match self.inner.projection_type() {
+            proj::ProjType::Geocentric => "geocentric",
+            proj::ProjType::Latlong => "latlong",
+            proj::ProjType::Other => "projected",
+        }
+        .into()
+    }
+
     #[wasm_bindgen(getter)]
     pub fn axis(&self) -> String {
         String::from_utf8_lossy(self.inner.axis()).into_owned()
@@ -69,6 +86,16 @@ impl Projection {
     pub fn units(&self) -> String {
         self.inner.units().into()
     }
+
+    #[wasm_bindgen(getter)]
+    pub fn vto_meter(&self) -> f64 {
+        self.inner.vto_meter()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn vunits(&self) -> String {
+        self.inner.vunits().into()
+    }
 }
 
 // ----------------------------
@@ -5,7 +5,7 @@
 //!
 use crate::errors::Error;
 use crate::math::consts::SEC_TO_RAD;
-use crate::nadgrids::{catalog, Grid, GridId, Lp};
+use crate::nadgrids::{catalog, Accuracy, Grid, GridId, Lp};
 use js_sys::DataView;
 use wasm_bindgen::prelude::*;
 
@@ -103,10 +103,17 @@ fn read_subgrid(view: &DataView, offset: usize, is_le: bool) -> Result<Grid, Err
     }
 
     let cvsoffset = offset + HEADER_SIZE;
+    let mut acc = Accuracy::default();
     let mut cvs: Vec<Lp> = (0..gs_count)
-        .map(|i| Lp {
-            lam: SEC_TO_RAD * (view.get_float32_endian(cvsoffset + i * 16, is_le) as f64),
-            phi: SEC_TO_RAD * (view.get_float32_endian(cvsoffset + i * 16 + 4, is_le) as f64),
+        .map(|i| {
+            acc.add(
+                view.get_float32_endian(cvsoffset + i * 16 + 8, is_le),
+                view.get_float32_endian(cvsoffset + i * 16 + 12, is_le),
+            );
+            Lp {
+                lam: SEC_TO_RAD * (view.get_float32_endian(cvsoffset + i * 16, is_le) as f64),
+                phi: SEC_TO_RAD * (view.get_float32_endian(cvsoffset + i * 16 + 4, is_le) as f64),
+            }
         })
         .collect();
 
@@ -128,6 +135,7 @@ fn read_subgrid(view: &DataView, offset: usize, is_le: bool) -> Result<Grid, Err
         del,
         lim,
         epsilon,
+        acc: acc.mean(),
         cvs: cvs.into_boxed_slice(),
     })
 }
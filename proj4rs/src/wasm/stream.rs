@@ -0,0 +1,95 @@
+//!
+//! Streaming transform
+//!
+//! Wrap a Web Streams `TransformStream` reprojecting chunks of
+//! coordinates `{ x: Float64Array, y: Float64Array, z?: Float64Array }`.
+//!
+use super::{transform, Point, Projection};
+use js_sys::{Float64Array, Object, Reflect};
+use wasm_bindgen::prelude::*;
+use web_sys::{ReadableStream, TransformStreamDefaultController, WritableStream};
+
+#[wasm_bindgen(js_name = TransformStream)]
+pub struct CoordinateStream {
+    inner: web_sys::TransformStream,
+}
+
+#[wasm_bindgen(js_class = TransformStream)]
+impl CoordinateStream {
+    #[wasm_bindgen(constructor)]
+    pub fn new(src: &Projection, dst: &Projection) -> Result<CoordinateStream, JsValue> {
+        let src = Projection {
+            inner: src.inner.clone(),
+        };
+        let dst = Projection {
+            inner: dst.inner.clone(),
+        };
+
+        // The closure is owned by the js transformer object
+        let on_chunk = Closure::<
+            dyn FnMut(JsValue, TransformStreamDefaultController) -> Result<(), JsValue>,
+        >::new(
+            move |chunk, controller: TransformStreamDefaultController| {
+                let out = transform_chunk(&src, &dst, &chunk)?;
+                controller.enqueue_with_chunk(&out)
+            },
+        );
+
+        let transformer = Object::new();
+        Reflect::set(&transformer, &"transform".into(), &on_chunk.into_js_value())?;
+
+        Ok(Self {
+            inner: web_sys::TransformStream::new_with_transformer(&transformer)?,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn readable(&self) -> ReadableStream {
+        self.inner.readable()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn writable(&self) -> WritableStream {
+        self.inner.writable()
+    }
+}
+
+fn get_array(chunk: &JsValue, key: &str) -> Result<Option<Vec<f64>>, JsValue> {
+    let value = Reflect::get(chunk, &key.into())?;
+    if value.is_undefined() {
+        Ok(None)
+    } else {
+        value
+            .dyn_into::<Float64Array>()
+            .map(|a| Some(a.to_vec()))
+            .map_err(|_| JsError::new(&format!("'{key}' is not a Float64Array")).into())
+    }
+}
+
+fn transform_chunk(src: &Projection, dst: &Projection, chunk: &JsValue) -> Result<Object, JsValue> {
+    let (mut x, mut y) = match (get_array(chunk, "x")?, get_array(chunk, "y")?) {
+        (Some(x), Some(y)) if x.len() == y.len() => (x, y),
+        _ => return Err(JsError::new("Invalid coordinates chunk").into()),
+    };
+    let mut z = get_array(chunk, "z")?;
+    if z.as_ref().is_some_and(|z| z.len() != x.len()) {
+        return Err(JsError::new("Invalid coordinates chunk").into());
+    }
+
+    for i in 0..x.len() {
+        let mut point = Point::new(x[i], y[i], z.as_ref().map(|z| z[i]).unwrap_or(0.));
+        transform(src, dst, &mut point)?;
+        (x[i], y[i]) = (point.x, point.y);
+        if let Some(z) = z.as_mut() {
+            z[i] = point.z;
+        }
+    }
+
+    let out = Object::new();
+    Reflect::set(&out, &"x".into(), &Float64Array::from(x.as_slice()))?;
+    Reflect::set(&out, &"y".into(), &Float64Array::from(y.as_slice()))?;
+    if let Some(z) = z {
+        Reflect::set(&out, &"z".into(), &Float64Array::from(z.as_slice()))?;
+    }
+    Ok(out)
+}